@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use solana_program::keccak;
+
+/// Allowlist entry presented by a minter when the collection has a Merkle root set
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowlistProof {
+    pub price: u64,              // Price encoded in the leaf (0 = collection mint fee)
+    pub proof: Vec<[u8; 32]>,    // Sibling hashes from leaf to root
+}
+
+/// Leaf hash for an allowlisted wallet: keccak256(wallet || price as u64 LE)
+pub fn leaf(wallet: &Pubkey, price: u64) -> [u8; 32] {
+    keccak::hashv(&[wallet.as_ref(), &price.to_le_bytes()]).to_bytes()
+}
+
+/// Verify a Merkle proof - each pair is hashed in sorted order so proofs carry no direction bits
+pub fn verify(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == root
+}
@@ -50,7 +50,7 @@ pub enum ProgramErrorCode {
     SameSuperAdmin,
     #[msg("Invalid collection address - cannot be empty")]
     InvalidCollection,
-    #[msg("Invalid metadata - name, symbol, and URI are required")]
+    #[msg("Invalid metadata - provide at least one of name, symbol and URI within Metaplex limits")]
     InvalidMetadata,
     #[msg("Invalid collection mint - cannot be empty")]
     InvalidCollectionMint,
@@ -68,4 +68,150 @@ pub enum ProgramErrorCode {
     InvalidUserState,
     #[msg("Invalid admin mint limit - cannot be less than current admin mint count")]
     InvalidAdminMintLimit,
+    #[msg("Collection is retired - no new NFTs can be minted")]
+    CollectionRetired,
+    #[msg("Account has already been migrated to the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("Invalid legacy account - unexpected discriminator")]
+    InvalidLegacyAccount,
+    #[msg("Collection is allowlisted - an allowlist proof is required")]
+    AllowlistProofRequired,
+    #[msg("Invalid allowlist proof for this wallet")]
+    InvalidAllowlistProof,
+    #[msg("No sale phase is active - minting is outside the sale schedule")]
+    NoActiveSalePhase,
+    #[msg("Invalid sale phase - end must be after start and phases cannot overlap")]
+    InvalidSalePhase,
+    #[msg("Too many sale phases for this collection")]
+    TooManySalePhases,
+    #[msg("Invalid sale phase index")]
+    InvalidSalePhaseIndex,
+    #[msg("Sale phase supply cap reached")]
+    PhaseSupplyCapReached,
+    #[msg("Wallet mint limit reached for this sale phase")]
+    PhaseWalletLimitReached,
+    #[msg("Sale has ended for this collection")]
+    SaleEnded,
+    #[msg("Sale is closed for this collection - supply and prices are frozen")]
+    SaleClosed,
+    #[msg("Invalid sale window - end must be after start")]
+    InvalidSaleWindow,
+    #[msg("Revenue overflow")]
+    RevenueOverflow,
+    #[msg("Admin allocation cannot be released yet")]
+    AdminAllocationLocked,
+    #[msg("Program is paused for this action")]
+    ProgramPaused,
+    #[msg("Invalid role holder")]
+    InvalidRoleHolder,
+    #[msg("No pending handoff to accept or cancel")]
+    NoPendingHandoff,
+    #[msg("Pending handoff has expired - propose it again")]
+    HandoffExpired,
+    #[msg("Invalid multisig config - signers must be unique and the threshold reachable")]
+    InvalidMultisigConfig,
+    #[msg("Admin state is governed by a multisig - submit a proposal instead")]
+    MultisigRequired,
+    #[msg("Signer is not a member of the multisig")]
+    NotMultisigSigner,
+    #[msg("Proposal already approved by this signer")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalThresholdNotMet,
+    #[msg("Proposal action cannot be executed by this instruction")]
+    InvalidProposalAction,
+    #[msg("Config changes are timelocked - queue the change instead")]
+    TimelockActive,
+    #[msg("Timelock has not elapsed for this change")]
+    TimelockNotElapsed,
+    #[msg("Invalid config delay - must be between 0 and 30 days, and decreases must be queued")]
+    InvalidConfigDelay,
+    #[msg("Config change cannot be executed by this instruction")]
+    InvalidConfigChange,
+    #[msg("Program data account does not belong to this program")]
+    InvalidProgramData,
+    #[msg("Invalid operator quota - cannot be below what the operator already minted")]
+    InvalidOperatorQuota,
+    #[msg("Airdrop operator has no quota for this collection")]
+    OperatorQuotaRequired,
+    #[msg("Airdrop operator has been revoked for this collection")]
+    OperatorRevoked,
+    #[msg("Airdrop operator quota exhausted for this collection")]
+    OperatorQuotaExhausted,
+    #[msg("Invalid batch accounts - each recipient needs recipient, mint, token account, metadata, user state, reservation and serial record")]
+    InvalidBatchAccounts,
+    #[msg("Wallet already holds the maximum number of reservations")]
+    WalletReservationLimitReached,
+    #[msg("Wallet already holds the maximum number of reservations in this collection")]
+    WalletCollectionLimitReached,
+    #[msg("Invalid wallet limit - must be at least 1")]
+    InvalidWalletLimit,
+    #[msg("Invalid quantity prices - breaks must start at 1 or later, ascend strictly and have a non-zero price")]
+    InvalidQuantityPrices,
+    #[msg("Reservation does not belong to this wallet or mint")]
+    InvalidReservation,
+    #[msg("NFT name is too long - at most 32 bytes including the serial suffix")]
+    NameTooLong,
+    #[msg("Serial record does not match the serial being issued or burned")]
+    InvalidSerialRecord,
+    #[msg("Collection metadata is not configured - set a name prefix, symbol and base URI first")]
+    CollectionMetadataNotSet,
+    #[msg("Invalid metadata config - name prefix up to 32 bytes, symbol up to 10, base URI up to 200 (175 when ending in '/')")]
+    InvalidMetadataConfig,
+    #[msg("Provenance can only be committed before the first mint")]
+    ProvenanceLocked,
+    #[msg("Collection has no committed provenance hash")]
+    ProvenanceNotCommitted,
+    #[msg("Collection has already been revealed")]
+    AlreadyRevealed,
+    #[msg("Collection has not been revealed yet")]
+    NotRevealed,
+    #[msg("Invalid reveal config - needs a max supply, a placeholder URI and a base URI within Metaplex limits")]
+    InvalidRevealConfig,
+    #[msg("Invalid reveal accounts - each NFT needs serial record, mint and metadata")]
+    InvalidRevealAccounts,
+    #[msg("Metadata account is not a token metadata account for this mint")]
+    InvalidMetadataAccount,
+    #[msg("NFT is not verified into this collection")]
+    NftNotInCollection,
+    #[msg("Collection NFT metadata is immutable")]
+    CollectionNftImmutable,
+    #[msg("Invalid collection authority record - must be the Metaplex PDA for the collection mint and delegate")]
+    InvalidCollectionAuthorityRecord,
+    #[msg("Collection mint migration still pending - finish migrating minted NFTs first")]
+    CollectionMigrationPending,
+    #[msg("Collection has no pending migration")]
+    NoCollectionMigration,
+    #[msg("Invalid migration accounts - each NFT needs reservation, mint and metadata from the migrating collection")]
+    InvalidMigrationAccounts,
+    #[msg("Update authority handoff requires a non-zero config delay")]
+    HandoffRequiresTimelock,
+    #[msg("Invalid handoff target - must be a new key other than the admin state")]
+    InvalidHandoffTarget,
+    #[msg("Update authority handoff has already been started for this campaign")]
+    UpdateAuthorityHandoffStarted,
+    #[msg("No update authority handoff has been executed")]
+    NoUpdateAuthorityHandoff,
+    #[msg("Invalid handoff accounts - each NFT needs mint and metadata still controlled by the admin state")]
+    InvalidHandoffAccounts,
+    #[msg("Invalid royalty config - at most 4 distinct partner creators, shares up to 100 and royalty up to 10000 bps")]
+    InvalidRoyaltyConfig,
+    #[msg("Max supply is fixed once a provenance hash is committed")]
+    SupplyLockedByProvenance,
+    #[msg("Every committed artwork has already been assigned a serial")]
+    ArtSupplyExhausted,
+    #[msg("Committed reveal slot has not been reached yet")]
+    RevealSlotPending,
+    #[msg("Committed reveal slot has been reached and can still seed the reveal - it cannot be postponed")]
+    RevealSlotReached,
+    #[msg("Committed reveal slot is no longer covered by the SlotHashes sysvar")]
+    RevealSlotExpired,
+    #[msg("NFT update authority has been handed off - minting and metadata changes are closed")]
+    UpdateAuthorityHandedOff,
+    #[msg("Partner creators cannot be removed once NFTs have been minted")]
+    CreatorRemovalAfterMint,
+    #[msg("Reveal offset has not been drawn from the committed slot yet")]
+    RevealNotSeeded,
 }
@@ -13,19 +13,26 @@ use mpl_token_metadata::{
 use solana_program::program::invoke_signed;
 
 use crate::error::ProgramErrorCode;
+use crate::instructions::reservation_policy::require_wallet_capacity;
 use crate::state::*;
 
 // Event definition
 #[event]
 pub struct AdminMintNftEvent {
+    pub campaign_id: u64,
     pub recipient: Pubkey,
     pub mint_address: Pubkey,
     pub admin: Pubkey,
+    pub operator: Option<Pubkey>,
+    pub tier_id: u16,
+    pub serial: u64,
     pub timestamp: i64,
 }
 
 #[derive(Accounts)]
+#[instruction(tier_id: u16)]
 pub struct AdminMintNft<'info> {
+    /// Super admin or airdrop operator
     #[account(mut)]
     pub admin: Signer<'info>,
 
@@ -79,54 +86,126 @@ pub struct AdminMintNft<'info> {
 
     #[account(
         mut,
-        seeds = [b"admin_state".as_ref()],
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
         bump,
-        constraint = admin.key() == admin_state.super_admin @ ProgramErrorCode::Unauthorized
+        constraint = RoleAssignment::authorizes(&admin_state, role_assignment.as_deref(), admin.key(), Role::AirdropOperator)
+            @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.pause_flags.admin_mint @ ProgramErrorCode::ProgramPaused,
+        constraint = admin_state.update_authority_handoff == Pubkey::default() @ ProgramErrorCode::UpdateAuthorityHandedOff,
     )]
     pub admin_state: Box<Account<'info, AdminState>>,
 
-    // === Optional Collection ===
-    /// Optional collection mint account for grouping NFTs - validated in handler
-    pub collection_mint: Option<Box<Account<'info, Mint>>>,
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+        constraint = !collection_state.retired @ ProgramErrorCode::CollectionRetired,
+        constraint = !collection_state.sale_closed @ ProgramErrorCode::SaleClosed,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
 
-    /// CHECK: Optional collection metadata account - validated by PDA derivation in handler if provided
+    // === Collection ===
+    /// Collection mint every NFT is verified into - validated in handler
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Collection metadata account - validated by PDA derivation in handler
     #[account(mut)]
-    pub collection_metadata: Option<UncheckedAccount<'info>>,
+    pub collection_metadata: UncheckedAccount<'info>,
 
-    /// CHECK: Optional collection master edition account - validated by PDA derivation in handler if provided
-    pub collection_master_edition: Option<UncheckedAccount<'info>>,
+    /// CHECK: Collection master edition account - validated by PDA derivation in handler
+    pub collection_master_edition: UncheckedAccount<'info>,
 
     /// CHECK: Sysvar instructions account - required for creator and collection verification
     #[account(address = solana_program::sysvar::instructions::ID)]
     pub sysvar_instructions: UncheckedAccount<'info>,
 
     // === Recipient User State ===
-    /// User state account for the recipient to track if they have already received/minted an NFT
-    /// Uses init_if_needed to support re-minting after burn (when account exists but has_minted = false)
+    /// User state account counting the recipient's reservations against the campaign cap
+    /// Uses init_if_needed so the first mint creates it; the cap is checked in the handler
     /// Solana's transaction atomicity and account locking prevent race conditions
     #[account(
         init_if_needed,
         payer = admin,
         space = UserState::space(),
-        seeds = [b"user_state", recipient.key().as_ref()],
+        seeds = [b"user_state".as_ref(), &campaign_seed(admin_state.campaign_id), recipient.key().as_ref()],
         bump,
-        constraint = !recipient_user_state.has_minted @ ProgramErrorCode::UserAlreadyMinted,
     )]
     pub recipient_user_state: Account<'info, UserState>,
+
+    /// Airdrop operator role assignment - omitted when the super admin signs
+    #[account(
+        seeds = [b"role_assignment".as_ref(), &campaign_seed(admin_state.campaign_id), &Role::AirdropOperator.seed(), admin.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// Airdrop operator quota for this tier - required unless the super admin signs
+    #[account(
+        mut,
+        seeds = [b"operator_quota".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref(), admin.key().as_ref()],
+        bump = operator_quota.bump,
+    )]
+    pub operator_quota: Option<Account<'info, OperatorQuota>>,
+
+    /// Record of this reservation, keyed by the NFT mint
+    #[account(
+        init,
+        payer = admin,
+        space = Reservation::space(),
+        seeds = [b"reservation".as_ref(), &campaign_seed(admin_state.campaign_id), mint.key().as_ref()],
+        bump,
+    )]
+    pub reservation: Box<Account<'info, Reservation>>,
+
+    /// Registry entry for the serial this mint is issued
+    #[account(
+        init,
+        payer = admin,
+        space = SerialRecord::space(),
+        seeds = [b"serial".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref(), collection_state.next_serial().to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub serial_record: Box<Account<'info, SerialRecord>>,
+}
+
+/// Creators written to a collection's NFTs: admin_state first, then the partner creators
+/// admin_state is verified by the caller; partners keep whatever verification `current` shows
+pub(crate) fn collection_creators(
+    collection_config: &CollectionState,
+    admin_state: Pubkey,
+    admin_verified: bool,
+    current: Option<&[Creator]>,
+) -> Vec<Creator> {
+    let partner_verified = |address: &Pubkey| {
+        current.is_some_and(|creators| creators.iter().any(|creator| creator.address == *address && creator.verified))
+    };
+    let mut creators = vec![Creator {
+        address: admin_state,
+        verified: admin_verified,
+        share: collection_config.admin_creator_share(),
+    }];
+    creators.extend(collection_config.creators.iter().map(|creator| Creator {
+        address: creator.address,
+        verified: partner_verified(&creator.address),
+        share: creator.share,
+    }));
+    creators
 }
 
 #[inline(never)]
-fn create_nft_metadata<'info>(
+pub(crate) fn create_nft_metadata<'info>(
     metadata_account: &AccountInfo<'info>,
     mint: &AccountInfo<'info>,
     admin_state: &AccountInfo<'info>,
-    admin_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
     system_program: &AccountInfo<'info>,
     rent: &AccountInfo<'info>,
     name: String,
     symbol: String,
     uri: String,
-    collection_key: Option<Pubkey>,
+    creators: Vec<Creator>,
+    seller_fee_basis_points: u16,
+    collection_key: Pubkey,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
     let create_metadata_ix = CreateMetadataAccountV3 {
@@ -134,7 +213,7 @@ fn create_nft_metadata<'info>(
         mint: mint.key(),
         mint_authority: admin_state.key(),
         update_authority: (admin_state.key(), true),
-        payer: admin_account.key(),
+        payer: payer.key(),
         system_program: system_program.key(),
         rent: Some(rent.key()),
     };
@@ -143,15 +222,11 @@ fn create_nft_metadata<'info>(
         name,
         symbol,
         uri,
-        seller_fee_basis_points: 0,
-        creators: Some(vec![Creator {
-            address: admin_state.key(),
-            verified: false,
-            share: 100,
-        }]),
-        collection: collection_key.map(|key| mpl_token_metadata::types::Collection {
+        seller_fee_basis_points,
+        creators: Some(creators),
+        collection: Some(mpl_token_metadata::types::Collection {
             verified: false,
-            key,
+            key: collection_key,
         }),
         uses: None,
     };
@@ -170,7 +245,7 @@ fn create_nft_metadata<'info>(
             metadata_account.clone(),
             mint.clone(),
             admin_state.clone(),
-            admin_account.clone(),
+            payer.clone(),
             system_program.clone(),
             rent.clone(),
         ],
@@ -181,7 +256,7 @@ fn create_nft_metadata<'info>(
 }
 
 #[inline(never)]
-fn verify_collection<'info>(
+pub(crate) fn verify_collection<'info>(
     metadata_account: &AccountInfo<'info>,
     collection_mint: &AccountInfo<'info>,
     collection_metadata: &AccountInfo<'info>,
@@ -223,7 +298,7 @@ fn verify_collection<'info>(
 }
 
 #[inline(never)]
-fn verify_creator<'info>(
+pub(crate) fn verify_creator<'info>(
     metadata_account: &AccountInfo<'info>,
     admin_state: &AccountInfo<'info>,
     system_program: &AccountInfo<'info>,
@@ -258,93 +333,180 @@ fn verify_creator<'info>(
     Ok(())
 }
 
-pub fn handler(ctx: Context<AdminMintNft>, collection_type: crate::state::CollectionType, name: String, symbol: String, uri: String) -> Result<()> {
-    msg!("Admin minting NFT for collection type: {:?} to recipient: {}", collection_type, ctx.accounts.recipient.key());
+#[inline(never)]
+pub(crate) fn issue_soulbound_token<'info>(
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    recipient_token_account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    admin_state: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    // Create the associated token account for the recipient
+    associated_token::create(CpiContext::new(
+        associated_token_program.clone(),
+        associated_token::Create {
+            payer: payer.clone(),
+            associated_token: recipient_token_account.clone(),
+            authority: recipient.clone(), // The recipient owns the token account
+            mint: mint.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    // Mint one token to the recipient's associated token account
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::MintTo {
+                mint: mint.clone(),
+                to: recipient_token_account.clone(),
+                authority: admin_state.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    // **FREEZE THE TOKEN ACCOUNT TO MAKE IT NON-TRANSFERABLE (SOULBOUND)**
+    // Once frozen, the token account cannot transfer tokens, making the NFT soulbound
+    token::freeze_account(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::FreezeAccount {
+                account: recipient_token_account.clone(),
+                mint: mint.clone(),
+                authority: admin_state.clone(),
+            },
+            signer_seeds,
+        ),
+    )?;
+    msg!("Token account frozen - NFT is now soulbound (non-transferable)");
+
+    // Revoke mint authority to make it a true NFT (no more tokens can be minted)
+    msg!("Revoking mint authority to prevent further minting");
+    let revoke_mint_authority_ix = spl_token::instruction::set_authority(
+        &token_program.key(),
+        &mint.key(),
+        None, // Set to None to revoke authority
+        spl_token::instruction::AuthorityType::MintTokens,
+        &admin_state.key(),
+        &[&admin_state.key()],
+    )?;
+    invoke_signed(
+        &revoke_mint_authority_ix,
+        &[token_program.clone(), mint.clone(), admin_state.clone()],
+        signer_seeds,
+    )?;
+    msg!("Mint authority revoked - this is now a true NFT");
+
+    Ok(())
+}
+
+/// Check the optional collection accounts against the tier's collection mint
+pub(crate) fn validated_collection_key(
+    collection_config: &CollectionState,
+    collection_mint: &Account<Mint>,
+    collection_metadata: &UncheckedAccount,
+    collection_master_edition: &UncheckedAccount,
+) -> Result<Pubkey> {
+    // Validate collection mint properties
+    require!(
+        collection_mint.key() == collection_config.collection_mint,
+        ProgramErrorCode::InvalidCollection
+    );
+    require!(
+        collection_mint.decimals == 0,
+        ProgramErrorCode::InvalidCollectionMint
+    );
+    require!(
+        collection_mint.supply == 1,
+        ProgramErrorCode::InvalidCollectionMint
+    );
+
+    // Validate collection metadata PDA
+    let expected_metadata_key = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            collection_mint.key().as_ref(),
+        ],
+        &mpl_token_metadata::ID,
+    ).0;
+    require!(
+        collection_metadata.key() == expected_metadata_key,
+        ProgramErrorCode::InvalidCollectionMetadata
+    );
+
+    // Validate collection master edition PDA
+    let expected_master_edition_key = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition",
+        ],
+        &mpl_token_metadata::ID,
+    ).0;
+    require!(
+        collection_master_edition.key() == expected_master_edition_key,
+        ProgramErrorCode::InvalidCollectionMasterEdition
+    );
+
+    Ok(collection_mint.key())
+}
+
+pub fn handler(ctx: Context<AdminMintNft>, tier_id: u16) -> Result<()> {
+    msg!("Admin minting NFT for collection tier: {} to recipient: {}", tier_id, ctx.accounts.recipient.key());
+
+    // One reservation per wallet unless the campaign cap allows more
+    require_wallet_capacity(&ctx.accounts.admin_state, ctx.accounts.recipient_user_state.reservation_count)?;
 
-    // Note: has_minted check is now enforced at account constraint level for better security
-    
     // Get the specific collection configuration
-    let collection_config = ctx.accounts.admin_state.get_collection_config(collection_type);
-
-    // Check max supply (0 = unlimited)
-    let max_supply = collection_config.max_supply;
-    if max_supply > 0 {
-        require!(
-            collection_config.current_reserved_count < max_supply,
-            ProgramErrorCode::MaxSupplyReached
-        );
-    }
+    let collection_config = &ctx.accounts.collection_state;
 
-    // Check admin mint limit (0 = admin cannot mint any NFTs)
+    // Check the admin allocation bucket (0 = admin cannot mint any NFTs)
+    // The bucket is carved out of max_supply, so public sales cannot consume it
     let admin_mint_limit = collection_config.admin_mint_limit;
     require!(
         collection_config.current_admin_mint_count < admin_mint_limit,
         ProgramErrorCode::AdminMintLimitReached
     );
 
-    let bump = ctx.bumps.admin_state;
-    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &[bump]]];
-
-    // Validate collection accounts if provided
-    let collection_key = if let Some(collection_mint) = &ctx.accounts.collection_mint {
-        // Validate collection mint properties
-        require!(
-            collection_mint.key() == collection_config.collection_mint,
-            ProgramErrorCode::InvalidCollection
-        );
-        require!(
-            collection_mint.decimals == 0,
-            ProgramErrorCode::InvalidCollectionMint
-        );
-        require!(
-            collection_mint.supply == 1,
-            ProgramErrorCode::InvalidCollectionMint
-        );
-
-        // Validate collection metadata PDA
-        let expected_metadata_key = Pubkey::find_program_address(
-            &[
-                b"metadata",
-                &mpl_token_metadata::ID.as_ref(),
-                &collection_mint.key().as_ref(),
-            ],
-            &mpl_token_metadata::ID,
-        ).0;
-        require!(
-            ctx.accounts.collection_metadata.is_some(),
-            ProgramErrorCode::InvalidCollectionMetadata
-        );
-        let collection_metadata = ctx.accounts.collection_metadata.as_ref().unwrap();
-        require!(
-            collection_metadata.key() == expected_metadata_key,
-            ProgramErrorCode::InvalidCollectionMetadata
-        );
-
-        // Validate collection master edition PDA
-        let expected_master_edition_key = Pubkey::find_program_address(
-            &[
-                b"metadata",
-                &mpl_token_metadata::ID.as_ref(),
-                &collection_mint.key().as_ref(),
-                b"edition",
-            ],
-            &mpl_token_metadata::ID,
-        ).0;
-        require!(
-            ctx.accounts.collection_master_edition.is_some(),
-            ProgramErrorCode::InvalidCollectionMasterEdition
-        );
-        let collection_master_edition = ctx.accounts.collection_master_edition.as_ref().unwrap();
-        require!(
-            collection_master_edition.key() == expected_master_edition_key,
-            ProgramErrorCode::InvalidCollectionMasterEdition
-        );
-
-        Some(collection_mint.key())
-    } else {
+    // Operators draw on their own per-tier quota; the super admin is only bound by the bucket
+    let operator = if ctx.accounts.admin.key() == ctx.accounts.admin_state.super_admin {
         None
+    } else {
+        let operator_quota = ctx
+            .accounts
+            .operator_quota
+            .as_ref()
+            .ok_or(ProgramErrorCode::OperatorQuotaRequired)?;
+        require!(!operator_quota.revoked, ProgramErrorCode::OperatorRevoked);
+        require!(operator_quota.remaining() > 0, ProgramErrorCode::OperatorQuotaExhausted);
+        Some(ctx.accounts.admin.key())
     };
 
+    // Serials are issued in mint order and never reused
+    let serial = collection_config.next_serial();
+    let (name, symbol, uri) = collection_config.nft_metadata(serial)?;
+
+    let bump = ctx.bumps.admin_state;
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[bump]]];
+
+    // Validate the collection accounts
+    let collection_key = validated_collection_key(
+        collection_config,
+        &ctx.accounts.collection_mint,
+        &ctx.accounts.collection_metadata,
+        &ctx.accounts.collection_master_edition,
+    )?;
+
     // Create metadata
     create_nft_metadata(
         &ctx.accounts.metadata_account.to_account_info(),
@@ -356,6 +518,8 @@ pub fn handler(ctx: Context<AdminMintNft>, collection_type: crate::state::Collec
         name,
         symbol,
         uri,
+        collection_creators(collection_config, ctx.accounts.admin_state.key(), false, None),
+        collection_config.royalty_bps,
         collection_key,
         signer_seeds,
     )?;
@@ -369,72 +533,33 @@ pub fn handler(ctx: Context<AdminMintNft>, collection_type: crate::state::Collec
         signer_seeds,
     )?;
 
-    // Verify collection if provided
-    if let (
-        Some(collection_mint),
-        Some(collection_metadata),
-        Some(collection_master_edition),
-    ) = (
-        &ctx.accounts.collection_mint,
-        &ctx.accounts.collection_metadata,
-        &ctx.accounts.collection_master_edition,
-    ) {
-        verify_collection(
-            &ctx.accounts.metadata_account.to_account_info(),
-            &collection_mint.to_account_info(),
-            &collection_metadata.to_account_info(),
-            &collection_master_edition.to_account_info(),
-            &ctx.accounts.admin_state.to_account_info(),
-            &ctx.accounts.system_program.to_account_info(),
-            &ctx.accounts.sysvar_instructions.to_account_info(),
-            signer_seeds,
-        )?;
-    }
-
-    // Create the associated token account for the recipient
-    associated_token::create(CpiContext::new(
-        ctx.accounts.associated_token_program.to_account_info(),
-        associated_token::Create {
-            payer: ctx.accounts.admin.to_account_info(),
-            associated_token: ctx.accounts.recipient_token_account.to_account_info(),
-            authority: ctx.accounts.recipient.to_account_info(), // The recipient owns the token account
-            mint: ctx.accounts.mint.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        },
-    ))?;
-
-    // Mint one token to the recipient's associated token account
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token::MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.recipient_token_account.to_account_info(),
-                authority: ctx.accounts.admin_state.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        1,
+    // Verify the NFT in its collection
+    verify_collection(
+        &ctx.accounts.metadata_account.to_account_info(),
+        &ctx.accounts.collection_mint.to_account_info(),
+        &ctx.accounts.collection_metadata.to_account_info(),
+        &ctx.accounts.collection_master_edition.to_account_info(),
+        &ctx.accounts.admin_state.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.sysvar_instructions.to_account_info(),
+        signer_seeds,
     )?;
 
-    // **FREEZE THE TOKEN ACCOUNT TO MAKE IT NON-TRANSFERABLE (SOULBOUND)**
-    // Once frozen, the token account cannot transfer tokens, making the NFT soulbound
-    token::freeze_account(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token::FreezeAccount {
-                account: ctx.accounts.recipient_token_account.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                authority: ctx.accounts.admin_state.to_account_info(),
-            },
-            signer_seeds,
-        ),
+    // Create the recipient's token account, mint the NFT, freeze it and revoke the mint authority
+    issue_soulbound_token(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        &ctx.accounts.recipient_token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.admin_state.to_account_info(),
+        signer_seeds,
     )?;
-    msg!("Token account frozen - NFT is now soulbound (non-transferable)");
 
     // Increment reserved count for the specific collection
-    let collection_config_mut = ctx.accounts.admin_state.get_collection_config_mut(collection_type);
+    let collection_config_mut = &mut ctx.accounts.collection_state;
     collection_config_mut.current_reserved_count = collection_config_mut
         .current_reserved_count
         .checked_add(1)
@@ -445,56 +570,90 @@ pub fn handler(ctx: Context<AdminMintNft>, collection_type: crate::state::Collec
         .current_admin_mint_count
         .checked_add(1)
         .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+    collection_config_mut.last_serial = serial;
+
+    if operator.is_some() {
+        if let Some(operator_quota) = ctx.accounts.operator_quota.as_mut() {
+            operator_quota.minted_count = operator_quota
+                .minted_count
+                .checked_add(1)
+                .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+            msg!("Operator quota remaining: {}", operator_quota.remaining());
+        }
+    }
 
     msg!(
-        "Collection {:?} - Current reserved count: {}, Current admin mint count: {}",
-        collection_type,
+        "Collection tier {} - Current reserved count: {}, Current admin mint count: {}",
+        tier_id,
         collection_config_mut.current_reserved_count,
         collection_config_mut.current_admin_mint_count
     );
 
-    // Initialize/update recipient user state to prevent them from minting another NFT
+    // Record the reservation and update the recipient's ledger
     let clock = Clock::get()?;
+    let collection_mint = collection_config_mut.collection_mint;
+    let revealed = collection_config_mut.revealed;
+    ctx.accounts.reservation.set_inner(Reservation {
+        wallet: ctx.accounts.recipient.key(),
+        tier_id,
+        mint_address: ctx.accounts.mint.key(),
+        minted_at: clock.unix_timestamp,
+        source: MintSource::Admin,
+        amount_paid: 0,
+        payment_mint: Pubkey::default(),
+        operator,
+        collection_mint,
+        serial,
+        bump: ctx.bumps.reservation,
+    });
+    ctx.accounts.serial_record.set_inner(SerialRecord {
+        tier_id,
+        serial,
+        mint_address: ctx.accounts.mint.key(),
+        wallet: ctx.accounts.recipient.key(),
+        burned: false,
+        revealed,
+        bump: ctx.bumps.serial_record,
+    });
+
+    let reservation_count = ctx
+        .accounts
+        .recipient_user_state
+        .reservation_count
+        .checked_add(1)
+        .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
     ctx.accounts.recipient_user_state.set_inner(UserState {
         user: ctx.accounts.recipient.key(),
         has_minted: true,
-        collection_type,
+        tier_id,
         mint_address: ctx.accounts.mint.key(),
         minted_at: clock.unix_timestamp,
         bump: ctx.bumps.recipient_user_state,
+        source: MintSource::Admin,
+        amount_paid: 0,
+        payment_mint: Pubkey::default(),
+        operator,
+        collection_mint,
+        reservation_count,
+        serial,
     });
 
+    msg!("Serial #{} issued in collection tier {}", serial, tier_id);
     msg!(
-        "Recipient user state initialized - recipient {} can no longer mint/receive NFTs",
-        ctx.accounts.recipient.key()
+        "Recipient user state updated - recipient {} holds {} reservation(s)",
+        ctx.accounts.recipient.key(),
+        reservation_count
     );
 
-    // Revoke mint authority to make it a true NFT (no more tokens can be minted)
-    msg!("Revoking mint authority to prevent further minting");
-    let revoke_mint_authority_ix = spl_token::instruction::set_authority(
-        &ctx.accounts.token_program.key(),
-        &ctx.accounts.mint.key(),
-        None, // Set to None to revoke authority
-        spl_token::instruction::AuthorityType::MintTokens,
-        &ctx.accounts.admin_state.key(),
-        &[&ctx.accounts.admin_state.key()],
-    )?;
-    invoke_signed(
-        &revoke_mint_authority_ix,
-        &[
-            ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.admin_state.to_account_info(),
-        ],
-        &[&[b"admin_state", &[ctx.bumps.admin_state]]],
-    )?;
-    msg!("Mint authority revoked - this is now a true NFT");
-
     // Emit event
     emit!(AdminMintNftEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
         recipient: ctx.accounts.recipient.key(),
         mint_address: ctx.accounts.mint.key(),
         admin: ctx.accounts.admin.key(),
+        operator,
+        tier_id,
+        serial,
         timestamp: clock.unix_timestamp,
     });
 
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::accounts::Metadata;
+
+use crate::error::ProgramErrorCode;
+use crate::instructions::update_nft_metadata::{read_metadata, update_metadata_data};
+use crate::state::*;
+
+/// Accounts each NFT supplies to the handoff crank through remaining_accounts, in this order:
+/// mint, metadata account
+pub const HANDOFF_NFT_ACCOUNTS: usize = 2;
+
+/// Upper bound on metadata accounts handed off per crank call - each one is a Metaplex CPI
+pub const MAX_HANDOFF_BATCH: usize = 5;
+
+// Event definitions
+#[event]
+pub struct UpdateAuthorityHandoffStartedEvent {
+    pub campaign_id: u64,
+    pub new_update_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdateAuthorityHandoffCrankEvent {
+    pub campaign_id: u64,
+    pub new_update_authority: Pubkey,
+    pub cranker: Pubkey,
+    pub handed_off: u8,
+    pub total_handed_off: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct HandOffUpdateAuthority<'info> {
+    /// Anyone can crank once the handoff has been executed
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program - validated by address
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.update_authority_handoff != Pubkey::default() @ ProgramErrorCode::NoUpdateAuthorityHandoff,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    /// CHECK: Sysvar instructions account - required by Metaplex UpdateV1
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+/// Checked when the handoff is queued or proposed - it must wait out a non-zero config delay
+pub(crate) fn validate_update_authority_handoff(
+    admin_state: &AdminState,
+    admin_state_key: Pubkey,
+    new_update_authority: Pubkey,
+) -> Result<()> {
+    require!(admin_state.config_delay > 0, ProgramErrorCode::HandoffRequiresTimelock);
+    require!(
+        new_update_authority != Pubkey::default() && new_update_authority != admin_state_key,
+        ProgramErrorCode::InvalidHandoffTarget
+    );
+    require!(
+        admin_state.update_authority_handoff == Pubkey::default(),
+        ProgramErrorCode::UpdateAuthorityHandoffStarted
+    );
+    Ok(())
+}
+
+/// Shared by timelocked config changes and multisig proposals - a campaign hands off at most once
+pub(crate) fn apply_update_authority_handoff(admin_state: &mut AdminState, new_update_authority: Pubkey, now: i64) -> Result<()> {
+    require!(
+        admin_state.update_authority_handoff == Pubkey::default(),
+        ProgramErrorCode::UpdateAuthorityHandoffStarted
+    );
+    admin_state.update_authority_handoff = new_update_authority;
+    admin_state.update_authority_handoff_at = now;
+
+    msg!("Update authority handoff to {} started", new_update_authority);
+
+    emit!(UpdateAuthorityHandoffStartedEvent {
+        campaign_id: admin_state.campaign_id,
+        new_update_authority,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, HandOffUpdateAuthority<'info>>) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    let requested = remaining.len() / HANDOFF_NFT_ACCOUNTS;
+    require!(
+        requested > 0 && requested * HANDOFF_NFT_ACCOUNTS == remaining.len(),
+        ProgramErrorCode::InvalidHandoffAccounts
+    );
+    require!(requested <= MAX_HANDOFF_BATCH, ProgramErrorCode::InvalidHandoffAccounts);
+
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let bump = ctx.bumps.admin_state;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[bump]]];
+
+    let new_update_authority = ctx.accounts.admin_state.update_authority_handoff;
+    let payer = ctx.accounts.payer.to_account_info();
+    let admin_state = ctx.accounts.admin_state.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let sysvar_instructions = ctx.accounts.sysvar_instructions.to_account_info();
+
+    let mut handed_off: u8 = 0;
+    for accounts in remaining.chunks(HANDOFF_NFT_ACCOUNTS) {
+        let [mint, metadata_account] = accounts else {
+            return err!(ProgramErrorCode::InvalidHandoffAccounts);
+        };
+        require!(
+            metadata_account.key() == Metadata::find_pda(mint.key).0,
+            ProgramErrorCode::InvalidHandoffAccounts
+        );
+
+        // Covers collection and reservation NFTs alike - anything this admin_state still controls
+        let metadata = read_metadata(metadata_account)?;
+        if metadata.update_authority == new_update_authority {
+            msg!("Skipping {} - already handed off", mint.key());
+            continue;
+        }
+        require!(metadata.update_authority == admin_state.key(), ProgramErrorCode::InvalidHandoffAccounts);
+
+        update_metadata_data(
+            metadata_account,
+            mint,
+            &admin_state,
+            &payer,
+            &system_program,
+            &sysvar_instructions,
+            None,
+            None,
+            Some(new_update_authority),
+            signer_seeds,
+        )?;
+        handed_off += 1;
+    }
+
+    let admin_state = &mut ctx.accounts.admin_state;
+    admin_state.handed_off_count = admin_state.handed_off_count.saturating_add(handed_off as u64);
+
+    msg!(
+        "Handed {} metadata accounts to {} ({} total)",
+        handed_off,
+        new_update_authority,
+        admin_state.handed_off_count
+    );
+
+    emit!(UpdateAuthorityHandoffCrankEvent {
+        campaign_id: admin_state.campaign_id,
+        new_update_authority,
+        cranker: ctx.accounts.payer.key(),
+        handed_off,
+        total_handed_off: admin_state.handed_off_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,500 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::{
+    associated_token::{get_associated_token_address, AssociatedToken},
+    token::{self, Token, Mint},
+};
+
+use crate::error::ProgramErrorCode;
+use crate::instructions::reservation_policy::require_wallet_capacity;
+use crate::instructions::admin_mint_nft::{
+    collection_creators, create_nft_metadata, issue_soulbound_token, validated_collection_key, verify_collection, verify_creator,
+    AdminMintNftEvent,
+};
+use crate::state::*;
+
+/// Accounts each recipient supplies through remaining_accounts, in this order:
+/// recipient, mint (new keypair, signer), recipient token account, metadata account, recipient user state,
+/// reservation, serial record
+/// Serial records are used in order by the recipients actually minted, so the nth entry should carry
+/// the PDA of the nth next serial whether or not its own recipient gets skipped
+pub const BATCH_ADMIN_MINT_ACCOUNTS: usize = 7;
+
+/// Upper bound on recipients per batch - transaction size and compute run out well before this
+pub const MAX_BATCH_ADMIN_MINT: usize = 8;
+
+// Event definitions
+#[event]
+pub struct AdminMintSkippedEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchAdminMintEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub admin: Pubkey,
+    pub operator: Option<Pubkey>,
+    pub requested: u8,
+    pub minted: u8,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct BatchAdminMintNft<'info> {
+    /// Super admin or airdrop operator
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Metaplex Token Metadata program - validated by address
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = RoleAssignment::authorizes(&admin_state, role_assignment.as_deref(), admin.key(), Role::AirdropOperator)
+            @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.pause_flags.admin_mint @ ProgramErrorCode::ProgramPaused,
+        constraint = admin_state.update_authority_handoff == Pubkey::default() @ ProgramErrorCode::UpdateAuthorityHandedOff,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+        constraint = !collection_state.retired @ ProgramErrorCode::CollectionRetired,
+        constraint = !collection_state.sale_closed @ ProgramErrorCode::SaleClosed,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    // === Collection ===
+    /// Collection mint every NFT is verified into - validated in handler
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Collection metadata account - validated by PDA derivation in handler
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition account - validated by PDA derivation in handler
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Sysvar instructions account - required for creator and collection verification
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// Airdrop operator role assignment - omitted when the super admin signs
+    #[account(
+        seeds = [b"role_assignment".as_ref(), &campaign_seed(admin_state.campaign_id), &Role::AirdropOperator.seed(), admin.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// Airdrop operator quota for this tier - required unless the super admin signs
+    #[account(
+        mut,
+        seeds = [b"operator_quota".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref(), admin.key().as_ref()],
+        bump = operator_quota.bump,
+    )]
+    pub operator_quota: Option<Account<'info, OperatorQuota>>,
+}
+
+/// One validated entry of the batch
+struct BatchRecipient<'info> {
+    recipient: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    recipient_token_account: AccountInfo<'info>,
+    metadata_account: AccountInfo<'info>,
+    user_state: AccountInfo<'info>,
+    user_state_bump: u8,
+    reservation: AccountInfo<'info>,
+    reservation_bump: u8,
+}
+
+/// Create a program-owned PDA, tolerating lamports that were sent to the address beforehand
+pub(crate) fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: account.clone() },
+                signer_seeds,
+            ),
+            rent_exempt_minimum,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let shortfall = rent_exempt_minimum.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            shortfall,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign { account_to_assign: account.clone() },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
+
+/// Create a 0-decimal mint for a recipient with admin_state as mint and freeze authority
+fn create_mint_account<'info>(
+    payer: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    admin_state: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(Mint::LEN);
+    create_account(
+        CpiContext::new(
+            system_program.clone(),
+            CreateAccount { from: payer.clone(), to: mint.clone() },
+        ),
+        rent_exempt_minimum,
+        Mint::LEN as u64,
+        token_program.key,
+    )?;
+    token::initialize_mint2(
+        CpiContext::new(token_program.clone(), token::InitializeMint2 { mint: mint.clone() }),
+        0,
+        admin_state.key,
+        Some(admin_state.key),
+    )
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, BatchAdminMintNft<'info>>,
+    tier_id: u16,
+) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    let requested = remaining.len() / BATCH_ADMIN_MINT_ACCOUNTS;
+    require!(
+        requested > 0 && requested * BATCH_ADMIN_MINT_ACCOUNTS == remaining.len(),
+        ProgramErrorCode::InvalidBatchAccounts
+    );
+    require!(requested <= MAX_BATCH_ADMIN_MINT, ProgramErrorCode::InvalidBatchAccounts);
+
+    msg!("Admin batch minting {} NFTs for collection tier: {}", requested, tier_id);
+
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let clock = Clock::get()?;
+
+    // Validate every entry before anything is created; recipients at the wallet reservation
+    // cap (counting earlier entries for the same wallet) are skipped rather than failing the batch
+    let mut recipients: Vec<BatchRecipient<'info>> = Vec::with_capacity(requested);
+    let mut serial_records: Vec<AccountInfo<'info>> = Vec::with_capacity(requested);
+    for accounts in remaining.chunks(BATCH_ADMIN_MINT_ACCOUNTS) {
+        let [recipient, mint, recipient_token_account, metadata_account, user_state, reservation, serial_record] = accounts else {
+            return err!(ProgramErrorCode::InvalidBatchAccounts);
+        };
+        require!(serial_record.is_writable, ProgramErrorCode::InvalidBatchAccounts);
+        serial_records.push(serial_record.clone());
+
+        require!(mint.is_signer && mint.is_writable, ProgramErrorCode::InvalidBatchAccounts);
+        require!(mint.data_is_empty(), ProgramErrorCode::InvalidBatchAccounts);
+        require!(
+            recipient_token_account.key() == get_associated_token_address(recipient.key, mint.key),
+            ProgramErrorCode::InvalidBatchAccounts
+        );
+        let expected_metadata_key = Pubkey::find_program_address(
+            &[b"metadata", mpl_token_metadata::ID.as_ref(), mint.key.as_ref()],
+            &mpl_token_metadata::ID,
+        ).0;
+        require!(metadata_account.key() == expected_metadata_key, ProgramErrorCode::InvalidBatchAccounts);
+        let (expected_user_state, user_state_bump) = Pubkey::find_program_address(
+            &[b"user_state", &campaign_seed, recipient.key.as_ref()],
+            &crate::ID,
+        );
+        require!(user_state.key() == expected_user_state, ProgramErrorCode::InvalidBatchAccounts);
+        require!(user_state.is_writable, ProgramErrorCode::InvalidBatchAccounts);
+        let (expected_reservation, reservation_bump) = Pubkey::find_program_address(
+            &[b"reservation", &campaign_seed, mint.key.as_ref()],
+            &crate::ID,
+        );
+        require!(reservation.key() == expected_reservation, ProgramErrorCode::InvalidBatchAccounts);
+        require!(reservation.is_writable, ProgramErrorCode::InvalidBatchAccounts);
+
+        // Reservations the recipient holds plus the ones planned earlier in this batch
+        let held = if user_state.data_is_empty() {
+            0
+        } else {
+            require!(user_state.owner == &crate::ID, ProgramErrorCode::InvalidBatchAccounts);
+            UserState::try_deserialize(&mut &user_state.try_borrow_data()?[..])?.reservation_count
+        };
+        let planned = recipients.iter().filter(|entry| entry.recipient.key == recipient.key).count() as u16;
+
+        if require_wallet_capacity(&ctx.accounts.admin_state, held.saturating_add(planned)).is_err() {
+            msg!("Skipping recipient {} - wallet reservation cap reached", recipient.key());
+            emit!(AdminMintSkippedEvent {
+                campaign_id,
+                tier_id,
+                recipient: recipient.key(),
+                timestamp: clock.unix_timestamp,
+            });
+            continue;
+        }
+
+        recipients.push(BatchRecipient {
+            recipient: recipient.clone(),
+            mint: mint.clone(),
+            recipient_token_account: recipient_token_account.clone(),
+            metadata_account: metadata_account.clone(),
+            user_state: user_state.clone(),
+            user_state_bump,
+            reservation: reservation.clone(),
+            reservation_bump,
+        });
+    }
+    let minted = recipients.len() as u64;
+
+    // Enforce the admin allocation bucket once for the whole batch
+    let collection_config = &ctx.accounts.collection_state;
+    let admin_mint_count_after = collection_config
+        .current_admin_mint_count
+        .checked_add(minted)
+        .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+    require!(
+        admin_mint_count_after <= collection_config.admin_mint_limit,
+        ProgramErrorCode::AdminMintLimitReached
+    );
+
+    // Operators draw on their own per-tier quota; the super admin is only bound by the bucket
+    let operator = if ctx.accounts.admin.key() == ctx.accounts.admin_state.super_admin {
+        None
+    } else {
+        let operator_quota = ctx
+            .accounts
+            .operator_quota
+            .as_ref()
+            .ok_or(ProgramErrorCode::OperatorQuotaRequired)?;
+        require!(!operator_quota.revoked, ProgramErrorCode::OperatorRevoked);
+        require!(operator_quota.remaining() >= minted, ProgramErrorCode::OperatorQuotaExhausted);
+        Some(ctx.accounts.admin.key())
+    };
+
+    let collection_key = validated_collection_key(
+        collection_config,
+        &ctx.accounts.collection_mint,
+        &ctx.accounts.collection_metadata,
+        &ctx.accounts.collection_master_edition,
+    )?;
+
+    let bump = ctx.bumps.admin_state;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[bump]]];
+
+    let admin = ctx.accounts.admin.to_account_info();
+    let admin_state = ctx.accounts.admin_state.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let associated_token_program = ctx.accounts.associated_token_program.to_account_info();
+    let rent = ctx.accounts.rent.to_account_info();
+    let sysvar_instructions = ctx.accounts.sysvar_instructions.to_account_info();
+
+    let mut serial = collection_config.last_serial;
+    for (entry, serial_record) in recipients.iter().zip(serial_records.iter()) {
+        // Serials are issued in mint order and never reused
+        serial = serial.checked_add(1).ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+        let serial_seed = serial.to_le_bytes();
+        let (expected_serial_record, serial_record_bump) = Pubkey::find_program_address(
+            &[b"serial", &campaign_seed, &tier_id.to_le_bytes(), &serial_seed],
+            &crate::ID,
+        );
+        require!(serial_record.key() == expected_serial_record, ProgramErrorCode::InvalidSerialRecord);
+
+        create_mint_account(&admin, &entry.mint, &admin_state, &system_program, &token_program)?;
+
+        let (name, symbol, uri) = ctx.accounts.collection_state.nft_metadata(serial)?;
+        create_nft_metadata(
+            &entry.metadata_account,
+            &entry.mint,
+            &admin_state,
+            &admin,
+            &system_program,
+            &rent,
+            name,
+            symbol,
+            uri,
+            collection_creators(&ctx.accounts.collection_state, admin_state.key(), false, None),
+            ctx.accounts.collection_state.royalty_bps,
+            collection_key,
+            signer_seeds,
+        )?;
+
+        // Verify creator (admin_state PDA is the creator)
+        verify_creator(&entry.metadata_account, &admin_state, &system_program, &sysvar_instructions, signer_seeds)?;
+
+        // Verify the NFT in its collection
+        verify_collection(
+            &entry.metadata_account,
+            &ctx.accounts.collection_mint.to_account_info(),
+            &ctx.accounts.collection_metadata.to_account_info(),
+            &ctx.accounts.collection_master_edition.to_account_info(),
+            &admin_state,
+            &system_program,
+            &sysvar_instructions,
+            signer_seeds,
+        )?;
+
+        issue_soulbound_token(
+            &token_program,
+            &associated_token_program,
+            &system_program,
+            &admin,
+            &entry.recipient,
+            &entry.recipient_token_account,
+            &entry.mint,
+            &admin_state,
+            signer_seeds,
+        )?;
+
+        // Record the reservation and update the recipient's ledger
+        let reservation_signer_seeds: &[&[&[u8]]] =
+            &[&[b"reservation", &campaign_seed, entry.mint.key.as_ref(), &[entry.reservation_bump]]];
+        create_pda_account(&admin, &entry.reservation, &system_program, Reservation::space(), reservation_signer_seeds)?;
+        let reservation = Reservation {
+            wallet: entry.recipient.key(),
+            tier_id,
+            mint_address: entry.mint.key(),
+            minted_at: clock.unix_timestamp,
+            source: MintSource::Admin,
+            amount_paid: 0,
+            payment_mint: Pubkey::default(),
+            operator,
+            collection_mint: ctx.accounts.collection_state.collection_mint,
+            serial,
+            bump: entry.reservation_bump,
+        };
+        reservation.try_serialize(&mut &mut entry.reservation.try_borrow_mut_data()?[..])?;
+
+        let serial_record_signer_seeds: &[&[&[u8]]] =
+            &[&[b"serial", &campaign_seed, &tier_id.to_le_bytes(), &serial_seed, &[serial_record_bump]]];
+        create_pda_account(&admin, serial_record, &system_program, SerialRecord::space(), serial_record_signer_seeds)?;
+        let record = SerialRecord {
+            tier_id,
+            serial,
+            mint_address: entry.mint.key(),
+            wallet: entry.recipient.key(),
+            burned: false,
+            revealed: ctx.accounts.collection_state.revealed,
+            bump: serial_record_bump,
+        };
+        record.try_serialize(&mut &mut serial_record.try_borrow_mut_data()?[..])?;
+
+        // A recipient listed twice sees the count written for its earlier entry
+        let reservation_count = if entry.user_state.data_is_empty() {
+            let user_state_signer_seeds: &[&[&[u8]]] =
+                &[&[b"user_state", &campaign_seed, entry.recipient.key.as_ref(), &[entry.user_state_bump]]];
+            create_pda_account(&admin, &entry.user_state, &system_program, UserState::space(), user_state_signer_seeds)?;
+            1
+        } else {
+            UserState::try_deserialize(&mut &entry.user_state.try_borrow_data()?[..])?
+                .reservation_count
+                .checked_add(1)
+                .ok_or(ProgramErrorCode::ReservedCountOverflow)?
+        };
+        let user_state = UserState {
+            user: entry.recipient.key(),
+            has_minted: true,
+            tier_id,
+            mint_address: entry.mint.key(),
+            minted_at: clock.unix_timestamp,
+            bump: entry.user_state_bump,
+            source: MintSource::Admin,
+            amount_paid: 0,
+            payment_mint: Pubkey::default(),
+            operator,
+            collection_mint: ctx.accounts.collection_state.collection_mint,
+            reservation_count,
+            serial,
+        };
+        user_state.try_serialize(&mut &mut entry.user_state.try_borrow_mut_data()?[..])?;
+
+        emit!(AdminMintNftEvent {
+            campaign_id,
+            recipient: entry.recipient.key(),
+            mint_address: entry.mint.key(),
+            admin: admin.key(),
+            operator,
+            tier_id,
+            serial,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Update the collection and operator counters once for the batch
+    let collection_config_mut = &mut ctx.accounts.collection_state;
+    collection_config_mut.current_reserved_count = collection_config_mut
+        .current_reserved_count
+        .checked_add(minted)
+        .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+    collection_config_mut.current_admin_mint_count = admin_mint_count_after;
+    collection_config_mut.last_serial = serial;
+
+    if operator.is_some() {
+        if let Some(operator_quota) = ctx.accounts.operator_quota.as_mut() {
+            operator_quota.minted_count = operator_quota
+                .minted_count
+                .checked_add(minted)
+                .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+            msg!("Operator quota remaining: {}", operator_quota.remaining());
+        }
+    }
+
+    msg!(
+        "Collection tier {} - batch minted {} of {}, current admin mint count: {}",
+        tier_id,
+        minted,
+        requested,
+        collection_config_mut.current_admin_mint_count
+    );
+
+    emit!(BatchAdminMintEvent {
+        campaign_id,
+        tier_id,
+        admin: admin.key(),
+        operator,
+        requested: requested as u8,
+        minted: minted as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
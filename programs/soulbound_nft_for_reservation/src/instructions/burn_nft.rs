@@ -12,9 +12,14 @@ use crate::error::ProgramErrorCode;
 // Event definition
 #[event]
 pub struct BurnNftEvent {
+    pub campaign_id: u64,
     pub user: Pubkey,
     pub mint_address: Pubkey,
-    pub collection_type: CollectionType,
+    pub tier_id: u16,
+    pub source: MintSource,
+    pub amount_paid: u64,
+    pub payment_mint: Pubkey,
+    pub serial: u64,
     pub timestamp: i64,
 }
 
@@ -27,40 +32,91 @@ pub struct BurnNft<'info> {
     /// CHECK: Validated in handler that this is the correct ATA
     #[account(mut)]
     pub old_token_account: UncheckedAccount<'info>,
-    /// CHECK: Validated in handler that this matches user_state.mint_address
+    /// CHECK: Validated through the reservation PDA derived from it
     #[account(mut)]
     pub old_mint: UncheckedAccount<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     #[account( 
         mut, 
-        seeds = [b"admin_state".as_ref()],
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
         bump,
+        constraint = !admin_state.pause_flags.burn @ ProgramErrorCode::ProgramPaused,
     )]
     pub admin_state: Box<Account<'info, AdminState>>,
 
-    /// User state account to reset after burning
+    /// Reservation of the NFT being burned - closed and its rent returned to the signer
     #[account(
         mut,
-        seeds = [b"user_state", signer.key().as_ref()],
+        close = signer,
+        seeds = [b"reservation".as_ref(), &campaign_seed(admin_state.campaign_id), old_mint.key().as_ref()],
+        bump = reservation.bump,
+        constraint = reservation.wallet == signer.key() @ ProgramErrorCode::InvalidReservation,
+    )]
+    pub reservation: Box<Account<'info, Reservation>>,
+
+    /// Collection the NFT was minted in - derived from reservation.tier_id
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), reservation.tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    /// User state account whose reservation count is decremented
+    #[account(
+        mut,
+        seeds = [b"user_state".as_ref(), &campaign_seed(admin_state.campaign_id), signer.key().as_ref()],
         bump,
         constraint = user_state.user == signer.key() @ ProgramErrorCode::InvalidUserState,
-        constraint = user_state.has_minted @ ProgramErrorCode::UserHasNotMinted,
+        constraint = user_state.reservation_count > 0 @ ProgramErrorCode::UserHasNotMinted,
     )]
     pub user_state: Account<'info, UserState>,
+
+    /// Per-wallet collection counters - pass it to free a slot under the collection wallet cap
+    #[account(
+        mut,
+        seeds = [b"wallet_mint_state".as_ref(), &campaign_seed(admin_state.campaign_id), reservation.tier_id.to_le_bytes().as_ref(), signer.key().as_ref()],
+        bump = wallet_mint_state.bump,
+    )]
+    pub wallet_mint_state: Option<Box<Account<'info, WalletMintState>>>,
+
+    /// Registry entry of the burned NFT's serial - required unless it was minted before serials
+    #[account(
+        mut,
+        seeds = [b"serial".as_ref(), &campaign_seed(admin_state.campaign_id), reservation.tier_id.to_le_bytes().as_ref(), reservation.serial.to_le_bytes().as_ref()],
+        bump = serial_record.bump,
+    )]
+    pub serial_record: Option<Box<Account<'info, SerialRecord>>>,
 }
 
 pub fn handler(ctx: Context<BurnNft>) -> Result<()> {
-    // Get collection type from user state (single source of truth)
-    let collection_type = ctx.accounts.user_state.collection_type;
+    // Get tier id from the reservation (single source of truth)
+    let tier_id = ctx.accounts.reservation.tier_id;
     
-    msg!("Burn NFT process started for collection type: {:?}", collection_type);
+    msg!("Burn NFT process started for collection tier: {}", tier_id);
 
-    // Validate that the mint being burned matches the user's recorded mint
+    // The reservation PDA is derived from the mint, so its address proves the match
     require!(
-        ctx.accounts.old_mint.key() == ctx.accounts.user_state.mint_address,
+        ctx.accounts.old_mint.key() == ctx.accounts.reservation.mint_address,
         ProgramErrorCode::InvalidMint
     );
-    msg!("Validated mint matches user state");
+    msg!("Validated mint matches reservation");
+
+    // Serials stay registered once issued; the record only flips to burned
+    let serial = ctx.accounts.reservation.serial;
+    if serial > 0 {
+        let serial_record = ctx
+            .accounts
+            .serial_record
+            .as_mut()
+            .ok_or(ProgramErrorCode::InvalidSerialRecord)?;
+        require!(
+            serial_record.mint_address == ctx.accounts.old_mint.key(),
+            ProgramErrorCode::InvalidSerialRecord
+        );
+        serial_record.burned = true;
+        msg!("Serial #{} marked burned", serial);
+    }
 
     // Validate that old_token_account is the correct associated token account for the signer
     let expected_ata = get_associated_token_address_with_program_id(
@@ -78,7 +134,8 @@ pub fn handler(ctx: Context<BurnNft>) -> Result<()> {
     // **UNFREEZE THE TOKEN ACCOUNT FIRST** (Required for soulbound NFTs)
     // The account was frozen to make it non-transferable, but we need to unfreeze it to burn it
     let bump = ctx.bumps.admin_state;
-    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &[bump]]];
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[bump]]];
     
     token::thaw_account(
         CpiContext::new_with_signer(
@@ -122,39 +179,86 @@ pub fn handler(ctx: Context<BurnNft>) -> Result<()> {
     // with supply = 0. This is standard behavior for NFT burns on Solana.
     
     // Decrement reserved count for the specific collection with underflow protection
-    let collection_config = ctx.accounts.admin_state.get_collection_config_mut(collection_type);
+    let source = ctx.accounts.reservation.source;
+    let collection_config = &mut ctx.accounts.collection_state;
+
+    // An NFT burned before the migration crank reached it no longer needs migrating
+    if collection_config.migrating_from.is_some()
+        && ctx.accounts.reservation.collection_mint != collection_config.collection_mint
+    {
+        collection_config.migration_pending = collection_config.migration_pending.saturating_sub(1);
+    }
     collection_config.current_reserved_count = collection_config
         .current_reserved_count
         .checked_sub(1)
         .ok_or(ProgramErrorCode::ReservedCountUnderflow)?;
-    
+
+    // Return the slot to the bucket it was taken from
+    match source {
+        MintSource::Public => {
+            collection_config.public_minted_count = collection_config
+                .public_minted_count
+                .checked_sub(1)
+                .ok_or(ProgramErrorCode::ReservedCountUnderflow)?;
+            // Mints made before the counter existed were never counted, hence saturating
+            if let Some(wallet_mint_state) = ctx.accounts.wallet_mint_state.as_mut() {
+                wallet_mint_state.reserved_count = wallet_mint_state.reserved_count.saturating_sub(1);
+            }
+        }
+        MintSource::Admin => {
+            collection_config.current_admin_mint_count = collection_config
+                .current_admin_mint_count
+                .checked_sub(1)
+                .ok_or(ProgramErrorCode::ReservedCountUnderflow)?;
+        }
+        MintSource::Legacy => {
+            // Migration counted every legacy mint beyond the admin count as public
+            collection_config.public_minted_count = collection_config.public_minted_count.saturating_sub(1);
+        }
+    }
+
     msg!(
-        "Collection {:?} reserved count decremented to: {}",
-        collection_type,
-        collection_config.current_reserved_count
+        "Collection tier {} reserved count decremented to: {} (public: {}, admin: {})",
+        tier_id,
+        collection_config.current_reserved_count,
+        collection_config.public_minted_count,
+        collection_config.current_admin_mint_count
     );
 
-    // Reset user state to allow minting again
-    ctx.accounts.user_state.has_minted = false;
-    ctx.accounts.user_state.mint_address = Pubkey::default();
-    ctx.accounts.user_state.minted_at = 0;
-    // Keep user, collection_type, and bump unchanged for reference
+    // Free the wallet's slot; the latest-mint fields are cleared when they describe this NFT
+    let user_state = &mut ctx.accounts.user_state;
+    user_state.reservation_count = user_state
+        .reservation_count
+        .checked_sub(1)
+        .ok_or(ProgramErrorCode::ReservedCountUnderflow)?;
+    user_state.has_minted = user_state.reservation_count > 0;
+    if user_state.mint_address == ctx.accounts.old_mint.key() {
+        user_state.mint_address = Pubkey::default();
+        user_state.minted_at = 0;
+    }
+    // Keep user, tier_id, bump and the burned NFT's provenance unchanged for reference
     
     msg!(
-        "User state reset - user {} can now mint again",
-        ctx.accounts.signer.key()
+        "User state updated - user {} holds {} reservation(s)",
+        ctx.accounts.signer.key(),
+        user_state.reservation_count
     );
 
     // Emit burn event
     let clock = Clock::get()?;
     emit!(BurnNftEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
         user: ctx.accounts.signer.key(),
         mint_address: ctx.accounts.old_mint.key(),
-        collection_type,
+        tier_id,
+        source,
+        amount_paid: ctx.accounts.reservation.amount_paid,
+        payment_mint: ctx.accounts.reservation.payment_mint,
+        serial,
         timestamp: clock.unix_timestamp,
     });
 
-    msg!("NFT burn complete for collection {:?}", collection_type);
+    msg!("NFT burn complete for collection tier {}", tier_id);
 
     Ok(())
 }
@@ -0,0 +1,260 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use mpl_token_metadata::{accounts::Metadata, instructions::UnverifyCollectionV1};
+use solana_program::program::invoke_signed;
+
+use crate::error::ProgramErrorCode;
+use crate::instructions::admin_mint_nft::{validated_collection_key, verify_collection};
+use crate::state::*;
+
+/// Accounts each NFT supplies to the migration crank through remaining_accounts, in this order:
+/// reservation, mint, metadata account
+pub const MIGRATE_NFT_ACCOUNTS: usize = 3;
+
+/// Upper bound on NFTs migrated per crank call - each one is two Metaplex CPIs
+pub const MAX_MIGRATION_BATCH: usize = 4;
+
+// Event definitions
+#[event]
+pub struct CollectionMigrationEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub old_collection_mint: Pubkey,
+    pub new_collection_mint: Pubkey,
+    pub cranker: Pubkey,
+    pub migrated: u8,
+    pub total_migrated: u64,
+    pub remaining: u64,                  // Live NFTs still in the old collection
+    pub completed: bool,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct MigrateCollectionNfts<'info> {
+    /// Anyone can crank - pays for the migration account and any metadata reallocation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program - validated by address
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+        constraint = admin_state.update_authority_handoff == Pubkey::default() @ ProgramErrorCode::UpdateAuthorityHandedOff,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+        constraint = collection_state.migrating_from.is_some() @ ProgramErrorCode::NoCollectionMigration,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    /// Progress of the tier's migration - reused by later collection mint replacements
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CollectionMigration::space(),
+        seeds = [b"collection_migration".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub collection_migration: Box<Account<'info, CollectionMigration>>,
+
+    /// CHECK: Replaced collection mint - validated against collection_state.migrating_from
+    #[account(constraint = collection_state.migrating_from == Some(old_collection_mint.key()) @ ProgramErrorCode::InvalidCollectionMint)]
+    pub old_collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Replaced collection's metadata account - validated as its metadata PDA
+    #[account(
+        mut,
+        address = Metadata::find_pda(&old_collection_mint.key()).0 @ ProgramErrorCode::InvalidCollectionMetadata,
+    )]
+    pub old_collection_metadata: UncheckedAccount<'info>,
+
+    /// Current collection mint NFTs are verified into
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Current collection metadata account - validated in handler
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Current collection master edition account - validated in handler
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Sysvar instructions account - required by Metaplex verify and unverify
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[inline(never)]
+fn unverify_collection<'info>(
+    metadata_account: &AccountInfo<'info>,
+    collection_mint: &AccountInfo<'info>,
+    collection_metadata: &AccountInfo<'info>,
+    admin_state: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    sysvar_instructions: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let unverify_collection_ix = UnverifyCollectionV1 {
+        authority: admin_state.key(),
+        delegate_record: None,
+        metadata: metadata_account.key(),
+        collection_mint: collection_mint.key(),
+        collection_metadata: Some(collection_metadata.key()),
+        system_program: system_program.key(),
+        sysvar_instructions: sysvar_instructions.key(),
+    };
+
+    invoke_signed(
+        &unverify_collection_ix.instruction(),
+        &[
+            admin_state.clone(),
+            metadata_account.clone(),
+            collection_mint.clone(),
+            collection_metadata.clone(),
+            system_program.clone(),
+            sysvar_instructions.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, MigrateCollectionNfts<'info>>, tier_id: u16) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    let requested = remaining.len() / MIGRATE_NFT_ACCOUNTS;
+    require!(
+        requested * MIGRATE_NFT_ACCOUNTS == remaining.len(),
+        ProgramErrorCode::InvalidMigrationAccounts
+    );
+    require!(requested <= MAX_MIGRATION_BATCH, ProgramErrorCode::InvalidMigrationAccounts);
+
+    let old_collection_mint = ctx.accounts.old_collection_mint.key();
+    let new_collection_mint = validated_collection_key(
+        &ctx.accounts.collection_state,
+        &ctx.accounts.collection_mint,
+        &ctx.accounts.collection_metadata,
+        &ctx.accounts.collection_master_edition,
+    )?;
+
+    // A migration account left over from an earlier replacement starts over
+    let now = Clock::get()?.unix_timestamp;
+    let migration = &mut ctx.accounts.collection_migration;
+    if migration.old_collection_mint != old_collection_mint || migration.new_collection_mint != new_collection_mint {
+        migration.tier_id = tier_id;
+        migration.old_collection_mint = old_collection_mint;
+        migration.new_collection_mint = new_collection_mint;
+        migration.migrated_count = 0;
+        migration.started_at = now;
+        migration.completed = false;
+        migration.bump = ctx.bumps.collection_migration;
+    }
+
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let bump = ctx.bumps.admin_state;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[bump]]];
+
+    let admin_state = ctx.accounts.admin_state.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let sysvar_instructions = ctx.accounts.sysvar_instructions.to_account_info();
+    let old_collection_mint_info = ctx.accounts.old_collection_mint.to_account_info();
+    let old_collection_metadata = ctx.accounts.old_collection_metadata.to_account_info();
+
+    let mut migrated: u8 = 0;
+    for accounts in remaining.chunks(MIGRATE_NFT_ACCOUNTS) {
+        let [reservation_info, mint, metadata_account] = accounts else {
+            return err!(ProgramErrorCode::InvalidMigrationAccounts);
+        };
+
+        // The reservation must be this tier's record for the NFT
+        require!(reservation_info.owner == &crate::ID, ProgramErrorCode::InvalidMigrationAccounts);
+        require!(reservation_info.is_writable, ProgramErrorCode::InvalidMigrationAccounts);
+        let mut reservation = Reservation::try_deserialize(&mut &reservation_info.try_borrow_data()?[..])?;
+        require!(reservation.tier_id == tier_id, ProgramErrorCode::InvalidMigrationAccounts);
+        let expected_reservation = Pubkey::create_program_address(
+            &[b"reservation", &campaign_seed, reservation.mint_address.as_ref(), &[reservation.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| ProgramErrorCode::InvalidMigrationAccounts)?;
+        require!(reservation_info.key() == expected_reservation, ProgramErrorCode::InvalidMigrationAccounts);
+
+        // Resumable - NFTs moved by an earlier crank are skipped
+        if reservation.collection_mint == new_collection_mint {
+            msg!("Skipping {} - already migrated", reservation.mint_address);
+            continue;
+        }
+        // Legacy reservations predate the recorded collection mint
+        require!(
+            reservation.collection_mint == old_collection_mint || reservation.collection_mint == Pubkey::default(),
+            ProgramErrorCode::InvalidMigrationAccounts
+        );
+        require!(mint.key() == reservation.mint_address, ProgramErrorCode::InvalidMigrationAccounts);
+        require!(
+            metadata_account.key() == Metadata::find_pda(mint.key).0,
+            ProgramErrorCode::InvalidMigrationAccounts
+        );
+
+        unverify_collection(
+            metadata_account,
+            &old_collection_mint_info,
+            &old_collection_metadata,
+            &admin_state,
+            &system_program,
+            &sysvar_instructions,
+            signer_seeds,
+        )?;
+        verify_collection(
+            metadata_account,
+            &ctx.accounts.collection_mint.to_account_info(),
+            &ctx.accounts.collection_metadata.to_account_info(),
+            &ctx.accounts.collection_master_edition.to_account_info(),
+            &admin_state,
+            &system_program,
+            &sysvar_instructions,
+            signer_seeds,
+        )?;
+
+        reservation.collection_mint = new_collection_mint;
+        reservation.try_serialize(&mut &mut reservation_info.try_borrow_mut_data()?[..])?;
+        migrated += 1;
+    }
+
+    // Done once no live NFT is left in the old collection - burned NFTs are dropped from the count
+    let collection_state = &mut ctx.accounts.collection_state;
+    collection_state.migration_pending = collection_state.migration_pending.saturating_sub(migrated as u64);
+    let remaining_size = collection_state.migration_pending;
+    let migration = &mut ctx.accounts.collection_migration;
+    migration.migrated_count = migration.migrated_count.saturating_add(migrated as u64);
+    if remaining_size == 0 {
+        migration.completed = true;
+        collection_state.migrating_from = None;
+        msg!("Collection tier {} migration to {} completed", tier_id, new_collection_mint);
+    } else {
+        msg!("Collection tier {} migration: {} NFTs left in old collection", tier_id, remaining_size);
+    }
+
+    emit!(CollectionMigrationEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
+        tier_id,
+        old_collection_mint,
+        new_collection_mint,
+        cranker: ctx.accounts.payer.key(),
+        migrated,
+        total_migrated: migration.migrated_count,
+        remaining: remaining_size,
+        completed: migration.completed,
+        timestamp: now,
+    });
+
+    Ok(())
+}
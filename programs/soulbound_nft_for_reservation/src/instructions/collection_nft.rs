@@ -0,0 +1,515 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
+};
+use mpl_token_metadata::{
+    accounts::{CollectionAuthorityRecord, Metadata},
+    instructions::{ApproveCollectionAuthority, RevokeCollectionAuthority},
+    types::Data,
+};
+use solana_program::program::invoke_signed;
+
+use crate::error::ProgramErrorCode;
+use crate::instructions::update_nft_metadata::{read_metadata, trim_padding, update_metadata_data, validate_metadata_update};
+use crate::state::*;
+
+// Event definitions
+#[event]
+pub struct CollectionNftUpdatedEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub collection_mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub is_mutable: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionAuthorityEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub collection_mint: Pubkey,
+    pub delegate: Pubkey,
+    pub approved: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionNftTransferredEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub collection_mint: Pubkey,
+    pub destination: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct UpdateCollectionNft<'info> {
+    /// Only super_admin can maintain collection NFTs - pays for any metadata reallocation
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program - validated by address
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(address = collection_state.collection_mint @ ProgramErrorCode::InvalidCollectionMint)]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Collection metadata account - validated as the collection mint's metadata PDA
+    #[account(
+        mut,
+        address = Metadata::find_pda(&collection_mint.key()).0 @ ProgramErrorCode::InvalidCollectionMetadata,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Sysvar instructions account - required by Metaplex UpdateV1
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct CollectionAuthorityDelegate<'info> {
+    /// Only super_admin can manage collection authority delegates - pays for the record
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program - validated by address
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(address = collection_state.collection_mint @ ProgramErrorCode::InvalidCollectionMint)]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Collection metadata account - validated as the collection mint's metadata PDA
+    #[account(address = Metadata::find_pda(&collection_mint.key()).0 @ ProgramErrorCode::InvalidCollectionMetadata)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Wallet or program the collection authority is delegated to - any key
+    pub delegate: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex collection authority record for the delegate - validated as its PDA
+    #[account(
+        mut,
+        address = CollectionAuthorityRecord::find_pda(&collection_mint.key(), &delegate.key()).0
+            @ ProgramErrorCode::InvalidCollectionAuthorityRecord,
+    )]
+    pub collection_authority_record: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct TransferCollectionNft<'info> {
+    /// Only super_admin can move a collection NFT out of the admin_state ATA
+    pub super_admin: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(address = collection_state.collection_mint @ ProgramErrorCode::InvalidCollectionMint)]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// The admin_state ATA the collection NFT was minted into
+    #[account(
+        mut,
+        address = get_associated_token_address(&admin_state.key(), &collection_mint.key()),
+        constraint = collection_token_account.amount == 1 @ ProgramErrorCode::InvalidCollectionMint,
+    )]
+    pub collection_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Receives the collection NFT - update authority stays with admin_state
+    #[account(
+        mut,
+        token::mint = collection_mint,
+        constraint = destination_token_account.key() != collection_token_account.key() @ ProgramErrorCode::InvalidCollectionMint,
+    )]
+    pub destination_token_account: Box<Account<'info, TokenAccount>>,
+}
+
+pub fn update_collection_nft_metadata_handler(
+    ctx: Context<UpdateCollectionNft>,
+    tier_id: u16,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[ctx.bumps.admin_state]]];
+    apply_collection_nft_metadata(
+        &ctx.accounts.collection_metadata.to_account_info(),
+        &ctx.accounts.collection_mint.to_account_info(),
+        &ctx.accounts.admin_state.to_account_info(),
+        &ctx.accounts.super_admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.sysvar_instructions.to_account_info(),
+        signer_seeds,
+        ctx.accounts.admin_state.campaign_id,
+        tier_id,
+        name,
+        symbol,
+        uri,
+    )
+}
+
+/// Shared by update_collection_nft_metadata and multisig proposals
+pub(crate) fn apply_collection_nft_metadata<'info>(
+    collection_metadata: &AccountInfo<'info>,
+    collection_mint: &AccountInfo<'info>,
+    admin_state: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    sysvar_instructions: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    campaign_id: u64,
+    tier_id: u16,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    validate_metadata_update(&name, &symbol, &uri)?;
+
+    // Fields left out keep their current value, as do creators and royalties
+    let current = read_metadata(collection_metadata)?;
+    require!(current.is_mutable, ProgramErrorCode::CollectionNftImmutable);
+    let data = Data {
+        name: name.unwrap_or_else(|| trim_padding(&current.name)),
+        symbol: symbol.unwrap_or_else(|| trim_padding(&current.symbol)),
+        uri: uri.unwrap_or_else(|| trim_padding(&current.uri)),
+        seller_fee_basis_points: current.seller_fee_basis_points,
+        creators: current.creators,
+    };
+
+    update_metadata_data(
+        collection_metadata,
+        collection_mint,
+        admin_state,
+        payer,
+        system_program,
+        sysvar_instructions,
+        Some(data.clone()),
+        None,
+        None,
+        signer_seeds,
+    )?;
+
+    msg!("Collection tier {} NFT metadata updated", tier_id);
+
+    emit!(CollectionNftUpdatedEvent {
+        campaign_id,
+        tier_id,
+        collection_mint: collection_mint.key(),
+        name: data.name,
+        symbol: data.symbol,
+        uri: data.uri,
+        is_mutable: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Metaplex only lets metadata go from mutable to immutable, so locking is permanent
+pub fn set_collection_nft_mutability_handler(ctx: Context<UpdateCollectionNft>, tier_id: u16, is_mutable: bool) -> Result<()> {
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[ctx.bumps.admin_state]]];
+    apply_collection_nft_mutability(
+        &ctx.accounts.collection_metadata.to_account_info(),
+        &ctx.accounts.collection_mint.to_account_info(),
+        &ctx.accounts.admin_state.to_account_info(),
+        &ctx.accounts.super_admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.sysvar_instructions.to_account_info(),
+        signer_seeds,
+        ctx.accounts.admin_state.campaign_id,
+        tier_id,
+        is_mutable,
+    )
+}
+
+/// Shared by set_collection_nft_mutability and multisig proposals
+pub(crate) fn apply_collection_nft_mutability<'info>(
+    collection_metadata: &AccountInfo<'info>,
+    collection_mint: &AccountInfo<'info>,
+    admin_state: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    sysvar_instructions: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    campaign_id: u64,
+    tier_id: u16,
+    is_mutable: bool,
+) -> Result<()> {
+    let current = read_metadata(collection_metadata)?;
+    require!(current.is_mutable, ProgramErrorCode::CollectionNftImmutable);
+
+    if is_mutable {
+        msg!("Collection tier {} NFT metadata is already mutable", tier_id);
+        return Ok(());
+    }
+
+    update_metadata_data(
+        collection_metadata,
+        collection_mint,
+        admin_state,
+        payer,
+        system_program,
+        sysvar_instructions,
+        None,
+        Some(false),
+        None,
+        signer_seeds,
+    )?;
+
+    msg!("Collection tier {} NFT metadata locked", tier_id);
+
+    emit!(CollectionNftUpdatedEvent {
+        campaign_id,
+        tier_id,
+        collection_mint: collection_mint.key(),
+        name: trim_padding(&current.name),
+        symbol: trim_padding(&current.symbol),
+        uri: trim_padding(&current.uri),
+        is_mutable: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn approve_collection_authority_handler(ctx: Context<CollectionAuthorityDelegate>, tier_id: u16) -> Result<()> {
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[ctx.bumps.admin_state]]];
+    apply_approve_collection_authority(
+        &ctx.accounts.collection_authority_record.to_account_info(),
+        &ctx.accounts.delegate.to_account_info(),
+        &ctx.accounts.admin_state.to_account_info(),
+        &ctx.accounts.super_admin.to_account_info(),
+        &ctx.accounts.collection_metadata.to_account_info(),
+        &ctx.accounts.collection_mint.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        signer_seeds,
+        ctx.accounts.admin_state.campaign_id,
+        tier_id,
+    )
+}
+
+/// Shared by approve_collection_authority and multisig proposals
+pub(crate) fn apply_approve_collection_authority<'info>(
+    collection_authority_record: &AccountInfo<'info>,
+    delegate: &AccountInfo<'info>,
+    admin_state: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    collection_metadata: &AccountInfo<'info>,
+    collection_mint: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    campaign_id: u64,
+    tier_id: u16,
+) -> Result<()> {
+    let ix = ApproveCollectionAuthority {
+        collection_authority_record: collection_authority_record.key(),
+        new_collection_authority: delegate.key(),
+        update_authority: admin_state.key(),
+        payer: payer.key(),
+        metadata: collection_metadata.key(),
+        mint: collection_mint.key(),
+        system_program: system_program.key(),
+        rent: None,
+    }
+    .instruction();
+
+    invoke_signed(
+        &ix,
+        &[
+            collection_authority_record.clone(),
+            delegate.clone(),
+            admin_state.clone(),
+            payer.clone(),
+            collection_metadata.clone(),
+            collection_mint.clone(),
+            system_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    msg!("Collection tier {} authority delegated to {}", tier_id, delegate.key());
+
+    emit!(CollectionAuthorityEvent {
+        campaign_id,
+        tier_id,
+        collection_mint: collection_mint.key(),
+        delegate: delegate.key(),
+        approved: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn revoke_collection_authority_handler(ctx: Context<CollectionAuthorityDelegate>, tier_id: u16) -> Result<()> {
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[ctx.bumps.admin_state]]];
+    apply_revoke_collection_authority(
+        &ctx.accounts.collection_authority_record.to_account_info(),
+        &ctx.accounts.delegate.to_account_info(),
+        &ctx.accounts.admin_state.to_account_info(),
+        &ctx.accounts.collection_metadata.to_account_info(),
+        &ctx.accounts.collection_mint.to_account_info(),
+        signer_seeds,
+        ctx.accounts.admin_state.campaign_id,
+        tier_id,
+    )
+}
+
+/// Shared by revoke_collection_authority and multisig proposals
+pub(crate) fn apply_revoke_collection_authority<'info>(
+    collection_authority_record: &AccountInfo<'info>,
+    delegate: &AccountInfo<'info>,
+    admin_state: &AccountInfo<'info>,
+    collection_metadata: &AccountInfo<'info>,
+    collection_mint: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    campaign_id: u64,
+    tier_id: u16,
+) -> Result<()> {
+    // The update authority may revoke any delegate; the record's rent goes back to it
+    let ix = RevokeCollectionAuthority {
+        collection_authority_record: collection_authority_record.key(),
+        delegate_authority: delegate.key(),
+        revoke_authority: admin_state.key(),
+        metadata: collection_metadata.key(),
+        mint: collection_mint.key(),
+    }
+    .instruction();
+
+    invoke_signed(
+        &ix,
+        &[
+            collection_authority_record.clone(),
+            delegate.clone(),
+            admin_state.clone(),
+            collection_metadata.clone(),
+            collection_mint.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    msg!("Collection tier {} authority revoked from {}", tier_id, delegate.key());
+
+    emit!(CollectionAuthorityEvent {
+        campaign_id,
+        tier_id,
+        collection_mint: collection_mint.key(),
+        delegate: delegate.key(),
+        approved: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn transfer_collection_nft_handler(ctx: Context<TransferCollectionNft>, tier_id: u16) -> Result<()> {
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[ctx.bumps.admin_state]]];
+    apply_transfer_collection_nft(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.collection_token_account,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.admin_state.to_account_info(),
+        signer_seeds,
+        ctx.accounts.admin_state.campaign_id,
+        tier_id,
+    )
+}
+
+/// Shared by transfer_collection_nft and multisig proposals
+pub(crate) fn apply_transfer_collection_nft<'info>(
+    token_program: &AccountInfo<'info>,
+    collection_token_account: &Account<'info, TokenAccount>,
+    destination_token_account: &Account<'info, TokenAccount>,
+    admin_state: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    campaign_id: u64,
+    tier_id: u16,
+) -> Result<()> {
+    transfer(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            Transfer {
+                from: collection_token_account.to_account_info(),
+                to: destination_token_account.to_account_info(),
+                authority: admin_state.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    msg!(
+        "Collection tier {} NFT transferred to {}",
+        tier_id,
+        destination_token_account.owner
+    );
+
+    emit!(CollectionNftTransferredEvent {
+        campaign_id,
+        tier_id,
+        collection_mint: collection_token_account.mint,
+        destination: destination_token_account.owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -68,7 +68,7 @@ pub struct CreateCollectionNft<'info> {
 
     #[account(
         mut,
-        seeds = [b"admin_state".as_ref()],
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
         bump,
     )]
     pub admin_state: Box<Account<'info, AdminState>>,
@@ -192,7 +192,8 @@ pub fn handler(ctx: Context<CreateCollectionNft>, name: String, symbol: String,
     msg!("Create collection NFT");
 
     let bump = ctx.bumps.admin_state;
-    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &[bump]]];
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[bump]]];
 
     // Create collection metadata
     create_metadata(
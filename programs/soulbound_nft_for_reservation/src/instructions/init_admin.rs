@@ -1,18 +1,47 @@
+use std::str::FromStr;
+
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::state::*;
 use crate::error::ProgramErrorCode;
 
+/// Key allowed to initialize admin states when set at build time (INIT_ADMIN_AUTHORITY env var)
+/// Useful when the program is deployed immutable and has no upgrade authority
+const INIT_ADMIN_AUTHORITY: Option<&str> = option_env!("INIT_ADMIN_AUTHORITY");
+
+/// The program's upgrade authority, or the build-time configured key, may initialize admin states
+pub fn is_init_admin_authority(program_data: &ProgramData, signer: Pubkey) -> bool {
+    program_data.upgrade_authority_address == Some(signer)
+        || INIT_ADMIN_AUTHORITY
+            .and_then(|key| Pubkey::from_str(key).ok())
+            .is_some_and(|key| key == signer)
+}
+
 #[derive(Accounts)]
+#[instruction(campaign_id: u64)]
 pub struct InitAdmin<'info> {
     /// Super admin who initializes the program
     #[account(mut)]
     pub super_admin: Signer<'info>,
 
+    /// Deployer approving the initialization - prevents front-running a fresh deployment
+    #[account(
+        constraint = is_init_admin_authority(&program_data, upgrade_authority.key()) @ ProgramErrorCode::Unauthorized
+    )]
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ ProgramErrorCode::InvalidProgramData
+    )]
+    pub program: Program<'info, crate::program::SoulboundNftForReservation>,
+
+    /// ProgramData account holding this program's upgrade authority
+    pub program_data: Account<'info, ProgramData>,
+
     #[account(
          init,
-         seeds = [b"admin_state".as_ref()],
+         seeds = [b"admin_state".as_ref(), &campaign_seed(campaign_id)],
          bump,
          payer = super_admin,
          space = AdminState::space()
@@ -30,7 +59,7 @@ pub struct InitAdmin<'info> {
     #[account(
         init,
         payer = super_admin,
-        seeds = [b"vault", payment_mint.key().as_ref()],
+        seeds = [b"vault".as_ref(), &campaign_seed(campaign_id), payment_mint.key().as_ref()],
         bump,
         token::mint = payment_mint,
         token::authority = admin_state,
@@ -47,22 +76,7 @@ pub struct InitAdmin<'info> {
 
 pub fn handler(
     ctx: Context<InitAdmin>,
-    // OG Collection parameters
-    og_collection_mint: Pubkey,
-    og_mint_fee: u64,
-    og_max_supply: u64,
-    og_admin_mint_limit: u64,
-    // Regular Collection parameters
-    regular_collection_mint: Pubkey,
-    regular_mint_fee: u64,
-    regular_max_supply: u64,
-    regular_admin_mint_limit: u64,
-    // Basic Collection parameters
-    basic_collection_mint: Pubkey,
-    basic_mint_fee: u64,
-    basic_max_supply: u64,
-    basic_admin_mint_limit: u64,
-    // Shared parameters
+    campaign_id: u64,
     withdraw_wallet: Pubkey,
     mint_start_date: i64,
 ) -> Result<()> {
@@ -74,52 +88,31 @@ pub fn handler(
         ProgramErrorCode::InvalidWithdrawWallet
     );
 
-    // Validate that all mint fees are greater than 0
-    require!(og_mint_fee > 0, ProgramErrorCode::InvalidMintFee);
-    require!(regular_mint_fee > 0, ProgramErrorCode::InvalidMintFee);
-    require!(basic_mint_fee > 0, ProgramErrorCode::InvalidMintFee);
-
-    // Validate collection mints are not default
-    require!(og_collection_mint != Pubkey::default(), ProgramErrorCode::InvalidCollectionMint);
-    require!(regular_collection_mint != Pubkey::default(), ProgramErrorCode::InvalidCollectionMint);
-    require!(basic_collection_mint != Pubkey::default(), ProgramErrorCode::InvalidCollectionMint);
-
     ctx.accounts.admin_state.bump = ctx.bumps.admin_state;
+    ctx.accounts.admin_state.campaign_id = campaign_id;
     ctx.accounts.admin_state.super_admin = super_admin_key;
     ctx.accounts.admin_state.withdraw_wallet = withdraw_wallet;
     ctx.accounts.admin_state.payment_mint = ctx.accounts.payment_mint.key();
     ctx.accounts.admin_state.mint_start_date = mint_start_date;
-
-    // Initialize OG Collection
-    ctx.accounts.admin_state.og_collection = crate::state::CollectionConfig::new(
-        og_collection_mint,
-        og_mint_fee,
-        og_max_supply,
-        og_admin_mint_limit
-    );
-
-    // Initialize Regular Collection
-    ctx.accounts.admin_state.regular_collection = crate::state::CollectionConfig::new(
-        regular_collection_mint,
-        regular_mint_fee,
-        regular_max_supply,
-        regular_admin_mint_limit
-    );
-
-    // Initialize Basic Collection
-    ctx.accounts.admin_state.basic_collection = crate::state::CollectionConfig::new(
-        basic_collection_mint,
-        basic_mint_fee,
-        basic_max_supply,
-        basic_admin_mint_limit
-    );
-
-    msg!("Admin initialized with vault at: {}", ctx.accounts.vault.key());
+    ctx.accounts.admin_state.guardian = Pubkey::default();
+    ctx.accounts.admin_state.pause_flags = PauseFlags::default();
+    ctx.accounts.admin_state.pending_super_admin = Pubkey::default();
+    ctx.accounts.admin_state.pending_super_admin_expires_at = 0;
+    ctx.accounts.admin_state.pending_withdraw_wallet = Pubkey::default();
+    ctx.accounts.admin_state.pending_withdraw_wallet_expires_at = 0;
+    ctx.accounts.admin_state.multisig_enabled = false;
+    ctx.accounts.admin_state.config_delay = 0;
+    ctx.accounts.admin_state.config_change_count = 0;
+    ctx.accounts.admin_state.max_reservations_per_wallet = 1;
+    ctx.accounts.admin_state.update_authority_handoff = Pubkey::default();
+    ctx.accounts.admin_state.update_authority_handoff_at = 0;
+    ctx.accounts.admin_state.handed_off_count = 0;
+
+    // Collections are registered separately with add_collection
+
+    msg!("Admin initialized for campaign {} with vault at: {}", campaign_id, ctx.accounts.vault.key());
     msg!("Super admin: {}", super_admin_key);
     msg!("Withdraw wallet: {}, mint_start_date: {}", withdraw_wallet, mint_start_date);
-    msg!("OG Collection: {}, fee: {}, max_supply: {}, admin_limit: {}", og_collection_mint, og_mint_fee, og_max_supply, og_admin_mint_limit);
-    msg!("Regular Collection: {}, fee: {}, max_supply: {}, admin_limit: {}", regular_collection_mint, regular_mint_fee, regular_max_supply, regular_admin_mint_limit);
-    msg!("Basic Collection: {}, fee: {}, max_supply: {}, admin_limit: {}", basic_collection_mint, basic_mint_fee, basic_max_supply, basic_admin_mint_limit);
 
     Ok(())
 }
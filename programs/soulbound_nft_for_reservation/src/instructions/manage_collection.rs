@@ -0,0 +1,274 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ProgramErrorCode;
+
+// Event definitions
+#[event]
+pub struct AddCollectionEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub collection_mint: Pubkey,
+    pub mint_fee: u64,
+    pub max_supply: u64,
+    pub admin_mint_limit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RetireCollectionEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub collection_mint: Pubkey,
+    pub final_reserved_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SaleClosedEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub collection_mint: Pubkey,
+    pub final_reserved_count: u64,
+    pub admin_mint_count: u64,
+    pub public_mint_count: u64,
+    pub total_revenue: u64,
+    pub timestamp: i64,
+}
+
+/// Register a new collection tier (super_admin only)
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct AddCollection<'info> {
+    /// Only super_admin can add collections
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        init,
+        payer = super_admin,
+        space = CollectionState::space(),
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Retire a collection tier - no further mints are accepted (super_admin only)
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct RetireCollection<'info> {
+    /// Only super_admin can retire collections
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+}
+
+/// Permanently close the sale for a collection tier (super_admin only)
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct CloseSale<'info> {
+    /// Only super_admin can close sales
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+}
+
+pub fn add_collection_handler(
+    ctx: Context<AddCollection>,
+    tier_id: u16,
+    collection_mint: Pubkey,
+    mint_fee: u64,
+    max_supply: u64,
+    admin_mint_limit: u64,
+) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    apply_add_collection(
+        &mut ctx.accounts.collection_state,
+        ctx.bumps.collection_state,
+        campaign_id,
+        tier_id,
+        collection_mint,
+        mint_fee,
+        max_supply,
+        admin_mint_limit,
+    )
+}
+
+/// Shared by add_collection and multisig proposals - fills a freshly created collection state
+pub(crate) fn apply_add_collection(
+    collection_state: &mut CollectionState,
+    bump: u8,
+    campaign_id: u64,
+    tier_id: u16,
+    collection_mint: Pubkey,
+    mint_fee: u64,
+    max_supply: u64,
+    admin_mint_limit: u64,
+) -> Result<()> {
+    // Validate collection mint is not default and mint fee is greater than 0
+    require!(collection_mint != Pubkey::default(), ProgramErrorCode::InvalidCollectionMint);
+    require!(mint_fee > 0, ProgramErrorCode::InvalidMintFee);
+
+    // The admin allocation is carved out of max_supply; the rest is the public bucket
+    let public_supply = CollectionState::public_supply_for(max_supply, admin_mint_limit)
+        .ok_or(ProgramErrorCode::InvalidAdminMintLimit)?;
+
+    *collection_state = CollectionState {
+        bump,
+        tier_id,
+        collection_mint,
+        mint_fee,
+        max_supply,
+        current_reserved_count: 0,
+        admin_mint_limit,
+        current_admin_mint_count: 0,
+        public_supply,
+        public_minted_count: 0,
+        admin_release_date: 0,
+        retired: false,
+        allowlist_root: None,
+        sale_phases: Vec::new(),
+        sale_start: 0,
+        sale_end: 0,
+        sale_closed: false,
+        total_revenue: 0,
+        max_per_wallet: 0,
+        quantity_prices: Vec::new(),
+        last_serial: 0,
+        serial_in_name: false,
+        name_prefix: String::new(),
+        symbol: String::new(),
+        base_uri: String::new(),
+        provenance_hash: None,
+        placeholder_uri: String::new(),
+        art_count: 0,
+        revealed: false,
+        reveal_base_uri: String::new(),
+        reveal_offset: 0,
+        reveal_slot: 0,
+        reveal_seeded: false,
+        migrating_from: None,
+        migration_pending: 0,
+        creators: Vec::new(),
+        royalty_bps: 0,
+    };
+
+    msg!(
+        "Collection tier {} added: {}, fee: {}, max_supply: {}, admin_limit: {}",
+        tier_id, collection_mint, mint_fee, max_supply, admin_mint_limit
+    );
+
+    let clock = Clock::get()?;
+    emit!(AddCollectionEvent {
+        campaign_id,
+        tier_id,
+        collection_mint,
+        mint_fee,
+        max_supply,
+        admin_mint_limit,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn retire_collection_handler(ctx: Context<RetireCollection>, tier_id: u16) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    apply_retire_collection(&mut ctx.accounts.collection_state, campaign_id, tier_id)
+}
+
+/// Shared by retire_collection and multisig proposals
+pub(crate) fn apply_retire_collection(collection_state: &mut CollectionState, campaign_id: u64, tier_id: u16) -> Result<()> {
+    require!(!collection_state.retired, ProgramErrorCode::CollectionRetired);
+    collection_state.retired = true;
+
+    // Existing holders can still burn; only new mints are rejected
+    msg!(
+        "Collection tier {} retired with {} reserved",
+        tier_id,
+        collection_state.current_reserved_count
+    );
+
+    let clock = Clock::get()?;
+    emit!(RetireCollectionEvent {
+        campaign_id,
+        tier_id,
+        collection_mint: collection_state.collection_mint,
+        final_reserved_count: collection_state.current_reserved_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn close_sale_handler(ctx: Context<CloseSale>, tier_id: u16) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    apply_close_sale(&mut ctx.accounts.collection_state, campaign_id, tier_id)
+}
+
+/// Shared by close_sale and multisig proposals
+pub(crate) fn apply_close_sale(collection_state: &mut CollectionState, campaign_id: u64, tier_id: u16) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
+    let clock = Clock::get()?;
+
+    // Mints, supply, price and schedule updates are rejected from now on; burns remain allowed
+    collection_state.sale_closed = true;
+
+    msg!(
+        "Collection tier {} sale closed - reserved: {}, admin minted: {}, revenue: {}",
+        tier_id,
+        collection_state.current_reserved_count,
+        collection_state.current_admin_mint_count,
+        collection_state.total_revenue
+    );
+
+    emit!(SaleClosedEvent {
+        campaign_id,
+        tier_id,
+        collection_mint: collection_state.collection_mint,
+        final_reserved_count: collection_state.current_reserved_count,
+        admin_mint_count: collection_state.current_admin_mint_count,
+        public_mint_count: collection_state.public_minted_count,
+        total_revenue: collection_state.total_revenue,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
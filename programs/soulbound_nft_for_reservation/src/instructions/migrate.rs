@@ -0,0 +1,309 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::state::*;
+use crate::error::ProgramErrorCode;
+use crate::instructions::batch_admin_mint_nft::create_pda_account;
+
+/// Move the three collections embedded in a legacy AdminState into CollectionState PDAs
+/// and shrink AdminState to its current layout (super_admin only)
+#[derive(Accounts)]
+pub struct MigrateCollections<'info> {
+    /// Must match the super_admin stored in the legacy admin_state
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    /// CHECK: Legacy admin_state - validated by PDA derivation, owner, length and discriminator in handler
+    /// Legacy deployments predate campaigns, so only the default campaign (empty seed) is migrated
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub admin_state: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = super_admin,
+        space = CollectionState::space(),
+        seeds = [b"collection_state".as_ref(), OG_TIER_ID.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub og_collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(
+        init,
+        payer = super_admin,
+        space = CollectionState::space(),
+        seeds = [b"collection_state".as_ref(), REGULAR_TIER_ID.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub regular_collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(
+        init,
+        payer = super_admin,
+        space = CollectionState::space(),
+        seeds = [b"collection_state".as_ref(), BASIC_TIER_ID.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub basic_collection_state: Box<Account<'info, CollectionState>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrite a UserState written by the original deployment (enum collection type) into the
+/// current layout and record its reservation (permissionless)
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct MigrateUserState<'info> {
+    /// Pays for the extra rent of the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Wallet the user state belongs to - only used for PDA derivation
+    pub user: UncheckedAccount<'info>,
+
+    /// CHECK: Legacy user_state - validated by PDA derivation, owner, length and discriminator in handler
+    /// Enum layout accounts predate campaigns and only exist in the default campaign
+    #[account(
+        mut,
+        seeds = [b"user_state", &campaign_seed(campaign_id), user.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub user_state: UncheckedAccount<'info>,
+
+    /// CHECK: Reservation PDA for the recorded mint - required when the user holds an NFT,
+    /// validated by PDA derivation in handler
+    #[account(mut)]
+    pub reservation: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+fn collection_state_from_legacy(bump: u8, tier_id: u16, config: &LegacyCollectionConfig) -> CollectionState {
+    CollectionState {
+        bump,
+        tier_id,
+        collection_mint: config.collection_mint,
+        mint_fee: config.mint_fee,
+        max_supply: config.max_supply,
+        current_reserved_count: config.current_reserved_count,
+        admin_mint_limit: config.admin_mint_limit,
+        current_admin_mint_count: config.current_admin_mint_count,
+        public_supply: config.max_supply.saturating_sub(config.admin_mint_limit),
+        public_minted_count: config
+            .current_reserved_count
+            .saturating_sub(config.current_admin_mint_count),
+        admin_release_date: 0,
+        retired: false,
+        allowlist_root: None,
+        sale_phases: Vec::new(),
+        sale_start: 0,
+        sale_end: 0,
+        sale_closed: false,
+        total_revenue: 0,
+        max_per_wallet: 0,
+        quantity_prices: Vec::new(),
+        last_serial: 0,
+        serial_in_name: false,
+        name_prefix: String::new(),
+        symbol: String::new(),
+        base_uri: String::new(),
+        provenance_hash: None,
+        placeholder_uri: String::new(),
+        art_count: 0,
+        revealed: false,
+        reveal_base_uri: String::new(),
+        reveal_offset: 0,
+        reveal_slot: 0,
+        reveal_seeded: false,
+        migrating_from: None,
+        migration_pending: 0,
+        creators: Vec::new(),
+        royalty_bps: 0,
+    }
+}
+
+pub fn migrate_collections_handler(ctx: Context<MigrateCollections>) -> Result<()> {
+    let admin_state_info = ctx.accounts.admin_state.to_account_info();
+
+    // Read the legacy layout
+    let legacy = {
+        let data = admin_state_info.try_borrow_data()?;
+        require!(
+            data.len() == LegacyAdminState::space(),
+            ProgramErrorCode::AccountAlreadyMigrated
+        );
+        require!(
+            data.len() >= 8 && data[..8] == AdminState::DISCRIMINATOR,
+            ProgramErrorCode::InvalidLegacyAccount
+        );
+        LegacyAdminState::deserialize(&mut &data[8..])?
+    };
+
+    require!(
+        legacy.super_admin == ctx.accounts.super_admin.key(),
+        ProgramErrorCode::Unauthorized
+    );
+
+    // Create one CollectionState per embedded config
+    ctx.accounts.og_collection_state.set_inner(collection_state_from_legacy(
+        ctx.bumps.og_collection_state,
+        OG_TIER_ID,
+        &legacy.og_collection,
+    ));
+    ctx.accounts.regular_collection_state.set_inner(collection_state_from_legacy(
+        ctx.bumps.regular_collection_state,
+        REGULAR_TIER_ID,
+        &legacy.regular_collection,
+    ));
+    ctx.accounts.basic_collection_state.set_inner(collection_state_from_legacy(
+        ctx.bumps.basic_collection_state,
+        BASIC_TIER_ID,
+        &legacy.basic_collection,
+    ));
+
+    // Shrink admin_state and return the surplus rent to the super admin
+    admin_state_info.realloc(AdminState::space(), false)?;
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(AdminState::space());
+    let surplus = admin_state_info
+        .lamports()
+        .saturating_sub(rent_exempt_minimum);
+    **admin_state_info.try_borrow_mut_lamports()? -= surplus;
+    **ctx.accounts.super_admin.to_account_info().try_borrow_mut_lamports()? += surplus;
+
+    let admin_state = AdminState {
+        bump: legacy.bump,
+        campaign_id: DEFAULT_CAMPAIGN_ID,
+        super_admin: legacy.super_admin,
+        withdraw_wallet: legacy.withdraw_wallet,
+        payment_mint: legacy.payment_mint,
+        mint_start_date: legacy.mint_start_date,
+        guardian: Pubkey::default(),
+        pause_flags: PauseFlags::default(),
+        pending_super_admin: Pubkey::default(),
+        pending_super_admin_expires_at: 0,
+        pending_withdraw_wallet: Pubkey::default(),
+        pending_withdraw_wallet_expires_at: 0,
+        multisig_enabled: false,
+        config_delay: 0,
+        config_change_count: 0,
+        max_reservations_per_wallet: 1,
+        update_authority_handoff: Pubkey::default(),
+        update_authority_handoff_at: 0,
+        handed_off_count: 0,
+    };
+    let mut data = admin_state_info.try_borrow_mut_data()?;
+    admin_state.try_serialize(&mut &mut data[..])?;
+
+    msg!("Admin state migrated - collections moved to CollectionState PDAs");
+    msg!("OG tier {}: {}", OG_TIER_ID, legacy.og_collection.collection_mint);
+    msg!("Regular tier {}: {}", REGULAR_TIER_ID, legacy.regular_collection.collection_mint);
+    msg!("Basic tier {}: {}", BASIC_TIER_ID, legacy.basic_collection.collection_mint);
+
+    Ok(())
+}
+
+pub fn migrate_user_state_handler(ctx: Context<MigrateUserState>, campaign_id: u64) -> Result<()> {
+    let user_state_info = ctx.accounts.user_state.to_account_info();
+
+    // Read the layout of the original deployment
+    let legacy = {
+        let data = user_state_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == UserState::DISCRIMINATOR,
+            ProgramErrorCode::InvalidLegacyAccount
+        );
+        require!(
+            data.len() == LegacyUserState::space(),
+            ProgramErrorCode::AccountAlreadyMigrated
+        );
+        LegacyUserState::deserialize(&mut &data[8..])?
+    };
+    // Legacy enum variants were declared in tier id order (OG, Regular, Basic)
+    let tier_id = legacy.collection_type as u16;
+
+    // Top up rent for the larger layout
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(UserState::space());
+    let shortfall = rent_exempt_minimum.saturating_sub(user_state_info.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: user_state_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    user_state_info.realloc(UserState::space(), false)?;
+
+    // Provenance was never recorded by the original layout, so burns of these mints only
+    // adjust the reserved count
+    let user_state = UserState {
+        user: legacy.user,
+        has_minted: legacy.has_minted,
+        tier_id,
+        mint_address: legacy.mint_address,
+        minted_at: legacy.minted_at,
+        bump: legacy.bump,
+        source: MintSource::Legacy,
+        amount_paid: 0,
+        payment_mint: Pubkey::default(),
+        operator: None,
+        collection_mint: Pubkey::default(),
+        reservation_count: legacy.has_minted as u16,
+        serial: 0,
+    };
+    user_state.try_serialize(&mut &mut user_state_info.try_borrow_mut_data()?[..])?;
+
+    // The original layout held at most one NFT - give it the Reservation record burns now require
+    if legacy.has_minted {
+        let reservation_info = ctx
+            .accounts
+            .reservation
+            .as_ref()
+            .ok_or(ProgramErrorCode::InvalidReservation)?
+            .to_account_info();
+        let campaign_seed = campaign_seed(campaign_id);
+        let (expected_reservation, reservation_bump) = Pubkey::find_program_address(
+            &[b"reservation", &campaign_seed, legacy.mint_address.as_ref()],
+            &crate::ID,
+        );
+        require!(reservation_info.key() == expected_reservation, ProgramErrorCode::InvalidReservation);
+
+        let reservation_signer_seeds: &[&[&[u8]]] =
+            &[&[b"reservation", &campaign_seed, legacy.mint_address.as_ref(), &[reservation_bump]]];
+        create_pda_account(
+            &ctx.accounts.payer.to_account_info(),
+            &reservation_info,
+            &ctx.accounts.system_program.to_account_info(),
+            Reservation::space(),
+            reservation_signer_seeds,
+        )?;
+        let reservation = Reservation {
+            wallet: legacy.user,
+            tier_id,
+            mint_address: legacy.mint_address,
+            minted_at: legacy.minted_at,
+            source: MintSource::Legacy,
+            amount_paid: 0,
+            payment_mint: Pubkey::default(),
+            operator: None,
+            collection_mint: Pubkey::default(),
+            serial: 0,
+            bump: reservation_bump,
+        };
+        reservation.try_serialize(&mut &mut reservation_info.try_borrow_mut_data()?[..])?;
+    }
+
+    msg!("User state migrated for {} (tier {})", legacy.user, user_state.tier_id);
+
+    Ok(())
+}
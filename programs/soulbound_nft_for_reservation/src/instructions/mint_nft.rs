@@ -4,27 +4,29 @@ use anchor_spl::{
     token::{self, Token, Mint, transfer_checked, TransferChecked},
     token_interface::{TokenInterface, Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount},
 };
-use mpl_token_metadata::{
-    instructions::{
-        CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs,
-        VerifyCollectionV1, VerifyCreatorV1,
-    },
-    types::{DataV2, Creator},
-};
 use solana_program::program::invoke_signed;
 
+use crate::allowlist::{self, AllowlistProof};
 use crate::error::ProgramErrorCode;
+use crate::instructions::admin_mint_nft::{
+    collection_creators, create_nft_metadata, validated_collection_key, verify_collection, verify_creator,
+};
+use crate::instructions::reservation_policy::require_wallet_capacity;
 use crate::state::*;
 
 // Event definition
 #[event]
 pub struct MintNftEvent {
+    pub campaign_id: u64,
     pub user: Pubkey,
     pub mint_address: Pubkey,
+    pub tier_id: u16,
+    pub serial: u64,
     pub timestamp: i64,
 }
 
 #[derive(Accounts)]
+#[instruction(tier_id: u16)]
 pub struct MintNft<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -75,11 +77,22 @@ pub struct MintNft<'info> {
     
     #[account(
         mut,
-        seeds = [b"admin_state".as_ref()],
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
         bump,
         constraint = admin_state.super_admin != Pubkey::default() @ ProgramErrorCode::AdminNotInitialized,
+        constraint = !admin_state.pause_flags.mint @ ProgramErrorCode::ProgramPaused,
+        constraint = admin_state.update_authority_handoff == Pubkey::default() @ ProgramErrorCode::UpdateAuthorityHandedOff,
     )]
     pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+        constraint = !collection_state.retired @ ProgramErrorCode::CollectionRetired,
+        constraint = !collection_state.sale_closed @ ProgramErrorCode::SaleClosed,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
     
 
     // === Payment token accounts ===
@@ -100,7 +113,7 @@ pub struct MintNft<'info> {
     /// Vault token account (PDA-controlled) to receive payment - created in init_admin
     #[account(
         mut,
-        seeds = [b"vault", payment_mint.key().as_ref()],
+        seeds = [b"vault".as_ref(), &campaign_seed(admin_state.campaign_id), payment_mint.key().as_ref()],
         bump,
     )]
     pub vault: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
@@ -108,266 +121,177 @@ pub struct MintNft<'info> {
     /// Token program for payment (can be Token or Token2022)
     pub payment_token_program: Interface<'info, TokenInterface>,
 
-    // === Optional Collection ===
-    /// Optional collection mint account for grouping NFTs - validated in handler
-    pub collection_mint: Option<Box<Account<'info, Mint>>>,
+    // === Collection ===
+    /// Collection mint every NFT is verified into - validated in handler
+    pub collection_mint: Box<Account<'info, Mint>>,
 
-    /// CHECK: Optional collection metadata account - validated by PDA derivation in handler if provided
+    /// CHECK: Collection metadata account - validated by PDA derivation in handler
     #[account(mut)]
-    pub collection_metadata: Option<UncheckedAccount<'info>>,
+    pub collection_metadata: UncheckedAccount<'info>,
 
-    /// CHECK: Optional collection master edition account - validated by PDA derivation in handler if provided
-    pub collection_master_edition: Option<UncheckedAccount<'info>>,
+    /// CHECK: Collection master edition account - validated by PDA derivation in handler
+    pub collection_master_edition: UncheckedAccount<'info>,
 
     /// CHECK: Sysvar instructions account - required for creator and collection verification
     #[account(address = solana_program::sysvar::instructions::ID)]
     pub sysvar_instructions: UncheckedAccount<'info>,
 
     // === User State ===
-    /// User state account to track if wallet has already minted an NFT
-    /// Uses init_if_needed to support re-minting after burn (when account exists but has_minted = false)
+    /// User state account counting the wallet's reservations against the campaign cap
+    /// Uses init_if_needed so the first mint creates it; the cap is checked in the handler
     /// Solana's transaction atomicity and account locking prevent race conditions
     #[account(
         init_if_needed,
         payer = signer,
         space = UserState::space(),
-        seeds = [b"user_state", signer.key().as_ref()],
+        seeds = [b"user_state".as_ref(), &campaign_seed(admin_state.campaign_id), signer.key().as_ref()],
         bump,
-        constraint = !user_state.has_minted @ ProgramErrorCode::UserAlreadyMinted,
     )]
     pub user_state: Account<'info, UserState>,
-}
-
-#[inline(never)]
-fn create_nft_metadata<'info>(
-    metadata_account: &AccountInfo<'info>,
-    mint: &AccountInfo<'info>,
-    admin_state: &AccountInfo<'info>,
-    signer_account: &AccountInfo<'info>,
-    system_program: &AccountInfo<'info>,
-    rent: &AccountInfo<'info>,
-    name: String,
-    symbol: String,
-    uri: String,
-    collection_key: Option<Pubkey>,
-    signer_seeds: &[&[&[u8]]],
-) -> Result<()> {
-    let create_metadata_ix = CreateMetadataAccountV3 {
-        metadata: metadata_account.key(),
-        mint: mint.key(),
-        mint_authority: admin_state.key(),
-        update_authority: (admin_state.key(), true),
-        payer: signer_account.key(),
-        system_program: system_program.key(),
-        rent: Some(rent.key()),
-    };
-
-    let data = DataV2 {
-        name,
-        symbol,
-        uri,
-        seller_fee_basis_points: 0,
-        creators: Some(vec![Creator {
-            address: admin_state.key(),
-            verified: false,
-            share: 100,
-        }]),
-        collection: collection_key.map(|key| mpl_token_metadata::types::Collection {
-            verified: false,
-            key,
-        }),
-        uses: None,
-    };
-
-    let args = CreateMetadataAccountV3InstructionArgs {
-        data,
-        is_mutable: true,
-        collection_details: None,
-    };
-
-    let ix = create_metadata_ix.instruction(args);
-
-    invoke_signed(
-        &ix,
-        &[
-            metadata_account.clone(),
-            mint.clone(),
-            admin_state.clone(),
-            signer_account.clone(),
-            system_program.clone(),
-            rent.clone(),
-        ],
-        signer_seeds,
-    )?;
 
-    Ok(())
-}
-
-#[inline(never)]
-fn verify_collection<'info>(
-    metadata_account: &AccountInfo<'info>,
-    collection_mint: &AccountInfo<'info>,
-    collection_metadata: &AccountInfo<'info>,
-    collection_master_edition: &AccountInfo<'info>,
-    admin_state: &AccountInfo<'info>,
-    system_program: &AccountInfo<'info>,
-    sysvar_instructions: &AccountInfo<'info>,
-    signer_seeds: &[&[&[u8]]],
-) -> Result<()> {
-    let verify_collection_ix = VerifyCollectionV1 {
-        authority: admin_state.key(),
-        delegate_record: None,
-        metadata: metadata_account.key(),
-        collection_mint: collection_mint.key(),
-        collection_metadata: Some(collection_metadata.key()),
-        collection_master_edition: Some(collection_master_edition.key()),
-        system_program: system_program.key(),
-        sysvar_instructions: sysvar_instructions.key(),
-    };
-
-    let ix = verify_collection_ix.instruction();
+    /// Per-wallet sale phase counters for this collection
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = WalletMintState::space(),
+        seeds = [b"wallet_mint_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref(), signer.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mint_state: Box<Account<'info, WalletMintState>>,
 
-    invoke_signed(
-        &ix,
-        &[
-            admin_state.clone(),
-            metadata_account.clone(),
-            collection_mint.clone(),
-            collection_metadata.clone(),
-            collection_master_edition.clone(),
-            system_program.clone(),
-            sysvar_instructions.clone(),
-        ],
-        signer_seeds,
-    )?;
+    /// Record of this reservation, keyed by the NFT mint
+    #[account(
+        init,
+        payer = signer,
+        space = Reservation::space(),
+        seeds = [b"reservation".as_ref(), &campaign_seed(admin_state.campaign_id), mint.key().as_ref()],
+        bump,
+    )]
+    pub reservation: Box<Account<'info, Reservation>>,
 
-    msg!("Collection verified successfully");
-    Ok(())
+    /// Registry entry for the serial this mint is issued
+    #[account(
+        init,
+        payer = signer,
+        space = SerialRecord::space(),
+        seeds = [b"serial".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref(), collection_state.next_serial().to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub serial_record: Box<Account<'info, SerialRecord>>,
 }
 
-#[inline(never)]
-fn verify_creator<'info>(
-    metadata_account: &AccountInfo<'info>,
-    admin_state: &AccountInfo<'info>,
-    system_program: &AccountInfo<'info>,
-    sysvar_instructions: &AccountInfo<'info>,
-    signer_seeds: &[&[&[u8]]],
+pub fn handler(
+    ctx: Context<MintNft>,
+    tier_id: u16,
+    allowlist_proof: Option<AllowlistProof>,
 ) -> Result<()> {
-    let verify_creator_ix = VerifyCreatorV1 {
-        authority: admin_state.key(),
-        delegate_record: None,
-        metadata: metadata_account.key(),
-        collection_mint: None,
-        collection_metadata: None,
-        collection_master_edition: None,
-        system_program: system_program.key(),
-        sysvar_instructions: sysvar_instructions.key(),
-    };
+    msg!("Mint regular NFT with Metaplex metadata for collection tier: {}", tier_id);
 
-    let ix = verify_creator_ix.instruction();
+    // One reservation per wallet unless the campaign cap allows more
+    require_wallet_capacity(&ctx.accounts.admin_state, ctx.accounts.user_state.reservation_count)?;
 
-    invoke_signed(
-        &ix,
-        &[
-            admin_state.clone(),
-            metadata_account.clone(),
-            system_program.clone(),
-            sysvar_instructions.clone(),
-        ],
-        signer_seeds,
-    )?;
+    let clock = Clock::get()?;
 
-    msg!("Creator verified successfully");
-    Ok(())
-}
+    // Get the specific collection configuration
+    let collection_config = &ctx.accounts.collection_state;
 
-pub fn handler(ctx: Context<MintNft>, collection_type: crate::state::CollectionType, name: String, symbol: String, uri: String) -> Result<()> {
-    msg!("Mint regular NFT with Metaplex metadata for collection type: {:?}", collection_type);
+    // Per-collection wallet cap (0 = campaign cap only)
+    let wallet_reserved_count = ctx.accounts.wallet_mint_state.reserved_count;
+    require!(
+        collection_config.max_per_wallet == 0 || wallet_reserved_count < collection_config.max_per_wallet,
+        ProgramErrorCode::WalletCollectionLimitReached
+    );
 
-    // Note: has_minted check is now enforced at account constraint level for better security
-    
-    // Check mint start date (0 = no restriction)
-    let mint_start_date = ctx.accounts.admin_state.mint_start_date;
-    if mint_start_date > 0 {
-        let clock = Clock::get()?;
-        require!(clock.unix_timestamp >= mint_start_date, ProgramErrorCode::MintNotStarted);
+    // Check the collection sale window (start 0 = shared mint_start_date, end 0 = no end)
+    let sale_start = if collection_config.sale_start > 0 {
+        collection_config.sale_start
+    } else {
+        ctx.accounts.admin_state.mint_start_date
+    };
+    if sale_start > 0 {
+        require!(clock.unix_timestamp >= sale_start, ProgramErrorCode::MintNotStarted);
     }
-
-    // Get the specific collection configuration
-    let collection_config = ctx.accounts.admin_state.get_collection_config(collection_type);
-
-    // Check max supply (0 = unlimited)
-    let max_supply = collection_config.max_supply;
-    if max_supply > 0 {
-        require!(collection_config.current_reserved_count < max_supply, ProgramErrorCode::MaxSupplyReached);
+    if collection_config.sale_end > 0 {
+        require!(clock.unix_timestamp < collection_config.sale_end, ProgramErrorCode::SaleEnded);
     }
 
-    // Validate mint fee
-    require!(collection_config.mint_fee > 0, ProgramErrorCode::InvalidMintFee);
-
-    let bump = ctx.bumps.admin_state;
-    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &[bump]]];
-
-    // Validate collection accounts if provided
-    let collection_key = if let Some(collection_mint) = &ctx.accounts.collection_mint {
-        // Validate collection mint properties
-        require!(
-            collection_mint.key() == collection_config.collection_mint,
-            ProgramErrorCode::InvalidCollection
-        );
+    // Resolve the active sale phase when the collection has a schedule
+    let active_phase = if collection_config.sale_phases.is_empty() {
+        None
+    } else {
+        let phase_index = collection_config
+            .active_phase_index(clock.unix_timestamp)
+            .ok_or(ProgramErrorCode::NoActiveSalePhase)?;
+        let phase = &collection_config.sale_phases[phase_index];
         require!(
-            collection_mint.decimals == 0,
-            ProgramErrorCode::InvalidCollectionMint
+            phase.supply_cap == 0 || phase.minted_count < phase.supply_cap,
+            ProgramErrorCode::PhaseSupplyCapReached
         );
         require!(
-            collection_mint.supply == 1,
-            ProgramErrorCode::InvalidCollectionMint
+            phase.per_wallet_limit == 0
+                || ctx.accounts.wallet_mint_state.phase_mints[phase_index] < phase.per_wallet_limit,
+            ProgramErrorCode::PhaseWalletLimitReached
         );
+        msg!("Sale phase {} active", phase_index);
+        Some(phase_index)
+    };
+    let phase = active_phase.map(|index| collection_config.sale_phases[index]);
 
-        // Validate collection metadata PDA
-        let expected_metadata_key = Pubkey::find_program_address(
-            &[
-                b"metadata",
-                &mpl_token_metadata::ID.as_ref(),
-                &collection_mint.key().as_ref(),
-            ],
-            &mpl_token_metadata::ID,
-        ).0;
-        require!(
-            ctx.accounts.collection_metadata.is_some(),
-            ProgramErrorCode::InvalidCollectionMetadata
-        );
-        let collection_metadata = ctx.accounts.collection_metadata.as_ref().unwrap();
+    // Check max supply (0 = unlimited)
+    // Check the public bucket - the admin allocation is never sold (max_supply 0 = unlimited)
+    if collection_config.max_supply > 0 {
         require!(
-            collection_metadata.key() == expected_metadata_key,
-            ProgramErrorCode::InvalidCollectionMetadata
+            collection_config.public_minted_count < collection_config.public_supply,
+            ProgramErrorCode::MaxSupplyReached
         );
+    }
 
-        // Validate collection master edition PDA
-        let expected_master_edition_key = Pubkey::find_program_address(
-            &[
-                b"metadata",
-                &mpl_token_metadata::ID.as_ref(),
-                &collection_mint.key().as_ref(),
-                b"edition",
-            ],
-            &mpl_token_metadata::ID,
-        ).0;
-        require!(
-            ctx.accounts.collection_master_edition.is_some(),
-            ProgramErrorCode::InvalidCollectionMasterEdition
-        );
-        let collection_master_edition = ctx.accounts.collection_master_edition.as_ref().unwrap();
-        require!(
-            collection_master_edition.key() == expected_master_edition_key,
-            ProgramErrorCode::InvalidCollectionMasterEdition
-        );
+    // Validate mint fee
+    require!(collection_config.mint_fee > 0, ProgramErrorCode::InvalidMintFee);
 
-        Some(collection_mint.key())
-    } else {
-        None
+    // Allowlisted phases or collections require a proof for the signer
+    // Price precedence: non-zero leaf price, then phase price, then quantity price, then collection mint fee
+    let base_fee = phase
+        .and_then(|phase| phase.price)
+        .or_else(|| collection_config.quantity_price(wallet_reserved_count.saturating_add(1)))
+        .unwrap_or(collection_config.mint_fee);
+    let allowlist_root = phase
+        .and_then(|phase| phase.allowlist_root)
+        .or(collection_config.allowlist_root);
+    let mint_fee = match allowlist_root {
+        Some(root) => {
+            let allowlist_proof = allowlist_proof.ok_or(ProgramErrorCode::AllowlistProofRequired)?;
+            let leaf = allowlist::leaf(&ctx.accounts.signer.key(), allowlist_proof.price);
+            require!(
+                allowlist::verify(&allowlist_proof.proof, root, leaf),
+                ProgramErrorCode::InvalidAllowlistProof
+            );
+            msg!("Allowlist proof verified for {}", ctx.accounts.signer.key());
+            if allowlist_proof.price > 0 {
+                allowlist_proof.price
+            } else {
+                base_fee
+            }
+        }
+        None => base_fee,
     };
 
+    // Serials are issued in mint order and never reused
+    let serial = collection_config.next_serial();
+    let (name, symbol, uri) = collection_config.nft_metadata(serial)?;
+
+    let bump = ctx.bumps.admin_state;
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[bump]]];
+
+    // Validate the collection accounts
+    let collection_key = validated_collection_key(
+        collection_config,
+        &ctx.accounts.collection_mint,
+        &ctx.accounts.collection_metadata,
+        &ctx.accounts.collection_master_edition,
+    )?;
+
     // Create metadata
     create_nft_metadata(
         &ctx.accounts.metadata_account.to_account_info(),
@@ -379,6 +303,8 @@ pub fn handler(ctx: Context<MintNft>, collection_type: crate::state::CollectionT
         name,
         symbol,
         uri,
+        collection_creators(collection_config, ctx.accounts.admin_state.key(), false, None),
+        collection_config.royalty_bps,
         collection_key,
         signer_seeds,
     )?;
@@ -392,27 +318,17 @@ pub fn handler(ctx: Context<MintNft>, collection_type: crate::state::CollectionT
         signer_seeds,
     )?;
 
-    // Verify collection if provided
-    if let (
-        Some(collection_mint),
-        Some(collection_metadata),
-        Some(collection_master_edition),
-    ) = (
-        &ctx.accounts.collection_mint,
-        &ctx.accounts.collection_metadata,
-        &ctx.accounts.collection_master_edition,
-    ) {
-        verify_collection(
-            &ctx.accounts.metadata_account.to_account_info(),
-            &collection_mint.to_account_info(),
-            &collection_metadata.to_account_info(),
-            &collection_master_edition.to_account_info(),
-            &ctx.accounts.admin_state.to_account_info(),
-            &ctx.accounts.system_program.to_account_info(),
-            &ctx.accounts.sysvar_instructions.to_account_info(),
-            signer_seeds,
-        )?;
-    }
+    // Verify the NFT in its collection
+    verify_collection(
+        &ctx.accounts.metadata_account.to_account_info(),
+        &ctx.accounts.collection_mint.to_account_info(),
+        &ctx.accounts.collection_metadata.to_account_info(),
+        &ctx.accounts.collection_master_edition.to_account_info(),
+        &ctx.accounts.admin_state.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.sysvar_instructions.to_account_info(),
+        signer_seeds,
+    )?;
 
     // Create the associated token account
     associated_token::create(CpiContext::new(
@@ -458,35 +374,102 @@ pub fn handler(ctx: Context<MintNft>, collection_type: crate::state::CollectionT
 
     // ==== EFFECTS: Update state before external interactions (CEI pattern) ====
     
-    // Store mint fee before mutable borrow
-    let mint_fee = collection_config.mint_fee;
     let payment_decimals = ctx.accounts.payment_mint.decimals;
     
     // Increment reserved count for the specific collection
-    let collection_config_mut = ctx.accounts.admin_state.get_collection_config_mut(collection_type);
+    let collection_config_mut = &mut ctx.accounts.collection_state;
     collection_config_mut.current_reserved_count = collection_config_mut
         .current_reserved_count
         .checked_add(1)
         .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+    collection_config_mut.public_minted_count = collection_config_mut
+        .public_minted_count
+        .checked_add(1)
+        .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+    collection_config_mut.total_revenue = collection_config_mut
+        .total_revenue
+        .checked_add(mint_fee)
+        .ok_or(ProgramErrorCode::RevenueOverflow)?;
+    collection_config_mut.last_serial = serial;
 
     msg!(
-        "Collection {:?} - Current reserved count: {}",
-        collection_type,
+        "Collection tier {} - Current reserved count: {}",
+        tier_id,
         collection_config_mut.current_reserved_count
     );
 
-    // Initialize user state to prevent further minting
-    let clock = Clock::get()?;
+    // Count the mint against the wallet's collection counter and the active sale phase
+    let wallet_mint_state = &mut ctx.accounts.wallet_mint_state;
+    wallet_mint_state.wallet = ctx.accounts.signer.key();
+    wallet_mint_state.tier_id = tier_id;
+    wallet_mint_state.bump = ctx.bumps.wallet_mint_state;
+    wallet_mint_state.reserved_count = wallet_mint_state
+        .reserved_count
+        .checked_add(1)
+        .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+
+    if let Some(phase_index) = active_phase {
+        let phase = &mut collection_config_mut.sale_phases[phase_index];
+        phase.minted_count = phase
+            .minted_count
+            .checked_add(1)
+            .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+
+        wallet_mint_state.phase_mints[phase_index] = wallet_mint_state.phase_mints[phase_index]
+            .checked_add(1)
+            .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+    }
+
+    // Record the reservation and update the wallet's ledger
+    let collection_mint = collection_config_mut.collection_mint;
+    let revealed = collection_config_mut.revealed;
+    ctx.accounts.reservation.set_inner(Reservation {
+        wallet: ctx.accounts.signer.key(),
+        tier_id,
+        mint_address: ctx.accounts.mint.key(),
+        minted_at: clock.unix_timestamp,
+        source: MintSource::Public,
+        amount_paid: mint_fee,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        operator: None,
+        collection_mint,
+        serial,
+        bump: ctx.bumps.reservation,
+    });
+    ctx.accounts.serial_record.set_inner(SerialRecord {
+        tier_id,
+        serial,
+        mint_address: ctx.accounts.mint.key(),
+        wallet: ctx.accounts.signer.key(),
+        burned: false,
+        revealed,
+        bump: ctx.bumps.serial_record,
+    });
+
+    let reservation_count = ctx
+        .accounts
+        .user_state
+        .reservation_count
+        .checked_add(1)
+        .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
     ctx.accounts.user_state.set_inner(UserState {
         user: ctx.accounts.signer.key(),
         has_minted: true,
-        collection_type,
+        tier_id,
         mint_address: ctx.accounts.mint.key(),
         minted_at: clock.unix_timestamp,
         bump: ctx.bumps.user_state,
+        source: MintSource::Public,
+        amount_paid: mint_fee,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        operator: None,
+        collection_mint,
+        reservation_count,
+        serial,
     });
 
-    msg!("User state initialized - user can no longer mint NFTs");
+    msg!("Serial #{} issued in collection tier {}", serial, tier_id);
+    msg!("User state updated - wallet holds {} reservation(s)", reservation_count);
 
     // ==== INTERACTIONS: External calls last (CEI pattern) ====
     
@@ -523,14 +506,17 @@ pub fn handler(ctx: Context<MintNft>, collection_type: crate::state::CollectionT
             ctx.accounts.mint.to_account_info(),
             ctx.accounts.admin_state.to_account_info(),
         ],
-        &[&[b"admin_state", &[ctx.bumps.admin_state]]],
+        signer_seeds,
     )?;
     msg!("Mint authority revoked - this is now a true NFT");
 
     // Emit event
     emit!(MintNftEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
         user: ctx.accounts.signer.key(),
         mint_address: ctx.accounts.mint.key(),
+        tier_id,
+        serial,
         timestamp: clock.unix_timestamp,
     });
 
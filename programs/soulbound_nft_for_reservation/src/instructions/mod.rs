@@ -1,8 +1,22 @@
 pub mod admin_mint_nft;
+pub mod authority_handoff;
+pub mod batch_admin_mint_nft;
 pub mod burn_nft;
+pub mod collection_migration;
+pub mod collection_nft;
 pub mod create_collection_nft;
 pub mod init_admin;
+pub mod manage_collection;
+pub mod migrate;
 pub mod mint_nft;
+pub mod multisig;
+pub mod operator_quota;
+pub mod pause;
+pub mod reservation_policy;
+pub mod reveal;
+pub mod roles;
+pub mod sale_phase;
+pub mod timelock;
 pub mod update_admin;
 pub mod update_nft_metadata;
 pub mod update_payment_mint;
@@ -10,10 +24,24 @@ pub mod update_withdraw_wallet;
 pub mod withdraw;
 
 pub use admin_mint_nft::*;
+pub use authority_handoff::*;
+pub use batch_admin_mint_nft::*;
 pub use burn_nft::*;
+pub use collection_migration::*;
+pub use collection_nft::*;
 pub use create_collection_nft::*;
 pub use init_admin::*;
+pub use manage_collection::*;
+pub use migrate::*;
 pub use mint_nft::*;
+pub use multisig::*;
+pub use operator_quota::*;
+pub use pause::*;
+pub use reservation_policy::*;
+pub use reveal::*;
+pub use roles::*;
+pub use sale_phase::*;
+pub use timelock::*;
 pub use update_admin::*;
 pub use update_nft_metadata::*;
 pub use update_payment_mint::*;
@@ -0,0 +1,1249 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{self, Token},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use mpl_token_metadata::accounts::{CollectionAuthorityRecord, Metadata};
+
+use crate::state::*;
+use crate::error::ProgramErrorCode;
+use crate::instructions::authority_handoff::{apply_update_authority_handoff, validate_update_authority_handoff};
+use crate::instructions::collection_nft::{
+    apply_approve_collection_authority, apply_collection_nft_metadata, apply_collection_nft_mutability,
+    apply_revoke_collection_authority, apply_transfer_collection_nft,
+};
+use crate::instructions::manage_collection::{apply_add_collection, apply_close_sale, apply_retire_collection};
+use crate::instructions::operator_quota::{apply_operator_quota, apply_revoke_operator_quota};
+use crate::instructions::pause::apply_guardian;
+use crate::instructions::reservation_policy::{apply_collection_wallet_limits, apply_wallet_reservation_cap};
+use crate::instructions::reveal::{apply_commit_provenance, apply_postpone_reveal, apply_reveal_collection};
+use crate::instructions::roles::{apply_grant_role, emit_role_revoked};
+use crate::instructions::sale_phase::{apply_add_sale_phase, apply_update_sale_phase};
+use crate::instructions::timelock::validate_config_delay;
+use crate::instructions::update_admin::{
+    apply_admin_mint_limit, apply_admin_release_date, apply_allowlist_root, apply_collection_metadata,
+    apply_collection_mint, apply_collection_royalties, apply_max_supply, apply_mint_fee,
+    apply_release_admin_allocation, apply_sale_window, apply_serial_in_name,
+};
+use crate::instructions::withdraw::transfer_from_vault;
+
+// Event definitions
+#[event]
+pub struct ProposalCreatedEvent {
+    pub campaign_id: u64,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalApprovedEvent {
+    pub campaign_id: u64,
+    pub proposal_id: u64,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExecutedEvent {
+    pub campaign_id: u64,
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Put the admin state under a multisig signer set (super_admin only, one-way)
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    /// Only super_admin can hand governance to a multisig
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        init,
+        payer = super_admin,
+        space = Multisig::space(),
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a proposal - the proposer's approval is recorded (multisig signer only)
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&proposer.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::space(),
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), multisig.proposal_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Approve a pending proposal (multisig signer only)
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ApproveProposal<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&approver.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+/// Execute an approved configuration proposal (multisig signer only)
+/// Collection actions need the CollectionState of the proposal's tier
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteProposal<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    /// Validated against the proposal's tier id in the handler
+    #[account(mut)]
+    pub collection_state: Option<Box<Account<'info, CollectionState>>>,
+}
+
+/// Execute an approved withdraw proposal (multisig signer only)
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteWithdrawProposal<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = !admin_state.pause_flags.withdraw @ ProgramErrorCode::ProgramPaused,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        constraint = payment_mint.key() == admin_state.payment_mint @ ProgramErrorCode::InvalidPaymentMint
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"vault".as_ref(), &campaign_seed(admin_state.campaign_id), payment_mint.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = admin_state,
+        token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = withdraw_token_account.mint == payment_mint.key() @ ProgramErrorCode::InvalidPaymentTokenAccount,
+        constraint = withdraw_token_account.owner == admin_state.withdraw_wallet @ ProgramErrorCode::InvalidWithdrawWallet
+    )]
+    pub withdraw_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Execute an approved payment mint proposal (multisig signer only)
+/// The old vault must be empty, as with update_payment_mint
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecutePaymentMintProposal<'info> {
+    /// Pays for the new vault if it does not exist yet
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        constraint = old_payment_mint.key() == admin_state.payment_mint @ ProgramErrorCode::InvalidPaymentMint,
+        mint::token_program = old_payment_token_program
+    )]
+    pub old_payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"vault".as_ref(), &campaign_seed(admin_state.campaign_id), old_payment_mint.key().as_ref()],
+        bump,
+        token::mint = old_payment_mint,
+        token::authority = admin_state,
+        token::token_program = old_payment_token_program,
+        constraint = old_vault.amount == 0 @ ProgramErrorCode::VaultNotEmpty
+    )]
+    pub old_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub old_payment_token_program: Interface<'info, TokenInterface>,
+
+    /// Must match the mint named in the proposal - checked in handler
+    #[account(
+        mint::token_program = new_payment_token_program
+    )]
+    pub new_payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        seeds = [b"vault".as_ref(), &campaign_seed(admin_state.campaign_id), new_payment_mint.key().as_ref()],
+        bump,
+        token::mint = new_payment_mint,
+        token::authority = admin_state,
+        token::token_program = new_payment_token_program,
+    )]
+    pub new_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub new_payment_token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Execute an approved add collection proposal (multisig signer only)
+/// The tier id must match the one named in the proposal - checked in handler
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, tier_id: u16)]
+pub struct ExecuteAddCollectionProposal<'info> {
+    /// Pays for the new collection state
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = CollectionState::space(),
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Execute an approved grant or revoke role proposal (multisig signer only)
+/// The role and holder must match the ones named in the proposal - checked in handler
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, role: Role, holder: Pubkey)]
+pub struct ExecuteRoleProposal<'info> {
+    /// Pays for a granted assignment and receives the rent of a revoked one
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = RoleAssignment::space(),
+        seeds = [b"role_assignment".as_ref(), &campaign_seed(admin_state.campaign_id), &role.seed(), holder.as_ref()],
+        bump,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Execute an approved set or revoke operator quota proposal (multisig signer only)
+/// The tier id and operator must match the ones named in the proposal - checked in handler
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, tier_id: u16, operator: Pubkey)]
+pub struct ExecuteOperatorQuotaProposal<'info> {
+    /// Pays for a newly registered operator
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = OperatorQuota::space(),
+        seeds = [b"operator_quota".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref(), operator.as_ref()],
+        bump,
+    )]
+    pub operator_quota: Account<'info, OperatorQuota>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Execute an approved collection NFT metadata or mutability proposal (multisig signer only)
+/// The tier id must match the one named in the proposal - checked in handler
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, tier_id: u16)]
+pub struct ExecuteCollectionNftProposal<'info> {
+    /// Pays for any metadata reallocation
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program - validated by address
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(address = collection_state.collection_mint @ ProgramErrorCode::InvalidCollectionMint)]
+    pub collection_mint: Box<Account<'info, token::Mint>>,
+
+    /// CHECK: Collection metadata account - validated as the collection mint's metadata PDA
+    #[account(
+        mut,
+        address = Metadata::find_pda(&collection_mint.key()).0 @ ProgramErrorCode::InvalidCollectionMetadata,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Sysvar instructions account - required by Metaplex UpdateV1
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+/// Execute an approved collection authority delegate proposal (multisig signer only)
+/// The tier id and delegate must match the ones named in the proposal - checked in handler
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, tier_id: u16)]
+pub struct ExecuteCollectionAuthorityProposal<'info> {
+    /// Pays for the collection authority record
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program - validated by address
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(address = collection_state.collection_mint @ ProgramErrorCode::InvalidCollectionMint)]
+    pub collection_mint: Box<Account<'info, token::Mint>>,
+
+    /// CHECK: Collection metadata account - validated as the collection mint's metadata PDA
+    #[account(address = Metadata::find_pda(&collection_mint.key()).0 @ ProgramErrorCode::InvalidCollectionMetadata)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Delegate named in the proposal - checked in handler
+    pub delegate: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex collection authority record for the delegate - validated as its PDA
+    #[account(
+        mut,
+        address = CollectionAuthorityRecord::find_pda(&collection_mint.key(), &delegate.key()).0
+            @ ProgramErrorCode::InvalidCollectionAuthorityRecord,
+    )]
+    pub collection_authority_record: UncheckedAccount<'info>,
+}
+
+/// Execute an approved collection NFT transfer proposal (multisig signer only)
+/// The destination must match the token account named in the proposal - checked in handler
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, tier_id: u16)]
+pub struct ExecuteTransferCollectionNftProposal<'info> {
+    pub executor: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"multisig".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&executor.key()) @ ProgramErrorCode::NotMultisigSigner
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal".as_ref(), &campaign_seed(admin_state.campaign_id), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProgramErrorCode::ProposalAlreadyExecuted
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(address = collection_state.collection_mint @ ProgramErrorCode::InvalidCollectionMint)]
+    pub collection_mint: Box<Account<'info, token::Mint>>,
+
+    /// The admin_state ATA the collection NFT was minted into
+    #[account(
+        mut,
+        address = get_associated_token_address(&admin_state.key(), &collection_mint.key()),
+        constraint = collection_token_account.amount == 1 @ ProgramErrorCode::InvalidCollectionMint,
+    )]
+    pub collection_token_account: Box<Account<'info, token::TokenAccount>>,
+
+    /// Receives the collection NFT - update authority stays with admin_state
+    #[account(
+        mut,
+        token::mint = collection_mint,
+        constraint = destination_token_account.key() != collection_token_account.key() @ ProgramErrorCode::InvalidCollectionMint,
+    )]
+    pub destination_token_account: Box<Account<'info, token::TokenAccount>>,
+}
+
+/// Earliest execution time - timelocked actions wait out the config delay like queued changes
+fn executable_at(action: &ProposalAction, created_at: i64, admin_state: &AdminState) -> i64 {
+    if action.is_timelocked() {
+        created_at.saturating_add(admin_state.config_delay)
+    } else {
+        created_at
+    }
+}
+
+/// Check the threshold and timelock, then mark the proposal executed, returning its action
+fn take_approved_action(proposal: &mut Proposal, multisig: &Multisig, admin_state: &AdminState) -> Result<ProposalAction> {
+    require!(
+        proposal.approval_count(multisig) >= multisig.threshold as usize,
+        ProgramErrorCode::ProposalThresholdNotMet
+    );
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= executable_at(&proposal.action, proposal.created_at, admin_state),
+        ProgramErrorCode::TimelockNotElapsed
+    );
+    proposal.executed = true;
+    Ok(proposal.action.clone())
+}
+
+/// Resolve the collection state for a tier of this campaign
+pub(crate) fn collection_for_tier<'a>(
+    collection_state: Option<&'a mut Account<'_, CollectionState>>,
+    admin_state: &AdminState,
+    tier_id: u16,
+) -> Result<&'a mut CollectionState> {
+    let collection_state = collection_state.ok_or(ProgramErrorCode::InvalidCollection)?;
+    let expected = Pubkey::create_program_address(
+        &[
+            b"collection_state",
+            &admin_state.campaign_seed(),
+            &tier_id.to_le_bytes(),
+            &[collection_state.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ProgramErrorCode::InvalidCollection)?;
+    require!(collection_state.key() == expected, ProgramErrorCode::InvalidCollection);
+    Ok(&mut **collection_state)
+}
+
+fn emit_executed(admin_state: &AdminState, proposal: &Proposal, executor: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    emit!(ProposalExecutedEvent {
+        campaign_id: admin_state.campaign_id,
+        proposal_id: proposal.id,
+        executor,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
+
+pub fn create_multisig_handler(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    Multisig::validate(&signers, threshold)?;
+
+    ctx.accounts.multisig.set_inner(Multisig {
+        signers,
+        threshold,
+        proposal_count: 0,
+        bump: ctx.bumps.multisig,
+    });
+
+    // Direct privileged instructions are rejected from now on; pending handoffs are dropped
+    let admin_state = &mut ctx.accounts.admin_state;
+    admin_state.multisig_enabled = true;
+    admin_state.pending_super_admin = Pubkey::default();
+    admin_state.pending_super_admin_expires_at = 0;
+    admin_state.pending_withdraw_wallet = Pubkey::default();
+    admin_state.pending_withdraw_wallet_expires_at = 0;
+
+    msg!(
+        "Multisig enabled: {} of {} signers",
+        threshold,
+        ctx.accounts.multisig.signers.len()
+    );
+
+    Ok(())
+}
+
+pub fn create_proposal_handler(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    // Reject signer sets that could never be executed
+    if let ProposalAction::UpdateSigners { signers, threshold } = &action {
+        Multisig::validate(signers, *threshold)?;
+    }
+    if let ProposalAction::UpdateConfigDelay { config_delay } = action {
+        validate_config_delay(config_delay)?;
+    }
+    if let ProposalAction::HandOffUpdateAuthority { new_update_authority } = action {
+        validate_update_authority_handoff(&ctx.accounts.admin_state, ctx.accounts.admin_state.key(), new_update_authority)?;
+    }
+
+    let clock = Clock::get()?;
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal_id = multisig.proposal_count;
+    multisig.proposal_count = proposal_id
+        .checked_add(1)
+        .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+
+    let proposer = ctx.accounts.proposer.key();
+    ctx.accounts.proposal.set_inner(Proposal {
+        id: proposal_id,
+        proposer,
+        action: action.clone(),
+        approvals: vec![proposer],
+        executed: false,
+        created_at: clock.unix_timestamp,
+        bump: ctx.bumps.proposal,
+    });
+
+    msg!("Proposal {} created by {}: {:?}", proposal_id, proposer, action);
+
+    emit!(ProposalCreatedEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
+        proposal_id,
+        proposer,
+        executable_at: executable_at(&action, clock.unix_timestamp, &ctx.accounts.admin_state),
+        action,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn approve_proposal_handler(ctx: Context<ApproveProposal>, proposal_id: u64) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        !proposal.approvals.contains(&approver),
+        ProgramErrorCode::ProposalAlreadyApproved
+    );
+    // Approvals from removed signers are pruned so the list stays within its allocated size
+    let multisig = &ctx.accounts.multisig;
+    proposal.approvals.retain(|key| multisig.is_signer(key));
+    proposal.approvals.push(approver);
+
+    let approvals = proposal.approval_count(multisig);
+    msg!(
+        "Proposal {} approved by {} ({}/{})",
+        proposal_id,
+        approver,
+        approvals,
+        multisig.threshold
+    );
+
+    let clock = Clock::get()?;
+    emit!(ProposalApprovedEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
+        proposal_id,
+        approver,
+        approvals: approvals as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn execute_proposal_handler(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Result<()> {
+    let action = take_approved_action(&mut ctx.accounts.proposal, &ctx.accounts.multisig, &ctx.accounts.admin_state)?;
+    let admin_state = &mut ctx.accounts.admin_state;
+    let collection_state = ctx.accounts.collection_state.as_deref_mut();
+
+    match action {
+        ProposalAction::UpdateMintFee { tier_id, mint_fee } => {
+            apply_mint_fee(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id, mint_fee)?;
+        }
+        ProposalAction::UpdateMaxSupply { tier_id, max_supply } => {
+            apply_max_supply(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id, max_supply)?;
+        }
+        ProposalAction::UpdateAdminMintLimit { tier_id, admin_mint_limit } => {
+            apply_admin_mint_limit(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                admin_mint_limit,
+            )?;
+        }
+        ProposalAction::UpdateCollectionMint { tier_id, collection_mint } => {
+            apply_collection_mint(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                collection_mint,
+            )?;
+        }
+        ProposalAction::UpdateMintStartDate { mint_start_date } => {
+            admin_state.mint_start_date = mint_start_date;
+            msg!("Mint start date updated to: {}", mint_start_date);
+        }
+        ProposalAction::UpdateSuperAdmin { new_super_admin } => {
+            require!(new_super_admin != Pubkey::default(), ProgramErrorCode::InvalidSuperAdmin);
+            require!(new_super_admin != admin_state.super_admin, ProgramErrorCode::SameSuperAdmin);
+            msg!("Super admin updated from {} to {}", admin_state.super_admin, new_super_admin);
+            admin_state.super_admin = new_super_admin;
+        }
+        ProposalAction::UpdateWithdrawWallet { new_withdraw_wallet } => {
+            require!(new_withdraw_wallet != Pubkey::default(), ProgramErrorCode::InvalidWithdrawWallet);
+            require!(new_withdraw_wallet != admin_state.withdraw_wallet, ProgramErrorCode::SameWithdrawWallet);
+            msg!("Withdraw wallet updated from {} to {}", admin_state.withdraw_wallet, new_withdraw_wallet);
+            admin_state.withdraw_wallet = new_withdraw_wallet;
+        }
+        ProposalAction::UpdateSigners { signers, threshold } => {
+            Multisig::validate(&signers, threshold)?;
+            msg!("Multisig signers updated: {} of {}", threshold, signers.len());
+            ctx.accounts.multisig.signers = signers;
+            ctx.accounts.multisig.threshold = threshold;
+        }
+        ProposalAction::UpdateConfigDelay { config_delay } => {
+            validate_config_delay(config_delay)?;
+            admin_state.config_delay = config_delay;
+            msg!("Config delay updated to: {}s", config_delay);
+        }
+        ProposalAction::HandOffUpdateAuthority { new_update_authority } => {
+            apply_update_authority_handoff(admin_state, new_update_authority, Clock::get()?.unix_timestamp)?;
+        }
+        ProposalAction::RetireCollection { tier_id } => {
+            let campaign_id = admin_state.campaign_id;
+            apply_retire_collection(collection_for_tier(collection_state, admin_state, tier_id)?, campaign_id, tier_id)?;
+        }
+        ProposalAction::CloseSale { tier_id } => {
+            let campaign_id = admin_state.campaign_id;
+            apply_close_sale(collection_for_tier(collection_state, admin_state, tier_id)?, campaign_id, tier_id)?;
+        }
+        ProposalAction::UpdateAllowlistRoot { tier_id, allowlist_root } => {
+            apply_allowlist_root(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id, allowlist_root)?;
+        }
+        ProposalAction::UpdateCollectionMetadata { tier_id, name_prefix, symbol, base_uri } => {
+            apply_collection_metadata(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                name_prefix,
+                symbol,
+                base_uri,
+            )?;
+        }
+        ProposalAction::UpdateCollectionRoyalties { tier_id, creators, royalty_bps } => {
+            let admin_state_key = admin_state.key();
+            apply_collection_royalties(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                admin_state_key,
+                tier_id,
+                creators,
+                royalty_bps,
+            )?;
+        }
+        ProposalAction::UpdateGuardian { new_guardian } => {
+            apply_guardian(admin_state, new_guardian)?;
+        }
+        ProposalAction::ReleaseAdminAllocation { tier_id } => {
+            apply_release_admin_allocation(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id)?;
+        }
+        ProposalAction::UpdateSerialInName { tier_id, serial_in_name } => {
+            apply_serial_in_name(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id, serial_in_name)?;
+        }
+        ProposalAction::UpdateSaleWindow { tier_id, sale_start, sale_end } => {
+            apply_sale_window(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id, sale_start, sale_end)?;
+        }
+        ProposalAction::UpdateAdminReleaseDate { tier_id, admin_release_date } => {
+            apply_admin_release_date(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                admin_release_date,
+            )?;
+        }
+        ProposalAction::AddSalePhase { tier_id, start_time, end_time, allowlist_root, price, supply_cap, per_wallet_limit } => {
+            apply_add_sale_phase(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                start_time,
+                end_time,
+                allowlist_root,
+                price,
+                supply_cap,
+                per_wallet_limit,
+            )?;
+        }
+        ProposalAction::UpdateSalePhase {
+            tier_id,
+            phase_index,
+            start_time,
+            end_time,
+            allowlist_root,
+            price,
+            supply_cap,
+            per_wallet_limit,
+        } => {
+            apply_update_sale_phase(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                phase_index,
+                start_time,
+                end_time,
+                allowlist_root,
+                price,
+                supply_cap,
+                per_wallet_limit,
+            )?;
+        }
+        ProposalAction::UpdateWalletReservationCap { max_reservations_per_wallet } => {
+            apply_wallet_reservation_cap(admin_state, max_reservations_per_wallet)?;
+        }
+        ProposalAction::UpdateWalletLimits { tier_id, max_per_wallet, quantity_prices } => {
+            apply_collection_wallet_limits(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                max_per_wallet,
+                quantity_prices,
+            )?;
+        }
+        ProposalAction::CommitProvenance { tier_id, provenance_hash, placeholder_uri, reveal_slot } => {
+            let campaign_id = admin_state.campaign_id;
+            apply_commit_provenance(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                campaign_id,
+                tier_id,
+                provenance_hash,
+                placeholder_uri,
+                reveal_slot,
+            )?;
+        }
+        ProposalAction::PostponeReveal { tier_id, reveal_slot } => {
+            let campaign_id = admin_state.campaign_id;
+            apply_postpone_reveal(collection_for_tier(collection_state, admin_state, tier_id)?, campaign_id, tier_id, reveal_slot)?;
+        }
+        ProposalAction::RevealCollection { tier_id, reveal_base_uri } => {
+            // The offset must already be seeded through seed_reveal - this path has no SlotHashes access
+            require!(
+                admin_state.update_authority_handoff == Pubkey::default(),
+                ProgramErrorCode::UpdateAuthorityHandedOff
+            );
+            let campaign_id = admin_state.campaign_id;
+            apply_reveal_collection(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                campaign_id,
+                tier_id,
+                reveal_base_uri,
+            )?;
+        }
+        ProposalAction::Withdraw { .. }
+        | ProposalAction::UpdatePaymentMint { .. }
+        | ProposalAction::AddCollection { .. }
+        | ProposalAction::GrantRole { .. }
+        | ProposalAction::RevokeRole { .. }
+        | ProposalAction::SetOperatorQuota { .. }
+        | ProposalAction::RevokeOperatorQuota { .. }
+        | ProposalAction::UpdateCollectionNftMetadata { .. }
+        | ProposalAction::SetCollectionNftMutability { .. }
+        | ProposalAction::ApproveCollectionAuthority { .. }
+        | ProposalAction::RevokeCollectionAuthority { .. }
+        | ProposalAction::TransferCollectionNft { .. } => {
+            return err!(ProgramErrorCode::InvalidProposalAction);
+        }
+    }
+
+    msg!("Proposal {} executed", proposal_id);
+    emit_executed(&ctx.accounts.admin_state, &ctx.accounts.proposal, ctx.accounts.executor.key())
+}
+
+pub fn execute_withdraw_proposal_handler(ctx: Context<ExecuteWithdrawProposal>, proposal_id: u64) -> Result<()> {
+    let amount = match take_approved_action(&mut ctx.accounts.proposal, &ctx.accounts.multisig, &ctx.accounts.admin_state)? {
+        ProposalAction::Withdraw { amount } => amount,
+        _ => return err!(ProgramErrorCode::InvalidProposalAction),
+    };
+
+    // Validate amount
+    require!(amount > 0, ProgramErrorCode::InvalidWithdrawAmount);
+    require!(ctx.accounts.vault.amount >= amount, ProgramErrorCode::InsufficientVaultBalance);
+
+    transfer_from_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.payment_mint,
+        &ctx.accounts.withdraw_token_account,
+        &ctx.accounts.admin_state,
+        ctx.bumps.admin_state,
+        amount,
+    )?;
+
+    msg!(
+        "Proposal {} executed - withdrew {} tokens to {}",
+        proposal_id,
+        amount,
+        ctx.accounts.admin_state.withdraw_wallet
+    );
+    emit_executed(&ctx.accounts.admin_state, &ctx.accounts.proposal, ctx.accounts.executor.key())
+}
+
+pub fn execute_payment_mint_proposal_handler(ctx: Context<ExecutePaymentMintProposal>, proposal_id: u64) -> Result<()> {
+    let new_mint = match take_approved_action(&mut ctx.accounts.proposal, &ctx.accounts.multisig, &ctx.accounts.admin_state)? {
+        ProposalAction::UpdatePaymentMint { new_payment_mint } => new_payment_mint,
+        _ => return err!(ProgramErrorCode::InvalidProposalAction),
+    };
+    require!(
+        ctx.accounts.new_payment_mint.key() == new_mint,
+        ProgramErrorCode::InvalidPaymentMint
+    );
+
+    let old_mint = ctx.accounts.old_payment_mint.key();
+    require!(old_mint != new_mint, ProgramErrorCode::SamePaymentMint);
+
+    ctx.accounts.admin_state.payment_mint = new_mint;
+
+    msg!("Proposal {} executed - payment mint updated:", proposal_id);
+    msg!("  From: {}", old_mint);
+    msg!("  To: {}", new_mint);
+    msg!("  New vault: {}", ctx.accounts.new_vault.key());
+
+    emit_executed(&ctx.accounts.admin_state, &ctx.accounts.proposal, ctx.accounts.executor.key())
+}
+
+pub fn execute_add_collection_proposal_handler(
+    ctx: Context<ExecuteAddCollectionProposal>,
+    proposal_id: u64,
+    tier_id: u16,
+) -> Result<()> {
+    let (collection_mint, mint_fee, max_supply, admin_mint_limit) =
+        match take_approved_action(&mut ctx.accounts.proposal, &ctx.accounts.multisig, &ctx.accounts.admin_state)? {
+            ProposalAction::AddCollection { tier_id: proposed, collection_mint, mint_fee, max_supply, admin_mint_limit }
+                if proposed == tier_id =>
+            {
+                (collection_mint, mint_fee, max_supply, admin_mint_limit)
+            }
+            _ => return err!(ProgramErrorCode::InvalidProposalAction),
+        };
+
+    apply_add_collection(
+        &mut ctx.accounts.collection_state,
+        ctx.bumps.collection_state,
+        ctx.accounts.admin_state.campaign_id,
+        tier_id,
+        collection_mint,
+        mint_fee,
+        max_supply,
+        admin_mint_limit,
+    )?;
+
+    msg!("Proposal {} executed", proposal_id);
+    emit_executed(&ctx.accounts.admin_state, &ctx.accounts.proposal, ctx.accounts.executor.key())
+}
+
+pub fn execute_role_proposal_handler(
+    ctx: Context<ExecuteRoleProposal>,
+    proposal_id: u64,
+    role: Role,
+    holder: Pubkey,
+) -> Result<()> {
+    let action = take_approved_action(&mut ctx.accounts.proposal, &ctx.accounts.multisig, &ctx.accounts.admin_state)?;
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+
+    match action {
+        ProposalAction::GrantRole { role: proposed_role, holder: proposed_holder }
+            if proposed_role == role && proposed_holder == holder =>
+        {
+            apply_grant_role(&mut ctx.accounts.role_assignment, ctx.bumps.role_assignment, campaign_id, role, holder)?;
+        }
+        ProposalAction::RevokeRole { role: proposed_role, holder: proposed_holder }
+            if proposed_role == role && proposed_holder == holder =>
+        {
+            // init_if_needed leaves a blank assignment when the role was never granted
+            require!(ctx.accounts.role_assignment.holder == holder, ProgramErrorCode::InvalidRoleHolder);
+            emit_role_revoked(campaign_id, role, holder)?;
+            ctx.accounts.role_assignment.close(ctx.accounts.executor.to_account_info())?;
+        }
+        _ => return err!(ProgramErrorCode::InvalidProposalAction),
+    }
+
+    msg!("Proposal {} executed", proposal_id);
+    emit_executed(&ctx.accounts.admin_state, &ctx.accounts.proposal, ctx.accounts.executor.key())
+}
+
+pub fn execute_operator_quota_proposal_handler(
+    ctx: Context<ExecuteOperatorQuotaProposal>,
+    proposal_id: u64,
+    tier_id: u16,
+    operator: Pubkey,
+) -> Result<()> {
+    let action = take_approved_action(&mut ctx.accounts.proposal, &ctx.accounts.multisig, &ctx.accounts.admin_state)?;
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+
+    match action {
+        ProposalAction::SetOperatorQuota { tier_id: proposed_tier, operator: proposed_operator, quota }
+            if proposed_tier == tier_id && proposed_operator == operator =>
+        {
+            apply_operator_quota(
+                &mut ctx.accounts.operator_quota,
+                ctx.bumps.operator_quota,
+                campaign_id,
+                tier_id,
+                operator,
+                quota,
+            )?;
+        }
+        ProposalAction::RevokeOperatorQuota { tier_id: proposed_tier, operator: proposed_operator }
+            if proposed_tier == tier_id && proposed_operator == operator =>
+        {
+            // init_if_needed leaves a blank quota when the operator was never registered
+            require!(ctx.accounts.operator_quota.operator == operator, ProgramErrorCode::InvalidOperatorQuota);
+            apply_revoke_operator_quota(&mut ctx.accounts.operator_quota, campaign_id, tier_id, operator)?;
+        }
+        _ => return err!(ProgramErrorCode::InvalidProposalAction),
+    }
+
+    msg!("Proposal {} executed", proposal_id);
+    emit_executed(&ctx.accounts.admin_state, &ctx.accounts.proposal, ctx.accounts.executor.key())
+}
+
+pub fn execute_collection_nft_proposal_handler(
+    ctx: Context<ExecuteCollectionNftProposal>,
+    proposal_id: u64,
+    tier_id: u16,
+) -> Result<()> {
+    let action = take_approved_action(&mut ctx.accounts.proposal, &ctx.accounts.multisig, &ctx.accounts.admin_state)?;
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[ctx.bumps.admin_state]]];
+
+    match action {
+        ProposalAction::UpdateCollectionNftMetadata { tier_id: proposed, name, symbol, uri } if proposed == tier_id => {
+            apply_collection_nft_metadata(
+                &ctx.accounts.collection_metadata.to_account_info(),
+                &ctx.accounts.collection_mint.to_account_info(),
+                &ctx.accounts.admin_state.to_account_info(),
+                &ctx.accounts.executor.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.sysvar_instructions.to_account_info(),
+                signer_seeds,
+                campaign_id,
+                tier_id,
+                name,
+                symbol,
+                uri,
+            )?;
+        }
+        ProposalAction::SetCollectionNftMutability { tier_id: proposed, is_mutable } if proposed == tier_id => {
+            apply_collection_nft_mutability(
+                &ctx.accounts.collection_metadata.to_account_info(),
+                &ctx.accounts.collection_mint.to_account_info(),
+                &ctx.accounts.admin_state.to_account_info(),
+                &ctx.accounts.executor.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.sysvar_instructions.to_account_info(),
+                signer_seeds,
+                campaign_id,
+                tier_id,
+                is_mutable,
+            )?;
+        }
+        _ => return err!(ProgramErrorCode::InvalidProposalAction),
+    }
+
+    msg!("Proposal {} executed", proposal_id);
+    emit_executed(&ctx.accounts.admin_state, &ctx.accounts.proposal, ctx.accounts.executor.key())
+}
+
+pub fn execute_collection_authority_proposal_handler(
+    ctx: Context<ExecuteCollectionAuthorityProposal>,
+    proposal_id: u64,
+    tier_id: u16,
+) -> Result<()> {
+    let action = take_approved_action(&mut ctx.accounts.proposal, &ctx.accounts.multisig, &ctx.accounts.admin_state)?;
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[ctx.bumps.admin_state]]];
+    let delegate_key = ctx.accounts.delegate.key();
+
+    match action {
+        ProposalAction::ApproveCollectionAuthority { tier_id: proposed, delegate }
+            if proposed == tier_id && delegate == delegate_key =>
+        {
+            apply_approve_collection_authority(
+                &ctx.accounts.collection_authority_record.to_account_info(),
+                &ctx.accounts.delegate.to_account_info(),
+                &ctx.accounts.admin_state.to_account_info(),
+                &ctx.accounts.executor.to_account_info(),
+                &ctx.accounts.collection_metadata.to_account_info(),
+                &ctx.accounts.collection_mint.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                signer_seeds,
+                campaign_id,
+                tier_id,
+            )?;
+        }
+        ProposalAction::RevokeCollectionAuthority { tier_id: proposed, delegate }
+            if proposed == tier_id && delegate == delegate_key =>
+        {
+            apply_revoke_collection_authority(
+                &ctx.accounts.collection_authority_record.to_account_info(),
+                &ctx.accounts.delegate.to_account_info(),
+                &ctx.accounts.admin_state.to_account_info(),
+                &ctx.accounts.collection_metadata.to_account_info(),
+                &ctx.accounts.collection_mint.to_account_info(),
+                signer_seeds,
+                campaign_id,
+                tier_id,
+            )?;
+        }
+        _ => return err!(ProgramErrorCode::InvalidProposalAction),
+    }
+
+    msg!("Proposal {} executed", proposal_id);
+    emit_executed(&ctx.accounts.admin_state, &ctx.accounts.proposal, ctx.accounts.executor.key())
+}
+
+pub fn execute_transfer_collection_nft_proposal_handler(
+    ctx: Context<ExecuteTransferCollectionNftProposal>,
+    proposal_id: u64,
+    tier_id: u16,
+) -> Result<()> {
+    let destination = match take_approved_action(&mut ctx.accounts.proposal, &ctx.accounts.multisig, &ctx.accounts.admin_state)? {
+        ProposalAction::TransferCollectionNft { tier_id: proposed, destination_token_account } if proposed == tier_id => {
+            destination_token_account
+        }
+        _ => return err!(ProgramErrorCode::InvalidProposalAction),
+    };
+    require!(
+        ctx.accounts.destination_token_account.key() == destination,
+        ProgramErrorCode::InvalidProposalAction
+    );
+
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[ctx.bumps.admin_state]]];
+    apply_transfer_collection_nft(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.collection_token_account,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.admin_state.to_account_info(),
+        signer_seeds,
+        ctx.accounts.admin_state.campaign_id,
+        tier_id,
+    )?;
+
+    msg!("Proposal {} executed", proposal_id);
+    emit_executed(&ctx.accounts.admin_state, &ctx.accounts.proposal, ctx.accounts.executor.key())
+}
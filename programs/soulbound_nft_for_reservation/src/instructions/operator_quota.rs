@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ProgramErrorCode;
+
+// Event definition
+#[event]
+pub struct OperatorQuotaUpdatedEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub operator: Pubkey,
+    pub quota: u64,
+    pub minted_count: u64,
+    pub revoked: bool,
+    pub timestamp: i64,
+}
+
+/// Register or update an airdrop operator's quota for a collection tier (super_admin only)
+#[derive(Accounts)]
+#[instruction(tier_id: u16, operator: Pubkey)]
+pub struct SetOperatorQuota<'info> {
+    /// Only super_admin can manage operator quotas
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    #[account(
+        init_if_needed,
+        payer = super_admin,
+        space = OperatorQuota::space(),
+        seeds = [b"operator_quota".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref(), operator.as_ref()],
+        bump,
+    )]
+    pub operator_quota: Account<'info, OperatorQuota>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Revoke an airdrop operator for a collection tier (super_admin only)
+#[derive(Accounts)]
+#[instruction(tier_id: u16, operator: Pubkey)]
+pub struct RevokeOperatorQuota<'info> {
+    /// Only super_admin can revoke operators
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_quota".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref(), operator.as_ref()],
+        bump = operator_quota.bump,
+    )]
+    pub operator_quota: Account<'info, OperatorQuota>,
+}
+
+fn emit_quota_updated(campaign_id: u64, operator_quota: &OperatorQuota) -> Result<()> {
+    let clock = Clock::get()?;
+    emit!(OperatorQuotaUpdatedEvent {
+        campaign_id,
+        tier_id: operator_quota.tier_id,
+        operator: operator_quota.operator,
+        quota: operator_quota.quota,
+        minted_count: operator_quota.minted_count,
+        revoked: operator_quota.revoked,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
+
+pub fn set_operator_quota_handler(
+    ctx: Context<SetOperatorQuota>,
+    tier_id: u16,
+    operator: Pubkey,
+    quota: u64,
+) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    apply_operator_quota(&mut ctx.accounts.operator_quota, ctx.bumps.operator_quota, campaign_id, tier_id, operator, quota)
+}
+
+/// Shared by set_operator_quota and multisig proposals
+pub(crate) fn apply_operator_quota(
+    operator_quota: &mut OperatorQuota,
+    bump: u8,
+    campaign_id: u64,
+    tier_id: u16,
+    operator: Pubkey,
+    quota: u64,
+) -> Result<()> {
+    require!(operator != Pubkey::default(), ProgramErrorCode::InvalidRoleHolder);
+
+    // Re-registering keeps the operator's counter, so the quota cannot be reset by revoking and re-adding
+    require!(quota >= operator_quota.minted_count, ProgramErrorCode::InvalidOperatorQuota);
+
+    operator_quota.operator = operator;
+    operator_quota.tier_id = tier_id;
+    operator_quota.quota = quota;
+    operator_quota.revoked = false;
+    operator_quota.bump = bump;
+
+    msg!(
+        "Operator {} quota for tier {} set to {} ({} used)",
+        operator,
+        tier_id,
+        quota,
+        operator_quota.minted_count
+    );
+
+    emit_quota_updated(campaign_id, operator_quota)
+}
+
+pub fn revoke_operator_quota_handler(ctx: Context<RevokeOperatorQuota>, tier_id: u16, operator: Pubkey) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    apply_revoke_operator_quota(&mut ctx.accounts.operator_quota, campaign_id, tier_id, operator)
+}
+
+/// Shared by revoke_operator_quota and multisig proposals
+pub(crate) fn apply_revoke_operator_quota(
+    operator_quota: &mut OperatorQuota,
+    campaign_id: u64,
+    tier_id: u16,
+    operator: Pubkey,
+) -> Result<()> {
+    operator_quota.revoked = true;
+
+    msg!("Operator {} revoked for tier {}", operator, tier_id);
+
+    emit_quota_updated(campaign_id, operator_quota)
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ProgramErrorCode;
+use crate::instructions::UpdateAdminInfo;
+
+// Event definition
+#[event]
+pub struct PauseFlagsUpdatedEvent {
+    pub campaign_id: u64,
+    pub authority: Pubkey,
+    pub pause_flags: PauseFlags,
+    pub timestamp: i64,
+}
+
+/// Set pause flags (guardian or super_admin) - flags can only be added here
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    /// Guardian or super_admin
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = (authority.key() == admin_state.guardian || authority.key() == admin_state.super_admin)
+            @ ProgramErrorCode::Unauthorized
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+}
+
+pub fn update_guardian_handler(ctx: Context<UpdateAdminInfo>, new_guardian: Pubkey) -> Result<()> {
+    require!(!ctx.accounts.admin_state.multisig_enabled, ProgramErrorCode::MultisigRequired);
+    apply_guardian(&mut ctx.accounts.admin_state, new_guardian)
+}
+
+/// Shared by update_guardian and multisig proposals
+pub(crate) fn apply_guardian(admin_state: &mut AdminState, new_guardian: Pubkey) -> Result<()> {
+    let old_guardian = admin_state.guardian;
+    admin_state.guardian = new_guardian;
+
+    msg!("Guardian updated:");
+    msg!("  From: {}", old_guardian);
+    msg!("  To: {}", new_guardian);
+
+    Ok(())
+}
+
+pub fn set_pause_flags_handler(ctx: Context<SetPauseFlags>, flags: PauseFlags) -> Result<()> {
+    let admin_state = &mut ctx.accounts.admin_state;
+    admin_state.pause_flags = admin_state.pause_flags.union(&flags);
+
+    msg!("Pause flags set by {}", ctx.accounts.authority.key());
+
+    let clock = Clock::get()?;
+    emit!(PauseFlagsUpdatedEvent {
+        campaign_id: admin_state.campaign_id,
+        authority: ctx.accounts.authority.key(),
+        pause_flags: admin_state.pause_flags,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn clear_pause_flags_handler(ctx: Context<UpdateAdminInfo>, flags: PauseFlags) -> Result<()> {
+    let admin_state = &mut ctx.accounts.admin_state;
+    admin_state.pause_flags = admin_state.pause_flags.difference(&flags);
+
+    msg!("Pause flags cleared by super admin");
+
+    let clock = Clock::get()?;
+    emit!(PauseFlagsUpdatedEvent {
+        campaign_id: admin_state.campaign_id,
+        authority: ctx.accounts.super_admin.key(),
+        pause_flags: admin_state.pause_flags,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ProgramErrorCode;
+use crate::instructions::{UpdateAdminInfo, UpdateCollectionInfo};
+
+/// Reject a mint for a wallet that already holds the campaign's maximum number of reservations
+pub(crate) fn require_wallet_capacity(admin_state: &AdminState, reservation_count: u16) -> Result<()> {
+    if reservation_count < admin_state.max_reservations_per_wallet {
+        return Ok(());
+    }
+    // Keep the original error for the one-per-wallet policy
+    if admin_state.max_reservations_per_wallet <= 1 {
+        err!(ProgramErrorCode::UserAlreadyMinted)
+    } else {
+        err!(ProgramErrorCode::WalletReservationLimitReached)
+    }
+}
+
+pub fn update_wallet_reservation_cap_handler(
+    ctx: Context<UpdateAdminInfo>,
+    max_reservations_per_wallet: u16,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_state.multisig_enabled, ProgramErrorCode::MultisigRequired);
+    apply_wallet_reservation_cap(&mut ctx.accounts.admin_state, max_reservations_per_wallet)
+}
+
+/// Shared by update_wallet_reservation_cap and multisig proposals
+pub(crate) fn apply_wallet_reservation_cap(admin_state: &mut AdminState, max_reservations_per_wallet: u16) -> Result<()> {
+    // Wallets above a lowered cap keep their reservations but cannot mint more
+    require!(max_reservations_per_wallet > 0, ProgramErrorCode::InvalidWalletLimit);
+
+    admin_state.max_reservations_per_wallet = max_reservations_per_wallet;
+    msg!("Max reservations per wallet updated to: {}", max_reservations_per_wallet);
+    Ok(())
+}
+
+pub fn update_collection_wallet_limits_handler(
+    ctx: Context<UpdateCollectionInfo>,
+    tier_id: u16,
+    max_per_wallet: u16,
+    quantity_prices: Vec<QuantityPrice>,
+) -> Result<()> {
+    require!(ctx.accounts.admin_state.config_delay == 0, ProgramErrorCode::TimelockActive);
+    apply_collection_wallet_limits(&mut ctx.accounts.collection_state, tier_id, max_per_wallet, quantity_prices)
+}
+
+/// Shared by update_collection_wallet_limits and multisig proposals
+pub(crate) fn apply_collection_wallet_limits(
+    collection_state: &mut CollectionState,
+    tier_id: u16,
+    max_per_wallet: u16,
+    quantity_prices: Vec<QuantityPrice>,
+) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
+
+    // Breaks must start at the first reservation or later, ascend strictly and carry a price
+    require!(
+        quantity_prices.len() <= MAX_QUANTITY_PRICES,
+        ProgramErrorCode::InvalidQuantityPrices
+    );
+    require!(
+        quantity_prices.iter().all(|quantity_price| quantity_price.min_quantity > 0 && quantity_price.price > 0),
+        ProgramErrorCode::InvalidQuantityPrices
+    );
+    require!(
+        quantity_prices.windows(2).all(|pair| pair[0].min_quantity < pair[1].min_quantity),
+        ProgramErrorCode::InvalidQuantityPrices
+    );
+
+    collection_state.max_per_wallet = max_per_wallet;
+    collection_state.quantity_prices = quantity_prices;
+
+    msg!(
+        "Collection tier {} wallet limit: {}, quantity price breaks: {}",
+        tier_id,
+        max_per_wallet,
+        collection_state.quantity_prices.len()
+    );
+    Ok(())
+}
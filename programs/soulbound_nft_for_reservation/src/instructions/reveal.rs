@@ -0,0 +1,461 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::{accounts::Metadata, types::Data};
+
+use crate::error::ProgramErrorCode;
+use crate::instructions::update_nft_metadata::{read_metadata, trim_padding, update_metadata_data};
+use crate::instructions::UpdateCollectionInfo;
+use crate::state::*;
+
+/// Accounts each NFT supplies to the reveal crank through remaining_accounts, in this order:
+/// serial record, mint, metadata account
+pub const REVEAL_NFT_ACCOUNTS: usize = 3;
+
+/// Upper bound on NFTs revealed per crank call - each one is a Metaplex CPI
+pub const MAX_REVEAL_BATCH: usize = 5;
+
+// Event definitions
+#[event]
+pub struct ProvenanceCommittedEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub provenance_hash: [u8; 32],
+    pub placeholder_uri: String,
+    pub art_count: u64,
+    pub reveal_slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevealPostponedEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub reveal_slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevealSeededEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub seed_slot: u64,
+    pub reveal_offset: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionRevealedEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub provenance_hash: [u8; 32],
+    pub reveal_base_uri: String,
+    pub reveal_offset: u64,
+    pub reveal_slot: u64,
+    pub art_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevealCrankEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub cranker: Pubkey,
+    pub revealed: u8,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct RevealCollection<'info> {
+    /// Only super_admin can reveal a collection
+    pub super_admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired,
+        constraint = admin_state.update_authority_handoff == Pubkey::default() @ ProgramErrorCode::UpdateAuthorityHandedOff,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    /// CHECK: SlotHashes sysvar - validated by address, read directly since it is too large to deserialize
+    #[account(address = solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct SeedReveal<'info> {
+    /// Anyone can draw the offset once the committed slot has been produced
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    /// CHECK: SlotHashes sysvar - validated by address, read directly since it is too large to deserialize
+    #[account(address = solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct CrankReveal<'info> {
+    /// Anyone can crank - pays for any metadata reallocation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program - validated by address
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+        constraint = admin_state.update_authority_handoff == Pubkey::default() @ ProgramErrorCode::UpdateAuthorityHandedOff,
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+        constraint = collection_state.revealed @ ProgramErrorCode::NotRevealed,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
+    /// CHECK: Sysvar instructions account - required by Metaplex UpdateV1
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+pub fn commit_provenance_handler(
+    ctx: Context<UpdateCollectionInfo>,
+    tier_id: u16,
+    provenance_hash: [u8; 32],
+    placeholder_uri: String,
+    reveal_slot: u64,
+) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    apply_commit_provenance(
+        &mut ctx.accounts.collection_state,
+        campaign_id,
+        tier_id,
+        provenance_hash,
+        placeholder_uri,
+        reveal_slot,
+    )
+}
+
+/// Shared by commit_provenance and multisig proposals
+pub(crate) fn apply_commit_provenance(
+    collection_state: &mut CollectionState,
+    campaign_id: u64,
+    tier_id: u16,
+    provenance_hash: [u8; 32],
+    placeholder_uri: String,
+    reveal_slot: u64,
+) -> Result<()> {
+    require!(!collection_state.revealed, ProgramErrorCode::AlreadyRevealed);
+
+    // The commitment only means something if it predates every mint
+    require!(collection_state.last_serial == 0, ProgramErrorCode::ProvenanceLocked);
+    require!(collection_state.max_supply > 0, ProgramErrorCode::InvalidRevealConfig);
+    require!(
+        !placeholder_uri.is_empty() && placeholder_uri.len() <= MAX_NFT_URI_LENGTH,
+        ProgramErrorCode::InvalidRevealConfig
+    );
+    // The offset comes from a slot hash nobody can know at commit time
+    require!(reveal_slot > Clock::get()?.slot, ProgramErrorCode::InvalidRevealConfig);
+
+    collection_state.provenance_hash = Some(provenance_hash);
+    collection_state.placeholder_uri = placeholder_uri;
+    collection_state.art_count = collection_state.max_supply;
+    collection_state.reveal_slot = reveal_slot;
+
+    msg!(
+        "Collection tier {} provenance committed over {} artworks, revealed from slot {}",
+        tier_id,
+        collection_state.art_count,
+        reveal_slot
+    );
+
+    emit!(ProvenanceCommittedEvent {
+        campaign_id,
+        tier_id,
+        provenance_hash,
+        placeholder_uri: collection_state.placeholder_uri.clone(),
+        art_count: collection_state.art_count,
+        reveal_slot,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn postpone_reveal_handler(ctx: Context<UpdateCollectionInfo>, tier_id: u16, reveal_slot: u64) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    apply_postpone_reveal(&mut ctx.accounts.collection_state, campaign_id, tier_id, reveal_slot)
+}
+
+/// Shared by postpone_reveal and multisig proposals
+pub(crate) fn apply_postpone_reveal(collection_state: &mut CollectionState, campaign_id: u64, tier_id: u16, reveal_slot: u64) -> Result<()> {
+    require!(collection_state.provenance_hash.is_some(), ProgramErrorCode::ProvenanceNotCommitted);
+    require!(
+        !collection_state.revealed && !collection_state.reveal_seeded,
+        ProgramErrorCode::AlreadyRevealed
+    );
+
+    // Only a slot whose hash is still unknown can be moved, and only further out. A slot nobody
+    // seeded from before it left the SlotHashes window can be replaced by a new future slot
+    let current_slot = Clock::get()?.slot;
+    let expired = current_slot > collection_state.reveal_slot.saturating_add(SLOT_HASHES_WINDOW);
+    require!(
+        current_slot < collection_state.reveal_slot || expired,
+        ProgramErrorCode::RevealSlotReached
+    );
+    require!(
+        reveal_slot > collection_state.reveal_slot && reveal_slot > current_slot,
+        ProgramErrorCode::InvalidRevealConfig
+    );
+
+    collection_state.reveal_slot = reveal_slot;
+
+    msg!("Collection tier {} reveal postponed to slot {}", tier_id, reveal_slot);
+
+    emit!(RevealPostponedEvent {
+        campaign_id,
+        tier_id,
+        reveal_slot,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Slots the SlotHashes sysvar keeps - a committed slot older than this can no longer seed the reveal
+const SLOT_HASHES_WINDOW: u64 = solana_program::slot_hashes::MAX_ENTRIES as u64;
+
+/// Hash of the first block at or after `target_slot`, read from SlotHashes data
+/// (u64 entry count, then (u64 slot, [u8; 32] hash) entries, newest first)
+fn committed_slot_seed(data: &[u8], target_slot: u64) -> Result<(u64, u64)> {
+    const ENTRY_SIZE: usize = 8 + 32;
+    require!(data.len() >= 8, ProgramErrorCode::RevealSlotPending);
+    let count = u64::from_le_bytes(data[..8].try_into().unwrap()) as usize;
+
+    let mut seed = None;
+    for entry in data[8..].chunks_exact(ENTRY_SIZE).take(count) {
+        let slot = u64::from_le_bytes(entry[..8].try_into().unwrap());
+        if slot < target_slot {
+            // The window reaches back past the target, so `seed` is the first block at or after it
+            return seed.ok_or_else(|| error!(ProgramErrorCode::RevealSlotPending));
+        }
+        seed = Some((slot, u64::from_le_bytes(entry[8..16].try_into().unwrap())));
+    }
+
+    // Every entry is at or after the target - the block that decides the offset may have aged out
+    match seed {
+        Some(_) => err!(ProgramErrorCode::RevealSlotExpired),
+        None => err!(ProgramErrorCode::RevealSlotPending),
+    }
+}
+
+/// Draw reveal_offset from the committed slot - shared by seed_reveal and reveal_collection
+fn seed_reveal_offset(collection_state: &mut CollectionState, campaign_id: u64, tier_id: u16, slot_hashes: &[u8]) -> Result<()> {
+    require!(collection_state.provenance_hash.is_some(), ProgramErrorCode::ProvenanceNotCommitted);
+    require!(
+        !collection_state.revealed && !collection_state.reveal_seeded,
+        ProgramErrorCode::AlreadyRevealed
+    );
+
+    // The committed slot was in the future when the provenance was committed, so the admin
+    // could not pick an offset
+    let (seed_slot, seed) = committed_slot_seed(slot_hashes, collection_state.reveal_slot)?;
+    collection_state.reveal_offset = seed % collection_state.art_count;
+    collection_state.reveal_seeded = true;
+
+    msg!(
+        "Collection tier {} reveal offset {} drawn from slot {}",
+        tier_id,
+        collection_state.reveal_offset,
+        seed_slot
+    );
+
+    emit!(RevealSeededEvent {
+        campaign_id,
+        tier_id,
+        seed_slot,
+        reveal_offset: collection_state.reveal_offset,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn seed_reveal_handler(ctx: Context<SeedReveal>, tier_id: u16) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    seed_reveal_offset(
+        &mut ctx.accounts.collection_state,
+        campaign_id,
+        tier_id,
+        &ctx.accounts.slot_hashes.try_borrow_data()?,
+    )
+}
+
+pub fn reveal_collection_handler(ctx: Context<RevealCollection>, tier_id: u16, reveal_base_uri: String) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    if !ctx.accounts.collection_state.reveal_seeded {
+        seed_reveal_offset(
+            &mut ctx.accounts.collection_state,
+            campaign_id,
+            tier_id,
+            &ctx.accounts.slot_hashes.try_borrow_data()?,
+        )?;
+    }
+    apply_reveal_collection(&mut ctx.accounts.collection_state, campaign_id, tier_id, reveal_base_uri)
+}
+
+/// Shared by reveal_collection and multisig proposals - the offset must already be drawn
+pub(crate) fn apply_reveal_collection(
+    collection_state: &mut CollectionState,
+    campaign_id: u64,
+    tier_id: u16,
+    reveal_base_uri: String,
+) -> Result<()> {
+    let provenance_hash = collection_state
+        .provenance_hash
+        .ok_or(ProgramErrorCode::ProvenanceNotCommitted)?;
+    require!(!collection_state.revealed, ProgramErrorCode::AlreadyRevealed);
+    require!(collection_state.reveal_seeded, ProgramErrorCode::RevealNotSeeded);
+    require!(
+        !reveal_base_uri.is_empty() && reveal_base_uri.len() <= MAX_NFT_URI_LENGTH - SERIAL_URI_SUFFIX_LENGTH,
+        ProgramErrorCode::InvalidRevealConfig
+    );
+
+    collection_state.revealed = true;
+    collection_state.reveal_base_uri = reveal_base_uri;
+
+    msg!("Collection tier {} revealed with offset {}", tier_id, collection_state.reveal_offset);
+
+    emit!(CollectionRevealedEvent {
+        campaign_id,
+        tier_id,
+        provenance_hash,
+        reveal_base_uri: collection_state.reveal_base_uri.clone(),
+        reveal_offset: collection_state.reveal_offset,
+        reveal_slot: collection_state.reveal_slot,
+        art_count: collection_state.art_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn crank_reveal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CrankReveal<'info>>,
+    tier_id: u16,
+) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    let requested = remaining.len() / REVEAL_NFT_ACCOUNTS;
+    require!(
+        requested > 0 && requested * REVEAL_NFT_ACCOUNTS == remaining.len(),
+        ProgramErrorCode::InvalidRevealAccounts
+    );
+    require!(requested <= MAX_REVEAL_BATCH, ProgramErrorCode::InvalidRevealAccounts);
+
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let bump = ctx.bumps.admin_state;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[bump]]];
+
+    let payer = ctx.accounts.payer.to_account_info();
+    let admin_state = ctx.accounts.admin_state.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let sysvar_instructions = ctx.accounts.sysvar_instructions.to_account_info();
+
+    let mut revealed: u8 = 0;
+    for accounts in remaining.chunks(REVEAL_NFT_ACCOUNTS) {
+        let [serial_record_info, mint, metadata_account] = accounts else {
+            return err!(ProgramErrorCode::InvalidRevealAccounts);
+        };
+
+        // The record must be this collection's registry entry for the serial it claims
+        require!(serial_record_info.owner == &crate::ID, ProgramErrorCode::InvalidSerialRecord);
+        require!(serial_record_info.is_writable, ProgramErrorCode::InvalidSerialRecord);
+        let mut serial_record = SerialRecord::try_deserialize(&mut &serial_record_info.try_borrow_data()?[..])?;
+        require!(serial_record.tier_id == tier_id, ProgramErrorCode::InvalidSerialRecord);
+        let expected_serial_record = Pubkey::create_program_address(
+            &[
+                b"serial",
+                &campaign_seed,
+                &tier_id.to_le_bytes(),
+                &serial_record.serial.to_le_bytes(),
+                &[serial_record.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| ProgramErrorCode::InvalidSerialRecord)?;
+        require!(serial_record_info.key() == expected_serial_record, ProgramErrorCode::InvalidSerialRecord);
+
+        if serial_record.burned || serial_record.revealed {
+            msg!("Skipping serial #{} - already revealed or burned", serial_record.serial);
+            continue;
+        }
+
+        require!(mint.key() == serial_record.mint_address, ProgramErrorCode::InvalidRevealAccounts);
+        require!(
+            metadata_account.key() == Metadata::find_pda(mint.key).0,
+            ProgramErrorCode::InvalidRevealAccounts
+        );
+
+        // Only the URI changes; name, symbol and creators are kept as minted
+        let metadata = read_metadata(metadata_account)?;
+        let data = Data {
+            name: trim_padding(&metadata.name),
+            symbol: trim_padding(&metadata.symbol),
+            uri: ctx.accounts.collection_state.revealed_uri(serial_record.serial),
+            seller_fee_basis_points: metadata.seller_fee_basis_points,
+            creators: metadata.creators,
+        };
+        update_metadata_data(
+            metadata_account,
+            mint,
+            &admin_state,
+            &payer,
+            &system_program,
+            &sysvar_instructions,
+            Some(data),
+            None,
+            None,
+            signer_seeds,
+        )?;
+
+        serial_record.revealed = true;
+        serial_record.try_serialize(&mut &mut serial_record_info.try_borrow_mut_data()?[..])?;
+        msg!("Serial #{} revealed", serial_record.serial);
+        revealed += 1;
+    }
+
+    emit!(RevealCrankEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
+        tier_id,
+        cranker: payer.key(),
+        revealed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
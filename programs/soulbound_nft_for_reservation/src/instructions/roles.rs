@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ProgramErrorCode;
+
+// Event definitions
+#[event]
+pub struct RoleGrantedEvent {
+    pub campaign_id: u64,
+    pub role: Role,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevokedEvent {
+    pub campaign_id: u64,
+    pub role: Role,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Grant a role to a holder (super_admin only)
+#[derive(Accounts)]
+#[instruction(role: Role, holder: Pubkey)]
+pub struct GrantRole<'info> {
+    /// Only super_admin can grant roles
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        init,
+        payer = super_admin,
+        space = RoleAssignment::space(),
+        seeds = [b"role_assignment".as_ref(), &campaign_seed(admin_state.campaign_id), &role.seed(), holder.as_ref()],
+        bump,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Revoke a role and return the rent to the super_admin (super_admin only)
+#[derive(Accounts)]
+#[instruction(role: Role, holder: Pubkey)]
+pub struct RevokeRole<'info> {
+    /// Only super_admin can revoke roles
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        close = super_admin,
+        seeds = [b"role_assignment".as_ref(), &campaign_seed(admin_state.campaign_id), &role.seed(), holder.as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+}
+
+pub fn grant_role_handler(ctx: Context<GrantRole>, role: Role, holder: Pubkey) -> Result<()> {
+    let campaign_id = ctx.accounts.admin_state.campaign_id;
+    apply_grant_role(&mut ctx.accounts.role_assignment, ctx.bumps.role_assignment, campaign_id, role, holder)
+}
+
+/// Shared by grant_role and multisig proposals
+pub(crate) fn apply_grant_role(
+    role_assignment: &mut RoleAssignment,
+    bump: u8,
+    campaign_id: u64,
+    role: Role,
+    holder: Pubkey,
+) -> Result<()> {
+    require!(holder != Pubkey::default(), ProgramErrorCode::InvalidRoleHolder);
+
+    let clock = Clock::get()?;
+    *role_assignment = RoleAssignment {
+        role,
+        holder,
+        granted_at: clock.unix_timestamp,
+        bump,
+    };
+
+    msg!("Role {:?} granted to {}", role, holder);
+
+    emit!(RoleGrantedEvent {
+        campaign_id,
+        role,
+        holder,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn revoke_role_handler(ctx: Context<RevokeRole>, role: Role, holder: Pubkey) -> Result<()> {
+    emit_role_revoked(ctx.accounts.admin_state.campaign_id, role, holder)
+}
+
+/// Shared by revoke_role and multisig proposals - the caller closes the assignment
+pub(crate) fn emit_role_revoked(campaign_id: u64, role: Role, holder: Pubkey) -> Result<()> {
+    msg!("Role {:?} revoked from {}", role, holder);
+
+    let clock = Clock::get()?;
+    emit!(RoleRevokedEvent {
+        campaign_id,
+        role,
+        holder,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ProgramErrorCode;
+use crate::instructions::UpdateCollectionInfo;
+
+fn validate_sale_phase(phase: &SalePhase, other_phases: &[SalePhase]) -> Result<()> {
+    require!(phase.start_time < phase.end_time, ProgramErrorCode::InvalidSalePhase);
+    require!(
+        other_phases.iter().all(|other| !phase.overlaps(other)),
+        ProgramErrorCode::InvalidSalePhase
+    );
+    if let Some(price) = phase.price {
+        require!(price > 0, ProgramErrorCode::InvalidMintFee);
+    }
+    require!(
+        phase.supply_cap == 0 || phase.supply_cap >= phase.minted_count,
+        ProgramErrorCode::InvalidMaxSupply
+    );
+    Ok(())
+}
+
+pub fn add_sale_phase_handler(
+    ctx: Context<UpdateCollectionInfo>,
+    tier_id: u16,
+    start_time: i64,
+    end_time: i64,
+    allowlist_root: Option<[u8; 32]>,
+    price: Option<u64>,
+    supply_cap: u64,
+    per_wallet_limit: u16,
+) -> Result<()> {
+    require!(ctx.accounts.admin_state.config_delay == 0, ProgramErrorCode::TimelockActive);
+    apply_add_sale_phase(
+        &mut ctx.accounts.collection_state,
+        tier_id,
+        start_time,
+        end_time,
+        allowlist_root,
+        price,
+        supply_cap,
+        per_wallet_limit,
+    )
+}
+
+/// Shared by add_sale_phase and multisig proposals
+pub(crate) fn apply_add_sale_phase(
+    collection_state: &mut CollectionState,
+    tier_id: u16,
+    start_time: i64,
+    end_time: i64,
+    allowlist_root: Option<[u8; 32]>,
+    price: Option<u64>,
+    supply_cap: u64,
+    per_wallet_limit: u16,
+) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
+    require!(
+        collection_state.sale_phases.len() < MAX_SALE_PHASES,
+        ProgramErrorCode::TooManySalePhases
+    );
+
+    let phase = SalePhase {
+        start_time,
+        end_time,
+        allowlist_root,
+        price,
+        supply_cap,
+        per_wallet_limit,
+        minted_count: 0,
+    };
+    validate_sale_phase(&phase, &collection_state.sale_phases)?;
+    collection_state.sale_phases.push(phase);
+
+    msg!(
+        "Collection tier {} sale phase {} added: {} - {}, cap: {}, wallet limit: {}",
+        tier_id,
+        collection_state.sale_phases.len() - 1,
+        start_time,
+        end_time,
+        supply_cap,
+        per_wallet_limit
+    );
+    Ok(())
+}
+
+pub fn update_sale_phase_handler(
+    ctx: Context<UpdateCollectionInfo>,
+    tier_id: u16,
+    phase_index: u8,
+    start_time: i64,
+    end_time: i64,
+    allowlist_root: Option<[u8; 32]>,
+    price: Option<u64>,
+    supply_cap: u64,
+    per_wallet_limit: u16,
+) -> Result<()> {
+    require!(ctx.accounts.admin_state.config_delay == 0, ProgramErrorCode::TimelockActive);
+    apply_update_sale_phase(
+        &mut ctx.accounts.collection_state,
+        tier_id,
+        phase_index,
+        start_time,
+        end_time,
+        allowlist_root,
+        price,
+        supply_cap,
+        per_wallet_limit,
+    )
+}
+
+/// Shared by update_sale_phase and multisig proposals
+pub(crate) fn apply_update_sale_phase(
+    collection_state: &mut CollectionState,
+    tier_id: u16,
+    phase_index: u8,
+    start_time: i64,
+    end_time: i64,
+    allowlist_root: Option<[u8; 32]>,
+    price: Option<u64>,
+    supply_cap: u64,
+    per_wallet_limit: u16,
+) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
+    let index = phase_index as usize;
+    require!(
+        index < collection_state.sale_phases.len(),
+        ProgramErrorCode::InvalidSalePhaseIndex
+    );
+
+    // Mints already made in the phase are kept so the cap cannot be reset
+    let phase = SalePhase {
+        start_time,
+        end_time,
+        allowlist_root,
+        price,
+        supply_cap,
+        per_wallet_limit,
+        minted_count: collection_state.sale_phases[index].minted_count,
+    };
+    let other_phases: Vec<SalePhase> = collection_state
+        .sale_phases
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, other)| *other)
+        .collect();
+    validate_sale_phase(&phase, &other_phases)?;
+    collection_state.sale_phases[index] = phase;
+
+    msg!(
+        "Collection tier {} sale phase {} updated: {} - {}, cap: {}, wallet limit: {}",
+        tier_id,
+        phase_index,
+        start_time,
+        end_time,
+        supply_cap,
+        per_wallet_limit
+    );
+    Ok(())
+}
@@ -0,0 +1,441 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::error::ProgramErrorCode;
+use crate::instructions::authority_handoff::{apply_update_authority_handoff, validate_update_authority_handoff};
+use crate::instructions::multisig::collection_for_tier;
+use crate::instructions::reservation_policy::apply_collection_wallet_limits;
+use crate::instructions::sale_phase::{apply_add_sale_phase, apply_update_sale_phase};
+use crate::instructions::update_admin::{
+    apply_admin_mint_limit, apply_allowlist_root, apply_collection_mint, apply_max_supply, apply_mint_fee,
+    apply_release_admin_allocation, apply_sale_window,
+};
+use crate::instructions::UpdateAdminInfo;
+
+// Event definitions
+#[event]
+pub struct ConfigChangeQueuedEvent {
+    pub campaign_id: u64,
+    pub change_id: u64,
+    pub change: ConfigChange,
+    pub queued_by: Pubkey,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeExecutedEvent {
+    pub campaign_id: u64,
+    pub change_id: u64,
+    pub change: ConfigChange,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeCancelledEvent {
+    pub campaign_id: u64,
+    pub change_id: u64,
+    pub change: ConfigChange,
+    pub timestamp: i64,
+}
+
+/// Queue a config change (super_admin, or fee manager for fee and supply changes)
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    /// Pays for the pending change - refunded on execution or cancellation
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PendingChange::space(),
+        seeds = [b"pending_change".as_ref(), &campaign_seed(admin_state.campaign_id), admin_state.config_change_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub pending_change: Box<Account<'info, PendingChange>>,
+
+    /// Fee manager role assignment - omitted when the super admin signs
+    #[account(
+        seeds = [b"role_assignment".as_ref(), &campaign_seed(admin_state.campaign_id), &Role::FeeManager.seed(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Apply a queued change once its delay has passed (permissionless)
+/// Collection changes need the CollectionState of the change's tier
+#[derive(Accounts)]
+#[instruction(change_id: u64)]
+pub struct ExecuteConfigChange<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [b"pending_change".as_ref(), &campaign_seed(admin_state.campaign_id), change_id.to_le_bytes().as_ref()],
+        bump = pending_change.bump,
+    )]
+    pub pending_change: Box<Account<'info, PendingChange>>,
+
+    /// CHECK: Rent refund destination - must be the key that queued the change
+    #[account(mut, address = pending_change.queued_by)]
+    pub queued_by: UncheckedAccount<'info>,
+
+    /// Validated against the change's tier id in the handler
+    #[account(mut)]
+    pub collection_state: Option<Box<Account<'info, CollectionState>>>,
+}
+
+/// Apply a queued payment mint change once its delay has passed (permissionless)
+/// The old vault must be empty, as with update_payment_mint
+#[derive(Accounts)]
+#[instruction(change_id: u64)]
+pub struct ExecutePaymentMintChange<'info> {
+    /// Pays for the new vault if it does not exist yet
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [b"pending_change".as_ref(), &campaign_seed(admin_state.campaign_id), change_id.to_le_bytes().as_ref()],
+        bump = pending_change.bump,
+    )]
+    pub pending_change: Box<Account<'info, PendingChange>>,
+
+    /// CHECK: Rent refund destination - must be the key that queued the change
+    #[account(mut, address = pending_change.queued_by)]
+    pub queued_by: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = old_payment_mint.key() == admin_state.payment_mint @ ProgramErrorCode::InvalidPaymentMint,
+        mint::token_program = old_payment_token_program
+    )]
+    pub old_payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"vault".as_ref(), &campaign_seed(admin_state.campaign_id), old_payment_mint.key().as_ref()],
+        bump,
+        token::mint = old_payment_mint,
+        token::authority = admin_state,
+        token::token_program = old_payment_token_program,
+        constraint = old_vault.amount == 0 @ ProgramErrorCode::VaultNotEmpty
+    )]
+    pub old_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub old_payment_token_program: Interface<'info, TokenInterface>,
+
+    /// Must match the mint named in the change - checked in handler
+    #[account(
+        mint::token_program = new_payment_token_program
+    )]
+    pub new_payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        seeds = [b"vault".as_ref(), &campaign_seed(admin_state.campaign_id), new_payment_mint.key().as_ref()],
+        bump,
+        token::mint = new_payment_mint,
+        token::authority = admin_state,
+        token::token_program = new_payment_token_program,
+    )]
+    pub new_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub new_payment_token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cancel a queued change before it executes (super_admin only)
+#[derive(Accounts)]
+#[instruction(change_id: u64)]
+pub struct CancelConfigChange<'info> {
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [b"pending_change".as_ref(), &campaign_seed(admin_state.campaign_id), change_id.to_le_bytes().as_ref()],
+        bump = pending_change.bump,
+    )]
+    pub pending_change: Box<Account<'info, PendingChange>>,
+
+    /// CHECK: Rent refund destination - must be the key that queued the change
+    #[account(mut, address = pending_change.queued_by)]
+    pub queued_by: UncheckedAccount<'info>,
+}
+
+fn require_elapsed(pending_change: &PendingChange) -> Result<i64> {
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= pending_change.executable_at,
+        ProgramErrorCode::TimelockNotElapsed
+    );
+    Ok(clock.unix_timestamp)
+}
+
+/// Shared by update_config_delay, queued changes and multisig proposals
+pub(crate) fn validate_config_delay(config_delay: i64) -> Result<()> {
+    require!(
+        (0..=MAX_CONFIG_DELAY).contains(&config_delay),
+        ProgramErrorCode::InvalidConfigDelay
+    );
+    Ok(())
+}
+
+pub fn update_config_delay_handler(ctx: Context<UpdateAdminInfo>, config_delay: i64) -> Result<()> {
+    require!(!ctx.accounts.admin_state.multisig_enabled, ProgramErrorCode::MultisigRequired);
+    validate_config_delay(config_delay)?;
+
+    // Lengthening the delay only adds notice; shortening it must be queued
+    require!(
+        config_delay >= ctx.accounts.admin_state.config_delay,
+        ProgramErrorCode::InvalidConfigDelay
+    );
+
+    ctx.accounts.admin_state.config_delay = config_delay;
+    msg!("Config delay updated to: {}s", config_delay);
+    Ok(())
+}
+
+pub fn queue_config_change_handler(ctx: Context<QueueConfigChange>, change: ConfigChange) -> Result<()> {
+    let admin_state = &mut ctx.accounts.admin_state;
+    let authority = ctx.accounts.authority.key();
+
+    // Fee managers may queue fee and supply changes; everything else is super_admin only
+    let role_authorized = match change {
+        ConfigChange::UpdateMintFee { .. } | ConfigChange::UpdateMaxSupply { .. } => RoleAssignment::authorizes(
+            admin_state,
+            ctx.accounts.role_assignment.as_deref(),
+            authority,
+            Role::FeeManager,
+        ),
+        _ => authority == admin_state.super_admin,
+    };
+    require!(role_authorized, ProgramErrorCode::Unauthorized);
+
+    if let ConfigChange::UpdateConfigDelay { config_delay } = change {
+        validate_config_delay(config_delay)?;
+    }
+    if let ConfigChange::HandOffUpdateAuthority { new_update_authority } = change {
+        validate_update_authority_handoff(admin_state, admin_state.key(), new_update_authority)?;
+    }
+
+    let clock = Clock::get()?;
+    let change_id = admin_state.config_change_count;
+    let executable_at = clock.unix_timestamp.saturating_add(admin_state.config_delay);
+    admin_state.config_change_count = change_id
+        .checked_add(1)
+        .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+
+    ctx.accounts.pending_change.set_inner(PendingChange {
+        id: change_id,
+        change: change.clone(),
+        queued_by: authority,
+        queued_at: clock.unix_timestamp,
+        executable_at,
+        bump: ctx.bumps.pending_change,
+    });
+
+    msg!("Config change {} queued: {:?} - executable at {}", change_id, change, executable_at);
+
+    emit!(ConfigChangeQueuedEvent {
+        campaign_id: admin_state.campaign_id,
+        change_id,
+        change,
+        queued_by: authority,
+        executable_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn execute_config_change_handler(ctx: Context<ExecuteConfigChange>, change_id: u64) -> Result<()> {
+    let now = require_elapsed(&ctx.accounts.pending_change)?;
+    let change = ctx.accounts.pending_change.change.clone();
+    let admin_state = &mut ctx.accounts.admin_state;
+    let collection_state = ctx.accounts.collection_state.as_deref_mut();
+
+    match change {
+        ConfigChange::UpdateMintFee { tier_id, mint_fee } => {
+            apply_mint_fee(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id, mint_fee)?;
+        }
+        ConfigChange::UpdateMaxSupply { tier_id, max_supply } => {
+            apply_max_supply(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id, max_supply)?;
+        }
+        ConfigChange::UpdateCollectionMint { tier_id, collection_mint } => {
+            apply_collection_mint(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                collection_mint,
+            )?;
+        }
+        ConfigChange::UpdateMintStartDate { mint_start_date } => {
+            admin_state.mint_start_date = mint_start_date;
+            msg!("Mint start date updated to: {}", mint_start_date);
+        }
+        ConfigChange::UpdateConfigDelay { config_delay } => {
+            // Changes queued before the cap existed are checked again here
+            validate_config_delay(config_delay)?;
+            admin_state.config_delay = config_delay;
+            msg!("Config delay updated to: {}s", config_delay);
+        }
+        ConfigChange::HandOffUpdateAuthority { new_update_authority } => {
+            apply_update_authority_handoff(admin_state, new_update_authority, now)?;
+        }
+        ConfigChange::UpdateAdminMintLimit { tier_id, admin_mint_limit } => {
+            apply_admin_mint_limit(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                admin_mint_limit,
+            )?;
+        }
+        ConfigChange::ReleaseAdminAllocation { tier_id } => {
+            apply_release_admin_allocation(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id)?;
+        }
+        ConfigChange::UpdateAllowlistRoot { tier_id, allowlist_root } => {
+            apply_allowlist_root(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id, allowlist_root)?;
+        }
+        ConfigChange::UpdateSaleWindow { tier_id, sale_start, sale_end } => {
+            apply_sale_window(collection_for_tier(collection_state, admin_state, tier_id)?, tier_id, sale_start, sale_end)?;
+        }
+        ConfigChange::AddSalePhase { tier_id, start_time, end_time, allowlist_root, price, supply_cap, per_wallet_limit } => {
+            apply_add_sale_phase(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                start_time,
+                end_time,
+                allowlist_root,
+                price,
+                supply_cap,
+                per_wallet_limit,
+            )?;
+        }
+        ConfigChange::UpdateSalePhase {
+            tier_id,
+            phase_index,
+            start_time,
+            end_time,
+            allowlist_root,
+            price,
+            supply_cap,
+            per_wallet_limit,
+        } => {
+            apply_update_sale_phase(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                phase_index,
+                start_time,
+                end_time,
+                allowlist_root,
+                price,
+                supply_cap,
+                per_wallet_limit,
+            )?;
+        }
+        ConfigChange::UpdateWalletLimits { tier_id, max_per_wallet, quantity_prices } => {
+            apply_collection_wallet_limits(
+                collection_for_tier(collection_state, admin_state, tier_id)?,
+                tier_id,
+                max_per_wallet,
+                quantity_prices,
+            )?;
+        }
+        ConfigChange::UpdatePaymentMint { .. } => {
+            return err!(ProgramErrorCode::InvalidConfigChange);
+        }
+    }
+
+    msg!("Config change {} executed", change_id);
+
+    emit!(ConfigChangeExecutedEvent {
+        campaign_id: admin_state.campaign_id,
+        change_id,
+        change: ctx.accounts.pending_change.change.clone(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn execute_payment_mint_change_handler(ctx: Context<ExecutePaymentMintChange>, change_id: u64) -> Result<()> {
+    let now = require_elapsed(&ctx.accounts.pending_change)?;
+    let new_mint = match ctx.accounts.pending_change.change {
+        ConfigChange::UpdatePaymentMint { new_payment_mint } => new_payment_mint,
+        _ => return err!(ProgramErrorCode::InvalidConfigChange),
+    };
+    require!(
+        ctx.accounts.new_payment_mint.key() == new_mint,
+        ProgramErrorCode::InvalidPaymentMint
+    );
+
+    let old_mint = ctx.accounts.old_payment_mint.key();
+    require!(old_mint != new_mint, ProgramErrorCode::SamePaymentMint);
+
+    ctx.accounts.admin_state.payment_mint = new_mint;
+
+    msg!("Config change {} executed - payment mint updated:", change_id);
+    msg!("  From: {}", old_mint);
+    msg!("  To: {}", new_mint);
+    msg!("  New vault: {}", ctx.accounts.new_vault.key());
+
+    emit!(ConfigChangeExecutedEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
+        change_id,
+        change: ctx.accounts.pending_change.change.clone(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_config_change_handler(ctx: Context<CancelConfigChange>, change_id: u64) -> Result<()> {
+    msg!("Config change {} cancelled", change_id);
+
+    let clock = Clock::get()?;
+    emit!(ConfigChangeCancelledEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
+        change_id,
+        change: ctx.accounts.pending_change.change.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
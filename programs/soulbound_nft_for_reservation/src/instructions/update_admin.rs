@@ -10,77 +10,385 @@ pub struct UpdateAdminInfo<'info> {
     pub super_admin: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"admin_state".as_ref()],
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
         bump,
         constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized
      )]
     pub admin_state: Box<Account<'info, AdminState>>,
 }
 
-pub fn update_mint_fee_handler(ctx: Context<UpdateAdminInfo>, collection_type: crate::state::CollectionType, mint_fee: u64) -> Result<()> {
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct UpdateCollectionInfo<'info> {
+    /// Only super_admin can update collection settings
+    pub super_admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u16)]
+pub struct UpdateCollectionPricing<'info> {
+    /// Super admin or fee manager
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = RoleAssignment::authorizes(&admin_state, role_assignment.as_deref(), authority.key(), Role::FeeManager)
+            @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired,
+        constraint = admin_state.config_delay == 0 @ ProgramErrorCode::TimelockActive
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+    #[account(
+        mut,
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+    /// Fee manager role assignment - omitted when the super admin signs
+    #[account(
+        seeds = [b"role_assignment".as_ref(), &campaign_seed(admin_state.campaign_id), &Role::FeeManager.seed(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+/// Accept a pending super admin handoff (pending super admin only)
+#[derive(Accounts)]
+pub struct AcceptSuperAdmin<'info> {
+    /// Must match admin_state.pending_super_admin
+    pub new_super_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.pending_super_admin != Pubkey::default() @ ProgramErrorCode::NoPendingHandoff,
+        constraint = admin_state.pending_super_admin == new_super_admin.key() @ ProgramErrorCode::Unauthorized
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+}
+
+pub fn update_mint_fee_handler(ctx: Context<UpdateCollectionPricing>, tier_id: u16, mint_fee: u64) -> Result<()> {
+    apply_mint_fee(&mut ctx.accounts.collection_state, tier_id, mint_fee)
+}
+
+pub fn update_max_supply_handler(ctx: Context<UpdateCollectionPricing>, tier_id: u16, max_supply: u64) -> Result<()> {
+    apply_max_supply(&mut ctx.accounts.collection_state, tier_id, max_supply)
+}
+
+/// Shared by update_mint_fee and multisig proposals
+pub(crate) fn apply_mint_fee(collection_state: &mut CollectionState, tier_id: u16, mint_fee: u64) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
     require!(mint_fee > 0, ProgramErrorCode::InvalidMintFee);
     
-    let collection_config = ctx.accounts.admin_state.get_collection_config_mut(collection_type);
-    collection_config.mint_fee = mint_fee;
+    collection_state.mint_fee = mint_fee;
     
-    msg!("Collection {:?} mint fee updated to: {}", collection_type, mint_fee);
+    msg!("Collection tier {} mint fee updated to: {}", tier_id, mint_fee);
     Ok(())
 }
 
-pub fn update_max_supply_handler(ctx: Context<UpdateAdminInfo>, collection_type: crate::state::CollectionType, max_supply: u64) -> Result<()> {
-    let collection_config = ctx.accounts.admin_state.get_collection_config(collection_type);
+/// Shared by update_max_supply and multisig proposals
+pub(crate) fn apply_max_supply(collection_state: &mut CollectionState, tier_id: u16, max_supply: u64) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
+    require!(
+        collection_state.provenance_hash.is_none(),
+        ProgramErrorCode::SupplyLockedByProvenance
+    );
     
     // Validate max_supply is not below current reserved count (0 means unlimited)
     require!(
-        max_supply == 0 || max_supply >= collection_config.current_reserved_count,
+        max_supply == 0 || max_supply >= collection_state.current_reserved_count,
+        ProgramErrorCode::InvalidMaxSupply
+    );
+
+    // The admin allocation is kept; the public bucket absorbs the change
+    let public_supply = CollectionState::public_supply_for(max_supply, collection_state.admin_mint_limit)
+        .ok_or(ProgramErrorCode::InvalidMaxSupply)?;
+    require!(
+        max_supply == 0 || public_supply >= collection_state.public_minted_count,
         ProgramErrorCode::InvalidMaxSupply
     );
     
-    let collection_config_mut = ctx.accounts.admin_state.get_collection_config_mut(collection_type);
-    collection_config_mut.max_supply = max_supply;
+    collection_state.max_supply = max_supply;
+    collection_state.public_supply = public_supply;
     
-    msg!("Collection {:?} max supply updated to: {}", collection_type, max_supply);
+    msg!("Collection tier {} max supply updated to: {}", tier_id, max_supply);
     Ok(())
 }
 
 pub fn update_mint_start_date_handler(ctx: Context<UpdateAdminInfo>, mint_start_date: i64) -> Result<()> {
+    require!(!ctx.accounts.admin_state.multisig_enabled, ProgramErrorCode::MultisigRequired);
+    require!(ctx.accounts.admin_state.config_delay == 0, ProgramErrorCode::TimelockActive);
     ctx.accounts.admin_state.mint_start_date = mint_start_date;
     msg!("Mint start date updated to: {}", mint_start_date);
     Ok(())
 }
 
-pub fn update_collection_mint_handler(ctx: Context<UpdateAdminInfo>, collection_type: crate::state::CollectionType, collection_mint: Pubkey) -> Result<()> {
+pub fn update_collection_mint_handler(ctx: Context<UpdateCollectionInfo>, tier_id: u16, collection_mint: Pubkey) -> Result<()> {
+    require!(ctx.accounts.admin_state.config_delay == 0, ProgramErrorCode::TimelockActive);
+    apply_collection_mint(&mut ctx.accounts.collection_state, tier_id, collection_mint)
+}
+
+/// Shared by update_collection_mint and multisig proposals
+pub(crate) fn apply_collection_mint(collection_state: &mut CollectionState, tier_id: u16, collection_mint: Pubkey) -> Result<()> {
     // Validate that collection_mint is not empty
     require!(
         collection_mint != Pubkey::default(),
         ProgramErrorCode::InvalidCollection
     );
+    require!(
+        collection_mint != collection_state.collection_mint,
+        ProgramErrorCode::InvalidCollectionMint
+    );
+    require!(
+        collection_state.migrating_from.is_none(),
+        ProgramErrorCode::CollectionMigrationPending
+    );
 
-    let collection_config = ctx.accounts.admin_state.get_collection_config_mut(collection_type);
-    collection_config.collection_mint = collection_mint;
+    // Live NFTs stay verified under the old collection until the migration crank moves them;
+    // burned ones no longer need moving
+    if collection_state.current_reserved_count > 0 {
+        collection_state.migrating_from = Some(collection_state.collection_mint);
+        collection_state.migration_pending = collection_state.current_reserved_count;
+        msg!("Collection tier {} migration pending from: {}", tier_id, collection_state.collection_mint);
+    }
+
+    collection_state.collection_mint = collection_mint;
     
-    msg!("Collection {:?} mint updated to: {}", collection_type, collection_mint);
+    msg!("Collection tier {} mint updated to: {}", tier_id, collection_mint);
     Ok(())
 }
 
-pub fn update_admin_mint_limit_handler(ctx: Context<UpdateAdminInfo>, collection_type: crate::state::CollectionType, admin_mint_limit: u64) -> Result<()> {
-    let collection_config = ctx.accounts.admin_state.get_collection_config(collection_type);
+pub fn update_allowlist_root_handler(ctx: Context<UpdateCollectionInfo>, tier_id: u16, allowlist_root: Option<[u8; 32]>) -> Result<()> {
+    // Allowlist leaves carry their own prices, so a new root is a price change
+    require!(ctx.accounts.admin_state.config_delay == 0, ProgramErrorCode::TimelockActive);
+    apply_allowlist_root(&mut ctx.accounts.collection_state, tier_id, allowlist_root)
+}
+
+/// Shared by update_allowlist_root and multisig proposals
+pub(crate) fn apply_allowlist_root(collection_state: &mut CollectionState, tier_id: u16, allowlist_root: Option<[u8; 32]>) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
+    collection_state.allowlist_root = allowlist_root;
+
+    if allowlist_root.is_some() {
+        msg!("Collection tier {} allowlist root rotated", tier_id);
+    } else {
+        msg!("Collection tier {} allowlist cleared - minting is public", tier_id);
+    }
+    Ok(())
+}
+
+pub fn update_collection_metadata_handler(
+    ctx: Context<UpdateCollectionInfo>,
+    tier_id: u16,
+    name_prefix: String,
+    symbol: String,
+    base_uri: String,
+) -> Result<()> {
+    apply_collection_metadata(&mut ctx.accounts.collection_state, tier_id, name_prefix, symbol, base_uri)
+}
+
+/// Shared by update_collection_metadata and multisig proposals
+pub(crate) fn apply_collection_metadata(
+    collection_state: &mut CollectionState,
+    tier_id: u16,
+    name_prefix: String,
+    symbol: String,
+    base_uri: String,
+) -> Result<()> {
+    // Only affects future mints - metadata already written is changed through update_nft_metadata
+    CollectionState::validate_metadata_config(&name_prefix, &symbol, &base_uri)?;
+
+    collection_state.name_prefix = name_prefix;
+    collection_state.symbol = symbol;
+    collection_state.base_uri = base_uri;
+
+    msg!(
+        "Collection tier {} metadata: {} ({}) at {}",
+        tier_id,
+        collection_state.name_prefix,
+        collection_state.symbol,
+        collection_state.base_uri
+    );
+    Ok(())
+}
+
+pub fn update_collection_royalties_handler(
+    ctx: Context<UpdateCollectionInfo>,
+    tier_id: u16,
+    creators: Vec<CollectionCreator>,
+    royalty_bps: u16,
+) -> Result<()> {
+    let admin_state = ctx.accounts.admin_state.key();
+    apply_collection_royalties(&mut ctx.accounts.collection_state, admin_state, tier_id, creators, royalty_bps)
+}
+
+/// Shared by update_collection_royalties and multisig proposals
+pub(crate) fn apply_collection_royalties(
+    collection_state: &mut CollectionState,
+    admin_state: Pubkey,
+    tier_id: u16,
+    creators: Vec<CollectionCreator>,
+    royalty_bps: u16,
+) -> Result<()> {
+    // Applies to future mints and to NFTs rewritten through update_nft_metadata
+    CollectionState::validate_royalties(&creators, royalty_bps, admin_state)?;
+
+    // Minted NFTs already list every creator, and partners may have verified themselves on them
+    if collection_state.last_serial > 0 || collection_state.current_reserved_count > 0 {
+        require!(
+            collection_state
+                .creators
+                .iter()
+                .all(|existing| creators.iter().any(|creator| creator.address == existing.address)),
+            ProgramErrorCode::CreatorRemovalAfterMint
+        );
+    }
+
+    collection_state.creators = creators;
+    collection_state.royalty_bps = royalty_bps;
+
+    msg!(
+        "Collection tier {} royalties: {} bps, {} partner creators, admin_state share {}",
+        tier_id,
+        royalty_bps,
+        collection_state.creators.len(),
+        collection_state.admin_creator_share()
+    );
+    Ok(())
+}
+
+pub fn update_serial_in_name_handler(ctx: Context<UpdateCollectionInfo>, tier_id: u16, serial_in_name: bool) -> Result<()> {
+    apply_serial_in_name(&mut ctx.accounts.collection_state, tier_id, serial_in_name)
+}
+
+/// Shared by update_serial_in_name and multisig proposals
+pub(crate) fn apply_serial_in_name(collection_state: &mut CollectionState, tier_id: u16, serial_in_name: bool) -> Result<()> {
+    // Only affects future mints - names already written keep their form
+    collection_state.serial_in_name = serial_in_name;
+
+    msg!("Collection tier {} serial in name: {}", tier_id, serial_in_name);
+    Ok(())
+}
+
+pub fn update_sale_window_handler(ctx: Context<UpdateCollectionInfo>, tier_id: u16, sale_start: i64, sale_end: i64) -> Result<()> {
+    // The sale start gates minting like mint_start_date does
+    require!(ctx.accounts.admin_state.config_delay == 0, ProgramErrorCode::TimelockActive);
+    apply_sale_window(&mut ctx.accounts.collection_state, tier_id, sale_start, sale_end)
+}
+
+/// Shared by update_sale_window and multisig proposals
+pub(crate) fn apply_sale_window(collection_state: &mut CollectionState, tier_id: u16, sale_start: i64, sale_end: i64) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
+
+    // Validate end is after start when both are set (0 means unset)
+    require!(
+        sale_end == 0 || sale_end > sale_start,
+        ProgramErrorCode::InvalidSaleWindow
+    );
+
+    collection_state.sale_start = sale_start;
+    collection_state.sale_end = sale_end;
+
+    msg!("Collection tier {} sale window updated: {} - {}", tier_id, sale_start, sale_end);
+    Ok(())
+}
+
+pub fn update_admin_mint_limit_handler(ctx: Context<UpdateCollectionInfo>, tier_id: u16, admin_mint_limit: u64) -> Result<()> {
+    require!(ctx.accounts.admin_state.config_delay == 0, ProgramErrorCode::TimelockActive);
+    apply_admin_mint_limit(&mut ctx.accounts.collection_state, tier_id, admin_mint_limit)
+}
+
+/// Shared by update_admin_mint_limit, queued config changes and multisig proposals
+pub(crate) fn apply_admin_mint_limit(collection_state: &mut CollectionState, tier_id: u16, admin_mint_limit: u64) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
     
     // Validate new limit is >= current admin mint count
     // This prevents setting a limit below what's already been minted
     require!(
-        admin_mint_limit >= collection_config.current_admin_mint_count,
+        admin_mint_limit >= collection_state.current_admin_mint_count,
+        ProgramErrorCode::InvalidAdminMintLimit
+    );
+
+    // Total supply is kept; stock moves between the admin and public buckets
+    let public_supply = CollectionState::public_supply_for(collection_state.max_supply, admin_mint_limit)
+        .ok_or(ProgramErrorCode::InvalidAdminMintLimit)?;
+    require!(
+        collection_state.max_supply == 0 || public_supply >= collection_state.public_minted_count,
         ProgramErrorCode::InvalidAdminMintLimit
     );
     
-    let collection_config_mut = ctx.accounts.admin_state.get_collection_config_mut(collection_type);
-    collection_config_mut.admin_mint_limit = admin_mint_limit;
+    collection_state.admin_mint_limit = admin_mint_limit;
+    collection_state.public_supply = public_supply;
     
-    msg!("Collection {:?} admin mint limit updated to: {}", collection_type, admin_mint_limit);
+    msg!("Collection tier {} admin mint limit updated to: {}", tier_id, admin_mint_limit);
     Ok(())
 }
 
-pub fn update_super_admin_handler(ctx: Context<UpdateAdminInfo>, new_super_admin: Pubkey) -> Result<()> {
+pub fn update_admin_release_date_handler(ctx: Context<UpdateCollectionInfo>, tier_id: u16, admin_release_date: i64) -> Result<()> {
+    apply_admin_release_date(&mut ctx.accounts.collection_state, tier_id, admin_release_date)
+}
+
+/// Shared by update_admin_release_date and multisig proposals
+pub(crate) fn apply_admin_release_date(collection_state: &mut CollectionState, tier_id: u16, admin_release_date: i64) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
+
+    collection_state.admin_release_date = admin_release_date;
+
+    msg!("Collection tier {} admin allocation release date updated to: {}", tier_id, admin_release_date);
+    Ok(())
+}
+
+pub fn release_admin_allocation_handler(ctx: Context<UpdateCollectionInfo>, tier_id: u16) -> Result<()> {
+    require!(ctx.accounts.admin_state.config_delay == 0, ProgramErrorCode::TimelockActive);
+    apply_release_admin_allocation(&mut ctx.accounts.collection_state, tier_id)
+}
+
+/// Shared by release_admin_allocation, queued config changes and multisig proposals
+pub(crate) fn apply_release_admin_allocation(collection_state: &mut CollectionState, tier_id: u16) -> Result<()> {
+    require!(!collection_state.sale_closed, ProgramErrorCode::SaleClosed);
+
+    // Validate release date is set and has passed
+    let clock = Clock::get()?;
+    require!(
+        collection_state.admin_release_date > 0 && clock.unix_timestamp >= collection_state.admin_release_date,
+        ProgramErrorCode::AdminAllocationLocked
+    );
+
+    // Move unused admin allocation into the public bucket
+    let unused = collection_state
+        .admin_mint_limit
+        .saturating_sub(collection_state.current_admin_mint_count);
+    collection_state.admin_mint_limit = collection_state.current_admin_mint_count;
+    collection_state.public_supply = collection_state
+        .public_supply
+        .checked_add(unused)
+        .ok_or(ProgramErrorCode::ReservedCountOverflow)?;
+
+    msg!(
+        "Collection tier {} released {} admin allocation to public - public supply: {}",
+        tier_id,
+        unused,
+        collection_state.public_supply
+    );
+    Ok(())
+}
+
+pub fn propose_super_admin_handler(ctx: Context<UpdateAdminInfo>, new_super_admin: Pubkey) -> Result<()> {
+    require!(!ctx.accounts.admin_state.multisig_enabled, ProgramErrorCode::MultisigRequired);
+
     // Validate that new_super_admin is not empty
     require!(
         new_super_admin != Pubkey::default(),
@@ -93,12 +401,49 @@ pub fn update_super_admin_handler(ctx: Context<UpdateAdminInfo>, new_super_admin
         ProgramErrorCode::SameSuperAdmin
     );
 
-    let old_admin = ctx.accounts.admin_state.super_admin;
-    ctx.accounts.admin_state.super_admin = new_super_admin;
+    // The new key takes over only after it signs accept_super_admin
+    let clock = Clock::get()?;
+    let expires_at = clock.unix_timestamp + HANDOFF_EXPIRY_SECONDS;
+    ctx.accounts.admin_state.pending_super_admin = new_super_admin;
+    ctx.accounts.admin_state.pending_super_admin_expires_at = expires_at;
+
+    msg!("Super admin handoff proposed to {} (expires at {})", new_super_admin, expires_at);
+
+    Ok(())
+}
+
+pub fn accept_super_admin_handler(ctx: Context<AcceptSuperAdmin>) -> Result<()> {
+    let admin_state = &mut ctx.accounts.admin_state;
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= admin_state.pending_super_admin_expires_at,
+        ProgramErrorCode::HandoffExpired
+    );
+
+    let old_admin = admin_state.super_admin;
+    admin_state.super_admin = admin_state.pending_super_admin;
+    admin_state.pending_super_admin = Pubkey::default();
+    admin_state.pending_super_admin_expires_at = 0;
 
     msg!("Super admin updated:");
     msg!("  From: {}", old_admin);
-    msg!("  To: {}", new_super_admin);
+    msg!("  To: {}", admin_state.super_admin);
+
+    Ok(())
+}
+
+pub fn cancel_super_admin_handoff_handler(ctx: Context<UpdateAdminInfo>) -> Result<()> {
+    let admin_state = &mut ctx.accounts.admin_state;
+    require!(
+        admin_state.pending_super_admin != Pubkey::default(),
+        ProgramErrorCode::NoPendingHandoff
+    );
+
+    msg!("Super admin handoff to {} cancelled", admin_state.pending_super_admin);
+
+    admin_state.pending_super_admin = Pubkey::default();
+    admin_state.pending_super_admin_expires_at = 0;
 
     Ok(())
 }
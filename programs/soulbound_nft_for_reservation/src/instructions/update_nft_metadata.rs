@@ -2,18 +2,36 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 use mpl_token_metadata::{
     instructions::{UpdateV1, UpdateV1InstructionArgs},
-    types::{Data, CollectionDetailsToggle, CollectionToggle, Creator, RuleSetToggle, UsesToggle},
+    accounts::Metadata,
+    types::{Data, CollectionDetailsToggle, CollectionToggle, RuleSetToggle, UsesToggle},
 };
 use solana_program::program::invoke_signed;
 
 use crate::error::ProgramErrorCode;
+use crate::instructions::admin_mint_nft::collection_creators;
 use crate::state::*;
 
+// Event definitions
+#[event]
+pub struct MetadataUpdatedEvent {
+    pub campaign_id: u64,
+    pub tier_id: u16,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub old_name: String,
+    pub new_name: String,
+    pub old_symbol: String,
+    pub new_symbol: String,
+    pub old_uri: String,
+    pub new_uri: String,
+    pub timestamp: i64,
+}
+
 #[derive(Accounts)]
 pub struct UpdateNftMetadata<'info> {
-    /// Only the super admin can update NFT metadata
+    /// Super admin or metadata operator
     #[account(mut)]
-    pub super_admin: Signer<'info>,
+    pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
     
@@ -24,68 +42,109 @@ pub struct UpdateNftMetadata<'info> {
     #[account(address = mpl_token_metadata::ID)]
     pub token_metadata_program: UncheckedAccount<'info>,
     
-    /// CHECK: Metadata account - validated by Metaplex program during CPI
-    #[account(mut)]
+    /// CHECK: Metadata account - validated as the mint's metadata PDA
+    #[account(
+        mut,
+        address = Metadata::find_pda(&mint.key()).0 @ ProgramErrorCode::InvalidMetadataAccount,
+    )]
     pub metadata_account: UncheckedAccount<'info>,
     
     #[account(
         mut,
-        seeds = [b"admin_state".as_ref()],
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
         bump,
-        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized
+        constraint = RoleAssignment::authorizes(&admin_state, role_assignment.as_deref(), authority.key(), Role::MetadataOperator)
+            @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.pause_flags.metadata @ ProgramErrorCode::ProgramPaused,
+        constraint = admin_state.update_authority_handoff == Pubkey::default() @ ProgramErrorCode::UpdateAuthorityHandedOff,
     )]
     pub admin_state: Box<Account<'info, AdminState>>,
 
+    /// Collection the NFT must be verified into
+    #[account(
+        seeds = [b"collection_state".as_ref(), &campaign_seed(admin_state.campaign_id), collection_state.tier_id.to_le_bytes().as_ref()],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Box<Account<'info, CollectionState>>,
+
     /// CHECK: Sysvar instructions account
     #[account(address = solana_program::sysvar::instructions::ID)]
     pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// Metadata operator role assignment - omitted when the super admin signs
+    #[account(
+        seeds = [b"role_assignment".as_ref(), &campaign_seed(admin_state.campaign_id), &Role::MetadataOperator.seed(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
-pub fn handler(
-    ctx: Context<UpdateNftMetadata>,
-    name: Option<String>,
-    symbol: Option<String>,
-    uri: Option<String>,
-) -> Result<()> {
-    msg!("Update NFT metadata");
-    msg!("NFT Mint: {}", ctx.accounts.mint.key());
+/// Token metadata strings are stored padded with '\0'
+pub(crate) fn trim_padding(value: &str) -> String {
+    value.trim_end_matches('\0').to_string()
+}
 
-    let bump = ctx.bumps.admin_state;
-    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &[bump]]];
+/// Deserialize a token metadata account owned by the Metaplex program
+pub(crate) fn read_metadata(metadata_account: &AccountInfo) -> Result<Metadata> {
+    require!(
+        metadata_account.owner == &mpl_token_metadata::ID,
+        ProgramErrorCode::InvalidMetadataAccount
+    );
+    let data = metadata_account.try_borrow_data()?;
+    Metadata::from_bytes(&data).map_err(|_| error!(ProgramErrorCode::InvalidMetadataAccount))
+}
 
-    // Create the new data - all fields required
-    let data = Data {
-        name: name.clone().ok_or(ProgramErrorCode::InvalidMetadata)?,
-        symbol: symbol.clone().ok_or(ProgramErrorCode::InvalidMetadata)?,
-        uri: uri.clone().ok_or(ProgramErrorCode::InvalidMetadata)?,
-        seller_fee_basis_points: 0,
-        creators: Some(vec![Creator {
-            address: ctx.accounts.admin_state.key(),
-            verified: true,
-            share: 100,
-        }]),
-    };
+/// Check the fields of a partial metadata update against Metaplex limits - at least one is required
+pub(crate) fn validate_metadata_update(name: &Option<String>, symbol: &Option<String>, uri: &Option<String>) -> Result<()> {
+    require!(
+        name.is_some() || symbol.is_some() || uri.is_some(),
+        ProgramErrorCode::InvalidMetadata
+    );
+    if let Some(name) = name {
+        require!(!name.is_empty() && name.len() <= MAX_NFT_NAME_LENGTH, ProgramErrorCode::InvalidMetadata);
+    }
+    if let Some(symbol) = symbol {
+        require!(symbol.len() <= MAX_NFT_SYMBOL_LENGTH, ProgramErrorCode::InvalidMetadata);
+    }
+    if let Some(uri) = uri {
+        require!(!uri.is_empty() && uri.len() <= MAX_NFT_URI_LENGTH, ProgramErrorCode::InvalidMetadata);
+    }
+    Ok(())
+}
 
-    // Build the update instruction using UpdateV1
+/// Update an NFT's data, mutability and/or update authority through Metaplex UpdateV1 with admin_state as update authority
+#[inline(never)]
+pub(crate) fn update_metadata_data<'info>(
+    metadata_account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    admin_state: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    sysvar_instructions: &AccountInfo<'info>,
+    data: Option<Data>,
+    is_mutable: Option<bool>,
+    new_update_authority: Option<Pubkey>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
     let update_ix = UpdateV1 {
-        authority: ctx.accounts.admin_state.key(),
+        authority: admin_state.key(),
         delegate_record: None,
         token: None,
-        mint: ctx.accounts.mint.key(),
-        metadata: ctx.accounts.metadata_account.key(),
+        mint: mint.key(),
+        metadata: metadata_account.key(),
         edition: None,
-        payer: ctx.accounts.super_admin.key(),
-        system_program: ctx.accounts.system_program.key(),
-        sysvar_instructions: ctx.accounts.sysvar_instructions.key(),
+        payer: payer.key(),
+        system_program: system_program.key(),
+        sysvar_instructions: sysvar_instructions.key(),
         authorization_rules_program: None,
         authorization_rules: None,
     };
 
     let args = UpdateV1InstructionArgs {
-        data: Some(data.clone()),
+        data,
         primary_sale_happened: None,
-        is_mutable: None,
-        new_update_authority: None,
+        is_mutable,
+        new_update_authority,
         authorization_data: None,
         collection_details: CollectionDetailsToggle::None,
         uses: UsesToggle::None,
@@ -98,21 +157,95 @@ pub fn handler(
     invoke_signed(
         &ix,
         &[
-            ctx.accounts.admin_state.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.metadata_account.to_account_info(),
-            ctx.accounts.super_admin.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.sysvar_instructions.to_account_info(),
+            admin_state.clone(),
+            mint.clone(),
+            metadata_account.clone(),
+            payer.clone(),
+            system_program.clone(),
+            sysvar_instructions.clone(),
         ],
         signer_seeds,
     )?;
 
+    Ok(())
+}
+
+pub fn handler(
+    ctx: Context<UpdateNftMetadata>,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    msg!("Update NFT metadata");
+    msg!("NFT Mint: {}", ctx.accounts.mint.key());
+
+    validate_metadata_update(&name, &symbol, &uri)?;
+
+    let current = read_metadata(&ctx.accounts.metadata_account)?;
+
+    // Only NFTs verified into one of our collections can be edited
+    require!(
+        matches!(
+            &current.collection,
+            Some(collection) if collection.verified && collection.key == ctx.accounts.collection_state.collection_mint
+        ),
+        ProgramErrorCode::NftNotInCollection
+    );
+
+    let bump = ctx.bumps.admin_state;
+    let campaign_seed = ctx.accounts.admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[bump]]];
+
+    // Fields left out keep their current value; creators and royalty follow the collection config,
+    // with admin_state re-verified and partners keeping the verification they already have
+    let creators = collection_creators(
+        &ctx.accounts.collection_state,
+        ctx.accounts.admin_state.key(),
+        true,
+        current.creators.as_deref(),
+    );
+    let old_name = trim_padding(&current.name);
+    let old_symbol = trim_padding(&current.symbol);
+    let old_uri = trim_padding(&current.uri);
+    let data = Data {
+        name: name.unwrap_or_else(|| old_name.clone()),
+        symbol: symbol.unwrap_or_else(|| old_symbol.clone()),
+        uri: uri.unwrap_or_else(|| old_uri.clone()),
+        seller_fee_basis_points: ctx.accounts.collection_state.royalty_bps,
+        creators: Some(creators),
+    };
+
+    update_metadata_data(
+        &ctx.accounts.metadata_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.admin_state.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.sysvar_instructions.to_account_info(),
+        Some(data.clone()),
+        None,
+        None,
+        signer_seeds,
+    )?;
+
     msg!("NFT metadata updated successfully");
     msg!("  Name: {:?}", data.name);
     msg!("  Symbol: {:?}", data.symbol);
     msg!("  URI: {:?}", data.uri);
 
+    emit!(MetadataUpdatedEvent {
+        campaign_id: ctx.accounts.admin_state.campaign_id,
+        tier_id: ctx.accounts.collection_state.tier_id,
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        old_name,
+        new_name: data.name,
+        old_symbol,
+        new_symbol: data.symbol,
+        old_uri,
+        new_uri: data.uri,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
-
@@ -14,9 +14,11 @@ pub struct UpdatePaymentMint<'info> {
 
     #[account(
         mut,
-        seeds = [b"admin_state".as_ref()],
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
         bump,
-        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized
+        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired,
+        constraint = admin_state.config_delay == 0 @ ProgramErrorCode::TimelockActive
     )]
     pub admin_state: Box<Account<'info, AdminState>>,
 
@@ -29,7 +31,7 @@ pub struct UpdatePaymentMint<'info> {
 
     /// The old vault - must be empty before changing payment mint
     #[account(
-        seeds = [b"vault", old_payment_mint.key().as_ref()],
+        seeds = [b"vault".as_ref(), &campaign_seed(admin_state.campaign_id), old_payment_mint.key().as_ref()],
         bump,
         token::mint = old_payment_mint,
         token::authority = admin_state,
@@ -52,7 +54,7 @@ pub struct UpdatePaymentMint<'info> {
     #[account(
         init_if_needed,
         payer = super_admin,
-        seeds = [b"vault", new_payment_mint.key().as_ref()],
+        seeds = [b"vault".as_ref(), &campaign_seed(admin_state.campaign_id), new_payment_mint.key().as_ref()],
         bump,
         token::mint = new_payment_mint,
         token::authority = admin_state,
@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::ProgramErrorCode;
 
-/// Update withdraw wallet (super_admin only)
+/// Propose or cancel a withdraw wallet change (super_admin only)
 #[derive(Accounts)]
 pub struct UpdateWithdrawWallet<'info> {
     /// Only super_admin can update withdraw wallet
@@ -12,14 +12,32 @@ pub struct UpdateWithdrawWallet<'info> {
 
     #[account(
         mut,
-        seeds = [b"admin_state".as_ref()],
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
         bump,
         constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized
     )]
     pub admin_state: Box<Account<'info, AdminState>>,
 }
 
-pub fn handler(ctx: Context<UpdateWithdrawWallet>, new_withdraw_wallet: Pubkey) -> Result<()> {
+/// Accept a pending withdraw wallet change (pending withdraw wallet only)
+#[derive(Accounts)]
+pub struct AcceptWithdrawWallet<'info> {
+    /// Must match admin_state.pending_withdraw_wallet - proves the wallet is controlled
+    pub new_withdraw_wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
+        bump,
+        constraint = admin_state.pending_withdraw_wallet != Pubkey::default() @ ProgramErrorCode::NoPendingHandoff,
+        constraint = admin_state.pending_withdraw_wallet == new_withdraw_wallet.key() @ ProgramErrorCode::Unauthorized
+    )]
+    pub admin_state: Box<Account<'info, AdminState>>,
+}
+
+pub fn propose_handler(ctx: Context<UpdateWithdrawWallet>, new_withdraw_wallet: Pubkey) -> Result<()> {
+    require!(!ctx.accounts.admin_state.multisig_enabled, ProgramErrorCode::MultisigRequired);
+
     // Validate that new_withdraw_wallet is not empty
     require!(
         new_withdraw_wallet != Pubkey::default(),
@@ -32,12 +50,49 @@ pub fn handler(ctx: Context<UpdateWithdrawWallet>, new_withdraw_wallet: Pubkey)
         ProgramErrorCode::SameWithdrawWallet
     );
 
-    let old_wallet = ctx.accounts.admin_state.withdraw_wallet;
-    ctx.accounts.admin_state.withdraw_wallet = new_withdraw_wallet;
+    // The new wallet takes effect only after it signs accept_withdraw_wallet
+    let clock = Clock::get()?;
+    let expires_at = clock.unix_timestamp + HANDOFF_EXPIRY_SECONDS;
+    ctx.accounts.admin_state.pending_withdraw_wallet = new_withdraw_wallet;
+    ctx.accounts.admin_state.pending_withdraw_wallet_expires_at = expires_at;
+
+    msg!("Withdraw wallet change proposed to {} (expires at {})", new_withdraw_wallet, expires_at);
+
+    Ok(())
+}
+
+pub fn accept_handler(ctx: Context<AcceptWithdrawWallet>) -> Result<()> {
+    let admin_state = &mut ctx.accounts.admin_state;
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= admin_state.pending_withdraw_wallet_expires_at,
+        ProgramErrorCode::HandoffExpired
+    );
+
+    let old_wallet = admin_state.withdraw_wallet;
+    admin_state.withdraw_wallet = admin_state.pending_withdraw_wallet;
+    admin_state.pending_withdraw_wallet = Pubkey::default();
+    admin_state.pending_withdraw_wallet_expires_at = 0;
 
     msg!("Withdraw wallet updated:");
     msg!("  From: {}", old_wallet);
-    msg!("  To: {}", new_withdraw_wallet);
+    msg!("  To: {}", admin_state.withdraw_wallet);
+
+    Ok(())
+}
+
+pub fn cancel_handler(ctx: Context<UpdateWithdrawWallet>) -> Result<()> {
+    let admin_state = &mut ctx.accounts.admin_state;
+    require!(
+        admin_state.pending_withdraw_wallet != Pubkey::default(),
+        ProgramErrorCode::NoPendingHandoff
+    );
+
+    msg!("Withdraw wallet change to {} cancelled", admin_state.pending_withdraw_wallet);
+
+    admin_state.pending_withdraw_wallet = Pubkey::default();
+    admin_state.pending_withdraw_wallet_expires_at = 0;
 
     Ok(())
 }
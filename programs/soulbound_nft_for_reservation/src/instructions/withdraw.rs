@@ -8,15 +8,18 @@ use crate::state::*;
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
-    /// Super admin who can withdraw funds
+    /// Super admin or treasurer
     #[account(mut)]
-    pub super_admin: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"admin_state".as_ref()],
+        seeds = [b"admin_state".as_ref(), &campaign_seed(admin_state.campaign_id)],
         bump,
-        constraint = admin_state.super_admin == super_admin.key() @ ProgramErrorCode::Unauthorized
+        constraint = RoleAssignment::authorizes(&admin_state, role_assignment.as_deref(), authority.key(), Role::Treasurer)
+            @ ProgramErrorCode::Unauthorized,
+        constraint = !admin_state.pause_flags.withdraw @ ProgramErrorCode::ProgramPaused,
+        constraint = !admin_state.multisig_enabled @ ProgramErrorCode::MultisigRequired,
     )]
     pub admin_state: Box<Account<'info, AdminState>>,
 
@@ -29,7 +32,7 @@ pub struct Withdraw<'info> {
     /// Vault token account (PDA-controlled) holding the payment tokens
     #[account(
         mut,
-        seeds = [b"vault", payment_mint.key().as_ref()],
+        seeds = [b"vault".as_ref(), &campaign_seed(admin_state.campaign_id), payment_mint.key().as_ref()],
         bump,
         token::mint = payment_mint,
         token::authority = admin_state,
@@ -48,6 +51,44 @@ pub struct Withdraw<'info> {
 
     /// Token program for payment (can be Token or Token2022)
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Treasurer role assignment - omitted when the super admin signs
+    #[account(
+        seeds = [b"role_assignment".as_ref(), &campaign_seed(admin_state.campaign_id), &Role::Treasurer.seed(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+/// Transfer from the vault to the withdraw wallet, signed by the admin_state PDA
+/// Shared by withdraw, withdraw_all and multisig withdraw proposals
+pub(crate) fn transfer_from_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    payment_mint: &InterfaceAccount<'info, Mint>,
+    withdraw_token_account: &InterfaceAccount<'info, TokenAccount>,
+    admin_state: &Account<'info, AdminState>,
+    admin_state_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    // Create signer seeds for admin_state PDA
+    let campaign_seed = admin_state.campaign_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_state", &campaign_seed, &[admin_state_bump]]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                mint: payment_mint.to_account_info(),
+                to: withdraw_token_account.to_account_info(),
+                authority: admin_state.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        payment_mint.decimals,
+    )
 }
 
 pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
@@ -61,25 +102,15 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     require!(amount > 0, ProgramErrorCode::InvalidWithdrawAmount);
     require!(vault_balance_before >= amount, ProgramErrorCode::InsufficientVaultBalance);
 
-    // Create signer seeds for admin_state PDA
-    let seeds = b"admin_state";
-    let bump = ctx.bumps.admin_state;
-    let signer_seeds: &[&[&[u8]]] = &[&[seeds, &[bump]]];
-
     // Transfer tokens from vault to withdraw wallet's token account
-    transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.vault.to_account_info(),
-                mint: ctx.accounts.payment_mint.to_account_info(),
-                to: ctx.accounts.withdraw_token_account.to_account_info(),
-                authority: ctx.accounts.admin_state.to_account_info(),
-            },
-            signer_seeds,
-        ),
+    transfer_from_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.payment_mint,
+        &ctx.accounts.withdraw_token_account,
+        &ctx.accounts.admin_state,
+        ctx.bumps.admin_state,
         amount,
-        ctx.accounts.payment_mint.decimals,
     )?;
 
     // Calculate remaining balance (vault balance is updated after transfer)
@@ -107,25 +138,15 @@ pub fn withdraw_all_handler(ctx: Context<Withdraw>) -> Result<()> {
     // Validate that vault has balance
     require!(vault_balance > 0, ProgramErrorCode::InsufficientVaultBalance);
 
-    // Create signer seeds for admin_state PDA
-    let seeds = b"admin_state";
-    let bump = ctx.bumps.admin_state;
-    let signer_seeds: &[&[&[u8]]] = &[&[seeds, &[bump]]];
-
     // Transfer all tokens from vault to withdraw wallet's token account
-    transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.vault.to_account_info(),
-                mint: ctx.accounts.payment_mint.to_account_info(),
-                to: ctx.accounts.withdraw_token_account.to_account_info(),
-                authority: ctx.accounts.admin_state.to_account_info(),
-            },
-            signer_seeds,
-        ),
+    transfer_from_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.payment_mint,
+        &ctx.accounts.withdraw_token_account,
+        &ctx.accounts.admin_state,
+        ctx.bumps.admin_state,
         vault_balance,
-        ctx.accounts.payment_mint.decimals,
     )?;
 
     // After transfer, vault balance should be 0
@@ -1,14 +1,19 @@
 #![allow(unexpected_cfgs)]
+#![allow(clippy::too_many_arguments)]
+#![allow(ambiguous_glob_reexports)]
 
 use anchor_lang::prelude::*;
 
+pub mod allowlist;
 pub mod error;
 pub mod state;
 pub mod instructions;
 
 pub use instructions::*;
 
+pub use crate::allowlist::AllowlistProof;
 pub use crate::error::ProgramErrorCode;
+pub use crate::state::{CollectionCreator, ConfigChange, PauseFlags, ProposalAction, QuantityPrice, Role};
 
 declare_id!("Ca8PS65mtseoGEsJpVbAbrXuTUamU9moSGSonVTtpnHt");
 
@@ -19,47 +24,73 @@ pub mod soulbound_nft_for_reservation {
     /// Initialize admin state with super_admin (signer)
     pub fn init_admin(
         ctx: Context<InitAdmin>,
-        // OG Collection parameters
-        og_collection_mint: Pubkey,
-        og_mint_fee: u64,
-        og_max_supply: u64,
-        og_admin_mint_limit: u64,
-        // Regular Collection parameters
-        regular_collection_mint: Pubkey,
-        regular_mint_fee: u64,
-        regular_max_supply: u64,
-        regular_admin_mint_limit: u64,
-        // Basic Collection parameters
-        basic_collection_mint: Pubkey,
-        basic_mint_fee: u64,
-        basic_max_supply: u64,
-        basic_admin_mint_limit: u64,
-        // Shared parameters
+        campaign_id: u64,
         withdraw_wallet: Pubkey,
         mint_start_date: i64,
     ) -> Result<()> {
-        instructions::init_admin::handler(
-            ctx,
-            og_collection_mint, og_mint_fee, og_max_supply, og_admin_mint_limit,
-            regular_collection_mint, regular_mint_fee, regular_max_supply, regular_admin_mint_limit,
-            basic_collection_mint, basic_mint_fee, basic_max_supply, basic_admin_mint_limit,
-            withdraw_wallet, mint_start_date
-        )
+        instructions::init_admin::handler(ctx, campaign_id, withdraw_wallet, mint_start_date)
     }
 
-    /// Update mint fee for a specific collection (super_admin only)
-    pub fn update_mint_fee(ctx: Context<UpdateAdminInfo>, collection_type: state::CollectionType, mint_fee: u64) -> Result<()> {
-        instructions::update_admin::update_mint_fee_handler(ctx, collection_type, mint_fee)
+    /// Register a new collection tier (super_admin only)
+    pub fn add_collection(
+        ctx: Context<AddCollection>,
+        tier_id: u16,
+        collection_mint: Pubkey,
+        mint_fee: u64,
+        max_supply: u64,
+        admin_mint_limit: u64,
+    ) -> Result<()> {
+        instructions::manage_collection::add_collection_handler(ctx, tier_id, collection_mint, mint_fee, max_supply, admin_mint_limit)
+    }
+
+    /// Retire a collection tier - stops new mints, existing NFTs can still be burned (super_admin only)
+    pub fn retire_collection(ctx: Context<RetireCollection>, tier_id: u16) -> Result<()> {
+        instructions::manage_collection::retire_collection_handler(ctx, tier_id)
+    }
+
+    /// Permanently close the sale for a collection tier - freezes supply and prices (super_admin only)
+    pub fn close_sale(ctx: Context<CloseSale>, tier_id: u16) -> Result<()> {
+        instructions::manage_collection::close_sale_handler(ctx, tier_id)
+    }
+
+    /// Move the OG/Regular/Basic configs of a legacy admin state into CollectionState PDAs (super_admin only)
+    pub fn migrate_collections(ctx: Context<MigrateCollections>) -> Result<()> {
+        instructions::migrate::migrate_collections_handler(ctx)
+    }
+
+    /// Rewrite a legacy user state into the current layout (permissionless)
+    pub fn migrate_user_state(ctx: Context<MigrateUserState>, campaign_id: u64) -> Result<()> {
+        instructions::migrate::migrate_user_state_handler(ctx, campaign_id)
     }
 
-    /// Update max supply for a specific collection (super_admin only)
-    pub fn update_max_supply(ctx: Context<UpdateAdminInfo>, collection_type: state::CollectionType, max_supply: u64) -> Result<()> {
-        instructions::update_admin::update_max_supply_handler(ctx, collection_type, max_supply)
+    /// Update mint fee for a specific collection (super_admin or fee manager)
+    pub fn update_mint_fee(ctx: Context<UpdateCollectionPricing>, tier_id: u16, mint_fee: u64) -> Result<()> {
+        instructions::update_admin::update_mint_fee_handler(ctx, tier_id, mint_fee)
+    }
+
+    /// Update max supply for a specific collection (super_admin or fee manager)
+    pub fn update_max_supply(ctx: Context<UpdateCollectionPricing>, tier_id: u16, max_supply: u64) -> Result<()> {
+        instructions::update_admin::update_max_supply_handler(ctx, tier_id, max_supply)
     }
 
     /// Update admin mint limit for a specific collection (super_admin only)
-    pub fn update_admin_mint_limit(ctx: Context<UpdateAdminInfo>, collection_type: state::CollectionType, admin_mint_limit: u64) -> Result<()> {
-        instructions::update_admin::update_admin_mint_limit_handler(ctx, collection_type, admin_mint_limit)
+    pub fn update_admin_mint_limit(ctx: Context<UpdateCollectionInfo>, tier_id: u16, admin_mint_limit: u64) -> Result<()> {
+        instructions::update_admin::update_admin_mint_limit_handler(ctx, tier_id, admin_mint_limit)
+    }
+
+    /// Update the sale window for a specific collection - 0 start falls back to mint_start_date, 0 end means no end (super_admin only)
+    pub fn update_sale_window(ctx: Context<UpdateCollectionInfo>, tier_id: u16, sale_start: i64, sale_end: i64) -> Result<()> {
+        instructions::update_admin::update_sale_window_handler(ctx, tier_id, sale_start, sale_end)
+    }
+
+    /// Set when unused admin allocation may be released to the public bucket (super_admin only)
+    pub fn update_admin_release_date(ctx: Context<UpdateCollectionInfo>, tier_id: u16, admin_release_date: i64) -> Result<()> {
+        instructions::update_admin::update_admin_release_date_handler(ctx, tier_id, admin_release_date)
+    }
+
+    /// Move unused admin allocation into the public bucket after the release date (super_admin only)
+    pub fn release_admin_allocation(ctx: Context<UpdateCollectionInfo>, tier_id: u16) -> Result<()> {
+        instructions::update_admin::release_admin_allocation_handler(ctx, tier_id)
     }
 
     /// Update mint start date - shared across all collections (super_admin only)
@@ -67,19 +98,266 @@ pub mod soulbound_nft_for_reservation {
         instructions::update_admin::update_mint_start_date_handler(ctx, mint_start_date)
     }
 
+    /// Set or clear the allowlist Merkle root for a specific collection (super_admin only)
+    pub fn update_allowlist_root(ctx: Context<UpdateCollectionInfo>, tier_id: u16, allowlist_root: Option<[u8; 32]>) -> Result<()> {
+        instructions::update_admin::update_allowlist_root_handler(ctx, tier_id, allowlist_root)
+    }
+
+    /// Append a sale phase to a collection's schedule (super_admin only)
+    pub fn add_sale_phase(
+        ctx: Context<UpdateCollectionInfo>,
+        tier_id: u16,
+        start_time: i64,
+        end_time: i64,
+        allowlist_root: Option<[u8; 32]>,
+        price: Option<u64>,
+        supply_cap: u64,
+        per_wallet_limit: u16,
+    ) -> Result<()> {
+        instructions::sale_phase::add_sale_phase_handler(ctx, tier_id, start_time, end_time, allowlist_root, price, supply_cap, per_wallet_limit)
+    }
+
+    /// Update an existing sale phase - mints already made in the phase are kept (super_admin only)
+    pub fn update_sale_phase(
+        ctx: Context<UpdateCollectionInfo>,
+        tier_id: u16,
+        phase_index: u8,
+        start_time: i64,
+        end_time: i64,
+        allowlist_root: Option<[u8; 32]>,
+        price: Option<u64>,
+        supply_cap: u64,
+        per_wallet_limit: u16,
+    ) -> Result<()> {
+        instructions::sale_phase::update_sale_phase_handler(ctx, tier_id, phase_index, start_time, end_time, allowlist_root, price, supply_cap, per_wallet_limit)
+    }
+
+    /// Set how many reservations a wallet may hold across the campaign - 1 keeps one per wallet (super_admin only)
+    pub fn update_wallet_reservation_cap(ctx: Context<UpdateAdminInfo>, max_reservations_per_wallet: u16) -> Result<()> {
+        instructions::reservation_policy::update_wallet_reservation_cap_handler(ctx, max_reservations_per_wallet)
+    }
+
+    /// Set a collection's per-wallet cap (0 = campaign cap only) and quantity price breaks (super_admin only)
+    pub fn update_collection_wallet_limits(
+        ctx: Context<UpdateCollectionInfo>,
+        tier_id: u16,
+        max_per_wallet: u16,
+        quantity_prices: Vec<QuantityPrice>,
+    ) -> Result<()> {
+        instructions::reservation_policy::update_collection_wallet_limits_handler(ctx, tier_id, max_per_wallet, quantity_prices)
+    }
+
+    /// Set the name prefix, symbol and base URI minted NFTs of a collection carry (super_admin only)
+    pub fn update_collection_metadata(
+        ctx: Context<UpdateCollectionInfo>,
+        tier_id: u16,
+        name_prefix: String,
+        symbol: String,
+        base_uri: String,
+    ) -> Result<()> {
+        instructions::update_admin::update_collection_metadata_handler(ctx, tier_id, name_prefix, symbol, base_uri)
+    }
+
+    /// Toggle appending the serial (e.g. "OG #0042") to NFT names minted in a collection (super_admin only)
+    pub fn update_serial_in_name(ctx: Context<UpdateCollectionInfo>, tier_id: u16, serial_in_name: bool) -> Result<()> {
+        instructions::update_admin::update_serial_in_name_handler(ctx, tier_id, serial_in_name)
+    }
+
+    /// Set partner creators and royalty written to a collection's NFTs - admin_state keeps the remaining share (super_admin only)
+    pub fn update_collection_royalties(
+        ctx: Context<UpdateCollectionInfo>,
+        tier_id: u16,
+        creators: Vec<CollectionCreator>,
+        royalty_bps: u16,
+    ) -> Result<()> {
+        instructions::update_admin::update_collection_royalties_handler(ctx, tier_id, creators, royalty_bps)
+    }
+
     /// Update collection mint address for a specific collection (super_admin only)
-    pub fn update_collection_mint(ctx: Context<UpdateAdminInfo>, collection_type: state::CollectionType, collection_mint: Pubkey) -> Result<()> {
-        instructions::update_admin::update_collection_mint_handler(ctx, collection_type, collection_mint)
+    pub fn update_collection_mint(ctx: Context<UpdateCollectionInfo>, tier_id: u16, collection_mint: Pubkey) -> Result<()> {
+        instructions::update_admin::update_collection_mint_handler(ctx, tier_id, collection_mint)
+    }
+
+    /// Propose a new withdraw wallet - takes effect once the wallet accepts (super_admin only)
+    pub fn propose_withdraw_wallet(ctx: Context<UpdateWithdrawWallet>, new_withdraw_wallet: Pubkey) -> Result<()> {
+        instructions::update_withdraw_wallet::propose_handler(ctx, new_withdraw_wallet)
+    }
+
+    /// Accept a pending withdraw wallet change (pending withdraw wallet only)
+    pub fn accept_withdraw_wallet(ctx: Context<AcceptWithdrawWallet>) -> Result<()> {
+        instructions::update_withdraw_wallet::accept_handler(ctx)
+    }
+
+    /// Cancel a pending withdraw wallet change (super_admin only)
+    pub fn cancel_withdraw_wallet_change(ctx: Context<UpdateWithdrawWallet>) -> Result<()> {
+        instructions::update_withdraw_wallet::cancel_handler(ctx)
+    }
+
+    /// Propose a new super admin - takes effect once the new key accepts (super_admin only)
+    pub fn propose_super_admin(ctx: Context<UpdateAdminInfo>, new_super_admin: Pubkey) -> Result<()> {
+        instructions::update_admin::propose_super_admin_handler(ctx, new_super_admin)
+    }
+
+    /// Accept a pending super admin handoff (pending super admin only)
+    pub fn accept_super_admin(ctx: Context<AcceptSuperAdmin>) -> Result<()> {
+        instructions::update_admin::accept_super_admin_handler(ctx)
+    }
+
+    /// Cancel a pending super admin handoff (super_admin only)
+    pub fn cancel_super_admin_handoff(ctx: Context<UpdateAdminInfo>) -> Result<()> {
+        instructions::update_admin::cancel_super_admin_handoff_handler(ctx)
+    }
+
+    /// Set the guardian allowed to pause the program (super_admin only)
+    pub fn update_guardian(ctx: Context<UpdateAdminInfo>, new_guardian: Pubkey) -> Result<()> {
+        instructions::pause::update_guardian_handler(ctx, new_guardian)
+    }
+
+    /// Pause the selected actions - flags can only be added (guardian or super_admin)
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, flags: PauseFlags) -> Result<()> {
+        instructions::pause::set_pause_flags_handler(ctx, flags)
+    }
+
+    /// Unpause the selected actions (super_admin only)
+    pub fn clear_pause_flags(ctx: Context<UpdateAdminInfo>, flags: PauseFlags) -> Result<()> {
+        instructions::pause::clear_pause_flags_handler(ctx, flags)
+    }
+
+    /// Grant a least-privilege role to a holder (super_admin only)
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, holder: Pubkey) -> Result<()> {
+        instructions::roles::grant_role_handler(ctx, role, holder)
+    }
+
+    /// Revoke a previously granted role (super_admin only)
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, holder: Pubkey) -> Result<()> {
+        instructions::roles::revoke_role_handler(ctx, role, holder)
+    }
+
+    /// Lengthen the config change delay, up to 30 days - shortening it must be queued (super_admin only)
+    pub fn update_config_delay(ctx: Context<UpdateAdminInfo>, config_delay: i64) -> Result<()> {
+        instructions::timelock::update_config_delay_handler(ctx, config_delay)
+    }
+
+    /// Queue a timelocked config change (super_admin, or fee manager for fee and supply)
+    pub fn queue_config_change(ctx: Context<QueueConfigChange>, change: ConfigChange) -> Result<()> {
+        instructions::timelock::queue_config_change_handler(ctx, change)
     }
 
-    /// Update withdraw wallet (super_admin only)
-    pub fn update_withdraw_wallet(ctx: Context<UpdateWithdrawWallet>, new_withdraw_wallet: Pubkey) -> Result<()> {
-        instructions::update_withdraw_wallet::handler(ctx, new_withdraw_wallet)
+    /// Apply a queued config change once its delay has passed (permissionless)
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>, change_id: u64) -> Result<()> {
+        instructions::timelock::execute_config_change_handler(ctx, change_id)
     }
 
-    /// Update super admin - transfer admin control to a new address (super_admin only)
-    pub fn update_super_admin(ctx: Context<UpdateAdminInfo>, new_super_admin: Pubkey) -> Result<()> {
-        instructions::update_admin::update_super_admin_handler(ctx, new_super_admin)
+    /// Apply a queued payment mint change once its delay has passed (permissionless)
+    pub fn execute_payment_mint_change(ctx: Context<ExecutePaymentMintChange>, change_id: u64) -> Result<()> {
+        instructions::timelock::execute_payment_mint_change_handler(ctx, change_id)
+    }
+
+    /// Cancel a queued config change before it executes (super_admin only)
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>, change_id: u64) -> Result<()> {
+        instructions::timelock::cancel_config_change_handler(ctx, change_id)
+    }
+
+    /// Hand privileged actions to an M-of-N signer set - irreversible (super_admin only)
+    pub fn create_multisig(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::multisig::create_multisig_handler(ctx, signers, threshold)
+    }
+
+    /// Hand the update authority of NFTs still controlled by admin_state to the executed handoff target
+    /// (mint, metadata per NFT in remaining_accounts) - permissionless
+    pub fn hand_off_update_authority<'info>(ctx: Context<'_, '_, '_, 'info, HandOffUpdateAuthority<'info>>) -> Result<()> {
+        instructions::authority_handoff::handler(ctx)
+    }
+
+    /// Propose a privileged action (multisig signer only)
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        instructions::multisig::create_proposal_handler(ctx, action)
+    }
+
+    /// Approve a proposal (multisig signer only)
+    pub fn approve_proposal(ctx: Context<ApproveProposal>, proposal_id: u64) -> Result<()> {
+        instructions::multisig::approve_proposal_handler(ctx, proposal_id)
+    }
+
+    /// Execute an approved configuration or admin change proposal (multisig signer only)
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Result<()> {
+        instructions::multisig::execute_proposal_handler(ctx, proposal_id)
+    }
+
+    /// Execute an approved withdraw proposal (multisig signer only)
+    pub fn execute_withdraw_proposal(ctx: Context<ExecuteWithdrawProposal>, proposal_id: u64) -> Result<()> {
+        instructions::multisig::execute_withdraw_proposal_handler(ctx, proposal_id)
+    }
+
+    /// Execute an approved payment mint proposal (multisig signer only)
+    pub fn execute_payment_mint_proposal(ctx: Context<ExecutePaymentMintProposal>, proposal_id: u64) -> Result<()> {
+        instructions::multisig::execute_payment_mint_proposal_handler(ctx, proposal_id)
+    }
+
+    /// Execute an approved add collection proposal (multisig signer only)
+    pub fn execute_add_collection_proposal(
+        ctx: Context<ExecuteAddCollectionProposal>,
+        proposal_id: u64,
+        tier_id: u16,
+    ) -> Result<()> {
+        instructions::multisig::execute_add_collection_proposal_handler(ctx, proposal_id, tier_id)
+    }
+
+    /// Execute an approved grant or revoke role proposal (multisig signer only)
+    pub fn execute_role_proposal(
+        ctx: Context<ExecuteRoleProposal>,
+        proposal_id: u64,
+        role: Role,
+        holder: Pubkey,
+    ) -> Result<()> {
+        instructions::multisig::execute_role_proposal_handler(ctx, proposal_id, role, holder)
+    }
+
+    /// Execute an approved set or revoke operator quota proposal (multisig signer only)
+    pub fn execute_operator_quota_proposal(
+        ctx: Context<ExecuteOperatorQuotaProposal>,
+        proposal_id: u64,
+        tier_id: u16,
+        operator: Pubkey,
+    ) -> Result<()> {
+        instructions::multisig::execute_operator_quota_proposal_handler(ctx, proposal_id, tier_id, operator)
+    }
+
+    /// Execute an approved collection NFT metadata or mutability proposal (multisig signer only)
+    pub fn execute_collection_nft_proposal(
+        ctx: Context<ExecuteCollectionNftProposal>,
+        proposal_id: u64,
+        tier_id: u16,
+    ) -> Result<()> {
+        instructions::multisig::execute_collection_nft_proposal_handler(ctx, proposal_id, tier_id)
+    }
+
+    /// Execute an approved collection authority delegate proposal (multisig signer only)
+    pub fn execute_collection_authority_proposal(
+        ctx: Context<ExecuteCollectionAuthorityProposal>,
+        proposal_id: u64,
+        tier_id: u16,
+    ) -> Result<()> {
+        instructions::multisig::execute_collection_authority_proposal_handler(ctx, proposal_id, tier_id)
+    }
+
+    /// Execute an approved collection NFT transfer proposal (multisig signer only)
+    pub fn execute_transfer_collection_nft_proposal(
+        ctx: Context<ExecuteTransferCollectionNftProposal>,
+        proposal_id: u64,
+        tier_id: u16,
+    ) -> Result<()> {
+        instructions::multisig::execute_transfer_collection_nft_proposal_handler(ctx, proposal_id, tier_id)
+    }
+
+    /// Register an airdrop operator's quota for a collection tier (super_admin only)
+    pub fn set_operator_quota(ctx: Context<SetOperatorQuota>, tier_id: u16, operator: Pubkey, quota: u64) -> Result<()> {
+        instructions::operator_quota::set_operator_quota_handler(ctx, tier_id, operator, quota)
+    }
+
+    /// Revoke an airdrop operator for a collection tier (super_admin only)
+    pub fn revoke_operator_quota(ctx: Context<RevokeOperatorQuota>, tier_id: u16, operator: Pubkey) -> Result<()> {
+        instructions::operator_quota::revoke_operator_quota_handler(ctx, tier_id, operator)
     }
 
     /// Update payment mint - migrate to a new payment token (super_admin only)
@@ -92,31 +370,113 @@ pub mod soulbound_nft_for_reservation {
         instructions::create_collection_nft::handler(ctx, name, symbol, uri)
     }
 
-    /// Mint an NFT in a specific collection
-    pub fn mint_nft(ctx: Context<MintNft>, collection_type: state::CollectionType, name: String, symbol: String, uri: String) -> Result<()> {
-        instructions::mint_nft::handler(ctx, collection_type, name, symbol, uri)
+    /// Update a collection NFT's name, symbol and/or URI - omitted fields keep their current value (super_admin only)
+    pub fn update_collection_nft_metadata(
+        ctx: Context<UpdateCollectionNft>,
+        tier_id: u16,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        instructions::collection_nft::update_collection_nft_metadata_handler(ctx, tier_id, name, symbol, uri)
+    }
+
+    /// Lock a collection NFT's metadata - Metaplex does not allow unlocking (super_admin only)
+    pub fn set_collection_nft_mutability(ctx: Context<UpdateCollectionNft>, tier_id: u16, is_mutable: bool) -> Result<()> {
+        instructions::collection_nft::set_collection_nft_mutability_handler(ctx, tier_id, is_mutable)
+    }
+
+    /// Move NFTs minted under a replaced collection mint into the current one (reservation, mint, metadata
+    /// per NFT in remaining_accounts) - permissionless and resumable until the old collection is empty
+    pub fn migrate_collection_nfts<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateCollectionNfts<'info>>,
+        tier_id: u16,
+    ) -> Result<()> {
+        instructions::collection_migration::handler(ctx, tier_id)
+    }
+
+    /// Approve a Metaplex collection authority delegate for a collection (super_admin only)
+    pub fn approve_collection_authority(ctx: Context<CollectionAuthorityDelegate>, tier_id: u16) -> Result<()> {
+        instructions::collection_nft::approve_collection_authority_handler(ctx, tier_id)
+    }
+
+    /// Revoke a Metaplex collection authority delegate from a collection (super_admin only)
+    pub fn revoke_collection_authority(ctx: Context<CollectionAuthorityDelegate>, tier_id: u16) -> Result<()> {
+        instructions::collection_nft::revoke_collection_authority_handler(ctx, tier_id)
+    }
+
+    /// Move a collection NFT out of the admin_state ATA - update authority is unaffected (super_admin only)
+    pub fn transfer_collection_nft(ctx: Context<TransferCollectionNft>, tier_id: u16) -> Result<()> {
+        instructions::collection_nft::transfer_collection_nft_handler(ctx, tier_id)
+    }
+
+    /// Mint an NFT in a specific collection - allowlist_proof is required when the collection has an allowlist root
+    /// Metadata is derived from the collection config and the NFT is always verified in the collection
+    pub fn mint_nft(ctx: Context<MintNft>, tier_id: u16, allowlist_proof: Option<AllowlistProof>) -> Result<()> {
+        instructions::mint_nft::handler(ctx, tier_id, allowlist_proof)
+    }
+
+    /// Admin mint an NFT for a specific recipient (airdrop) - super_admin or airdrop operator
+    pub fn admin_mint_nft(ctx: Context<AdminMintNft>, tier_id: u16) -> Result<()> {
+        instructions::admin_mint_nft::handler(ctx, tier_id)
+    }
+
+    /// Admin mint to several recipients passed through remaining_accounts (recipient, mint, token account,
+    /// metadata, user state, reservation, serial record per recipient) - super_admin or airdrop operator
+    pub fn batch_admin_mint_nft<'info>(ctx: Context<'_, '_, '_, 'info, BatchAdminMintNft<'info>>, tier_id: u16) -> Result<()> {
+        instructions::batch_admin_mint_nft::handler(ctx, tier_id)
+    }
+
+    /// Commit the final art order hash, the placeholder URI and a future slot whose hash will seed
+    /// the reveal, before the first mint - max supply is fixed from then on (super_admin only)
+    pub fn commit_provenance(
+        ctx: Context<UpdateCollectionInfo>,
+        tier_id: u16,
+        provenance_hash: [u8; 32],
+        placeholder_uri: String,
+        reveal_slot: u64,
+    ) -> Result<()> {
+        instructions::reveal::commit_provenance_handler(ctx, tier_id, provenance_hash, placeholder_uri, reveal_slot)
+    }
+
+    /// Move the committed reveal slot further out while it has not been reached, or replace it once
+    /// it left the SlotHashes window without seeding the reveal (super_admin only)
+    pub fn postpone_reveal(ctx: Context<UpdateCollectionInfo>, tier_id: u16, reveal_slot: u64) -> Result<()> {
+        instructions::reveal::postpone_reveal_handler(ctx, tier_id, reveal_slot)
+    }
+
+    /// Draw the art offset from the hash of the first block at or after the committed slot, which
+    /// must still be in the SlotHashes sysvar - permissionless
+    pub fn seed_reveal(ctx: Context<SeedReveal>, tier_id: u16) -> Result<()> {
+        instructions::reveal::seed_reveal_handler(ctx, tier_id)
+    }
+
+    /// Reveal a collection, drawing the art offset first if nobody has yet (super_admin only)
+    pub fn reveal_collection(ctx: Context<RevealCollection>, tier_id: u16, reveal_base_uri: String) -> Result<()> {
+        instructions::reveal::reveal_collection_handler(ctx, tier_id, reveal_base_uri)
     }
 
-    /// Admin mint an NFT for a specific recipient (airdrop) - super_admin only
-    pub fn admin_mint_nft(ctx: Context<AdminMintNft>, collection_type: state::CollectionType, name: String, symbol: String, uri: String) -> Result<()> {
-        instructions::admin_mint_nft::handler(ctx, collection_type, name, symbol, uri)
+    /// Point minted NFTs at their final art (serial record, mint, metadata per NFT in remaining_accounts) - permissionless
+    pub fn crank_reveal<'info>(ctx: Context<'_, '_, '_, 'info, CrankReveal<'info>>, tier_id: u16) -> Result<()> {
+        instructions::reveal::crank_reveal_handler(ctx, tier_id)
     }
 
+    /// Update an NFT's name, symbol and/or URI - omitted fields keep their current value
     pub fn update_nft_metadata(ctx: Context<UpdateNftMetadata>, name: Option<String>, symbol: Option<String>, uri: Option<String>) -> Result<()> {
         instructions::update_nft_metadata::handler(ctx, name, symbol, uri)
     }
 
-    /// Burn an NFT - collection type is determined from user state
+    /// Burn an NFT - collection tier is determined from user state
     pub fn burn_nft(ctx: Context<BurnNft>) -> Result<()> {
         instructions::burn_nft::handler(ctx)
     }
 
-    /// Withdraw payment tokens from the vault (super_admin only)
+    /// Withdraw payment tokens from the vault (super_admin or treasurer)
     pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         instructions::withdraw::handler(ctx, amount)
     }
 
-    /// Withdraw all payment tokens from the vault (super_admin only)
+    /// Withdraw all payment tokens from the vault (super_admin or treasurer)
     pub fn withdraw_all(ctx: Context<Withdraw>) -> Result<()> {
         instructions::withdraw::withdraw_all_handler(ctx)
     }
@@ -1,84 +1,109 @@
 use anchor_lang::prelude::*;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
-pub enum CollectionType {
-    OG,
-    Regular,
-    Basic,
+/// Campaign of the original single-campaign deployment
+pub const DEFAULT_CAMPAIGN_ID: u64 = 0;
+
+/// PDA seed component for a campaign.
+/// The default campaign uses an empty seed so its admin_state, vaults, collection states and
+/// user states keep the addresses they had before campaigns were introduced.
+pub fn campaign_seed(campaign_id: u64) -> Vec<u8> {
+    if campaign_id == DEFAULT_CAMPAIGN_ID {
+        Vec::new()
+    } else {
+        campaign_id.to_le_bytes().to_vec()
+    }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
-pub struct CollectionConfig {
-    pub collection_mint: Pubkey,         // Collection mint address
-    pub mint_fee: u64,                   // Fee to mint NFT in this collection
-    pub max_supply: u64,                 // Maximum supply (0 = unlimited)
-    pub current_reserved_count: u64,     // Current minted count
-    pub admin_mint_limit: u64,           // Maximum NFTs admin can mint (0 = admin cannot mint)
-    pub current_admin_mint_count: u64,   // Current admin minted count
+/// How long a proposed super_admin or withdraw_wallet handoff can be accepted (7 days)
+pub const HANDOFF_EXPIRY_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Longest config delay (30 days) - keeps queued changes and timelocked proposals executable
+pub const MAX_CONFIG_DELAY: i64 = 30 * 24 * 60 * 60;
+
+/// Emergency pause switches - each one stops a single class of instructions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PauseFlags {
+    pub mint: bool,
+    pub admin_mint: bool,
+    pub burn: bool,
+    pub withdraw: bool,
+    pub metadata: bool,
 }
 
-impl CollectionConfig {
-    pub fn new(collection_mint: Pubkey, mint_fee: u64, max_supply: u64, admin_mint_limit: u64) -> Self {
-        Self {
-            collection_mint,
-            mint_fee,
-            max_supply,
-            current_reserved_count: 0,
-            admin_mint_limit,
-            current_admin_mint_count: 0,
+impl PauseFlags {
+    pub const SIZE: usize = 5;
+
+    /// Flags set in either `self` or `other`
+    pub fn union(&self, other: &PauseFlags) -> PauseFlags {
+        PauseFlags {
+            mint: self.mint || other.mint,
+            admin_mint: self.admin_mint || other.admin_mint,
+            burn: self.burn || other.burn,
+            withdraw: self.withdraw || other.withdraw,
+            metadata: self.metadata || other.metadata,
         }
     }
 
-    pub const fn space() -> usize {
-        32 +        // collection_mint
-        8 +         // mint_fee
-        8 +         // max_supply
-        8 +         // current_reserved_count
-        8 +         // admin_mint_limit
-        8           // current_admin_mint_count
+    /// Flags set in `self` but not in `other`
+    pub fn difference(&self, other: &PauseFlags) -> PauseFlags {
+        PauseFlags {
+            mint: self.mint && !other.mint,
+            admin_mint: self.admin_mint && !other.admin_mint,
+            burn: self.burn && !other.burn,
+            withdraw: self.withdraw && !other.withdraw,
+            metadata: self.metadata && !other.metadata,
+        }
     }
 }
 
 #[account]
 pub struct AdminState {
     pub bump: u8,                       // bump for PDA
+    pub campaign_id: u64,               // campaign this admin state governs (PDA seed)
     pub super_admin: Pubkey,            // super admin - can update all admin parameters
     pub withdraw_wallet: Pubkey,        // wallet address to receive withdrawn funds
     pub payment_mint: Pubkey,           // SPL token mint address for payment (e.g., USDC) - SHARED
     pub mint_start_date: i64,           // Unix timestamp when minting starts (0 = no restriction) - SHARED
-
-    // Three collection configurations
-    pub og_collection: CollectionConfig,      // OG collection config
-    pub regular_collection: CollectionConfig, // Regular collection config
-    pub basic_collection: CollectionConfig,   // Basic collection config
+    pub guardian: Pubkey,               // Can set pause flags but not clear them (default = none)
+    pub pause_flags: PauseFlags,        // Emergency pause switches - only super_admin can clear
+    pub pending_super_admin: Pubkey,    // Proposed super admin awaiting acceptance (default = none)
+    pub pending_super_admin_expires_at: i64, // Deadline for accepting pending_super_admin
+    pub pending_withdraw_wallet: Pubkey, // Proposed withdraw wallet awaiting acceptance (default = none)
+    pub pending_withdraw_wallet_expires_at: i64, // Deadline for accepting pending_withdraw_wallet
+    pub multisig_enabled: bool,         // Privileged actions must go through Multisig proposals
+    pub config_delay: i64,              // Seconds a queued config change waits (0 = direct updates allowed)
+    pub config_change_count: u64,       // Next PendingChange id (PDA seed)
+    pub max_reservations_per_wallet: u16, // Reservations a wallet may hold across the campaign (1 = one per wallet)
+    pub update_authority_handoff: Pubkey, // Key NFT update authority is being handed to (default = none, set once)
+    pub update_authority_handoff_at: i64, // Timestamp the handoff was executed
+    pub handed_off_count: u64,          // Metadata accounts handed off by the crank so far
 }
 
 impl AdminState {
     pub fn space() -> usize {
         8 +                             // discriminator
         1 +                             // bump
+        8 +                             // campaign_id
         32 +                            // super_admin
         32 +                            // withdraw_wallet
         32 +                            // payment_mint
         8 +                             // mint_start_date
-        CollectionConfig::space() +     // og_collection
-        CollectionConfig::space() +     // regular_collection
-        CollectionConfig::space()       // basic_collection
+        32 +                            // guardian
+        PauseFlags::SIZE +              // pause_flags
+        32 +                            // pending_super_admin
+        8 +                             // pending_super_admin_expires_at
+        32 +                            // pending_withdraw_wallet
+        8 +                             // pending_withdraw_wallet_expires_at
+        1 +                             // multisig_enabled
+        8 +                             // config_delay
+        8 +                             // config_change_count
+        2 +                             // max_reservations_per_wallet
+        32 +                            // update_authority_handoff
+        8 +                             // update_authority_handoff_at
+        8                               // handed_off_count
     }
 
-    pub fn get_collection_config(&self, collection_type: CollectionType) -> &CollectionConfig {
-        match collection_type {
-            CollectionType::OG => &self.og_collection,
-            CollectionType::Regular => &self.regular_collection,
-            CollectionType::Basic => &self.basic_collection,
-        }
-    }
-
-    pub fn get_collection_config_mut(&mut self, collection_type: CollectionType) -> &mut CollectionConfig {
-        match collection_type {
-            CollectionType::OG => &mut self.og_collection,
-            CollectionType::Regular => &mut self.regular_collection,
-            CollectionType::Basic => &mut self.basic_collection,
-        }
+    pub fn campaign_seed(&self) -> Vec<u8> {
+        campaign_seed(self.campaign_id)
     }
 }
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Progress of moving a tier's minted NFTs from a replaced collection mint to the current one
+#[account]
+pub struct CollectionMigration {
+    pub tier_id: u16,                    // Tier id being migrated (PDA seed)
+    pub old_collection_mint: Pubkey,     // Collection NFTs are unverified from
+    pub new_collection_mint: Pubkey,     // Collection NFTs are verified into
+    pub migrated_count: u64,             // NFTs moved so far
+    pub started_at: i64,                 // Timestamp of the first crank for this migration
+    pub completed: bool,                 // Old collection reported zero size
+    pub bump: u8,                        // PDA bump
+}
+
+impl CollectionMigration {
+    pub fn space() -> usize {
+        8 + // discriminator
+        2 + // tier_id
+        32 + // old_collection_mint
+        32 + // new_collection_mint
+        8 + // migrated_count
+        8 + // started_at
+        1 + // completed
+        1 // bump
+    }
+}
@@ -0,0 +1,276 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ProgramErrorCode;
+
+/// Tier ids assigned to the three collections that used to be embedded in AdminState
+pub const OG_TIER_ID: u16 = 0;
+pub const REGULAR_TIER_ID: u16 = 1;
+pub const BASIC_TIER_ID: u16 = 2;
+
+/// Maximum number of sale phases a collection can schedule
+pub const MAX_SALE_PHASES: usize = 4;
+
+/// Maximum number of quantity price breaks a collection can define
+pub const MAX_QUANTITY_PRICES: usize = 4;
+
+/// Longest NFT name, symbol and URI Metaplex token metadata accepts
+pub const MAX_NFT_NAME_LENGTH: usize = 32;
+pub const MAX_NFT_SYMBOL_LENGTH: usize = 10;
+pub const MAX_NFT_URI_LENGTH: usize = 200;
+
+/// Room a base URI ending in '/' keeps for the "<serial>.json" suffix (u64 digits + ".json")
+pub const SERIAL_URI_SUFFIX_LENGTH: usize = 20 + 5;
+
+/// Partner creators a collection can credit - Metaplex allows 5 creators and admin_state takes one slot
+pub const MAX_COLLECTION_CREATORS: usize = 4;
+
+/// Largest royalty Metaplex accepts (100%)
+pub const MAX_ROYALTY_BPS: u16 = 10_000;
+
+/// Partner credited as a creator on every NFT of a collection - admin_state keeps the remaining share
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CollectionCreator {
+    pub address: Pubkey,                 // Creator wallet - verifies itself through Metaplex sign_metadata
+    pub share: u8,                       // Percentage of royalties (all shares incl. admin_state sum to 100)
+}
+
+impl CollectionCreator {
+    pub const SIZE: usize = 32 + 1;
+}
+
+/// Price for a wallet's nth reservation in a collection and beyond
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuantityPrice {
+    pub min_quantity: u16,               // Applies from this reservation ordinal onward (1 = first)
+    pub price: u64,                      // Price in payment mint base units
+}
+
+impl QuantityPrice {
+    pub const SIZE: usize = 2 + 8;
+}
+
+/// One window of a collection's sale schedule (e.g. presale, allowlist, public)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SalePhase {
+    pub start_time: i64,                 // Unix timestamp the phase opens (inclusive)
+    pub end_time: i64,                   // Unix timestamp the phase closes (exclusive)
+    pub allowlist_root: Option<[u8; 32]>, // Merkle root for this phase (None = collection root applies)
+    pub price: Option<u64>,              // Price override (None = collection mint fee)
+    pub supply_cap: u64,                 // Max mints during this phase (0 = unlimited)
+    pub per_wallet_limit: u16,           // Max mints per wallet during this phase (0 = unlimited)
+    pub minted_count: u64,               // Mints made during this phase
+}
+
+impl SalePhase {
+    pub const SIZE: usize =
+        8 +         // start_time
+        8 +         // end_time
+        1 + 32 +    // allowlist_root
+        1 + 8 +     // price
+        8 +         // supply_cap
+        2 +         // per_wallet_limit
+        8;          // minted_count
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now >= self.start_time && now < self.end_time
+    }
+
+    pub fn overlaps(&self, other: &SalePhase) -> bool {
+        self.start_time < other.end_time && other.start_time < self.end_time
+    }
+}
+
+#[account]
+pub struct CollectionState {
+    pub bump: u8,                        // bump for PDA
+    pub tier_id: u16,                    // Tier id used as PDA seed
+    pub collection_mint: Pubkey,         // Collection mint address
+    pub mint_fee: u64,                   // Fee to mint NFT in this collection
+    pub max_supply: u64,                 // Maximum supply (0 = unlimited) = public_supply + admin_mint_limit
+    pub current_reserved_count: u64,     // Current minted count
+    pub admin_mint_limit: u64,           // Admin allocation bucket (0 = admin cannot mint)
+    pub current_admin_mint_count: u64,   // Current admin minted count
+    pub public_supply: u64,              // Public bucket - ignored when max_supply is 0 (unlimited)
+    pub public_minted_count: u64,        // Public mints made against public_supply
+    pub admin_release_date: i64,         // Unused admin allocation can move to public after this (0 = never)
+    pub retired: bool,                   // Retired collections accept no new mints
+    pub allowlist_root: Option<[u8; 32]>, // Merkle root of allowed wallets (None = public)
+    pub sale_phases: Vec<SalePhase>,     // Sale schedule (empty = governed by the sale window only)
+    pub sale_start: i64,                 // Unix timestamp minting opens (0 = falls back to mint_start_date)
+    pub sale_end: i64,                   // Unix timestamp minting closes (0 = no end)
+    pub sale_closed: bool,               // Closed sales permanently freeze supply and prices
+    pub total_revenue: u64,              // Mint fees collected, in payment mint base units
+    pub max_per_wallet: u16,             // Public reservations a wallet may hold in this collection (0 = campaign cap only)
+    pub quantity_prices: Vec<QuantityPrice>, // Quantity price breaks, ascending by min_quantity (empty = none)
+    pub last_serial: u64,                // Serial issued by the latest mint (0 = none yet, serials start at 1)
+    pub serial_in_name: bool,            // Append " #<serial>" to the NFT name at mint
+    pub name_prefix: String,             // NFT name written at mint (before the optional serial suffix)
+    pub symbol: String,                  // NFT symbol written at mint
+    pub base_uri: String,                // Metadata URI - "<base_uri><serial>.json" when it ends in '/' (empty = minting disabled)
+    pub provenance_hash: Option<[u8; 32]>, // Hash committing to the final art order (None = no delayed reveal)
+    pub placeholder_uri: String,         // URI minted NFTs carry until the reveal
+    pub art_count: u64,                  // Number of final artworks the provenance hash covers
+    pub revealed: bool,                  // Whether the final art has been revealed
+    pub reveal_base_uri: String,         // Final art URIs - "<reveal_base_uri><art index>.json"
+    pub reveal_offset: u64,              // Random shift: art index = (serial - 1 + reveal_offset) % art_count
+    pub reveal_slot: u64,                // Committed slot whose hash seeds reveal_offset
+    pub reveal_seeded: bool,             // Whether reveal_offset has been drawn from the committed slot
+    pub migrating_from: Option<Pubkey>,  // Replaced collection mint whose NFTs still need migrating (None = none pending)
+    pub migration_pending: u64,          // Live NFTs still verified under migrating_from
+    pub creators: Vec<CollectionCreator>, // Partner creators written after admin_state (empty = admin_state 100%)
+    pub royalty_bps: u16,                // Seller fee basis points written to NFT metadata
+}
+
+impl CollectionState {
+    pub fn space() -> usize {
+        8 +         // discriminator
+        1 +         // bump
+        2 +         // tier_id
+        32 +        // collection_mint
+        8 +         // mint_fee
+        8 +         // max_supply
+        8 +         // current_reserved_count
+        8 +         // admin_mint_limit
+        8 +         // current_admin_mint_count
+        8 +         // public_supply
+        8 +         // public_minted_count
+        8 +         // admin_release_date
+        1 +         // retired
+        1 + 32 +    // allowlist_root
+        4 + MAX_SALE_PHASES * SalePhase::SIZE + // sale_phases
+        8 +         // sale_start
+        8 +         // sale_end
+        1 +         // sale_closed
+        8 +         // total_revenue
+        2 +         // max_per_wallet
+        4 + MAX_QUANTITY_PRICES * QuantityPrice::SIZE + // quantity_prices
+        8 +         // last_serial
+        1 +         // serial_in_name
+        4 + MAX_NFT_NAME_LENGTH + // name_prefix
+        4 + MAX_NFT_SYMBOL_LENGTH + // symbol
+        4 + MAX_NFT_URI_LENGTH + // base_uri
+        1 + 32 +    // provenance_hash
+        4 + MAX_NFT_URI_LENGTH + // placeholder_uri
+        8 +         // art_count
+        1 +         // revealed
+        4 + MAX_NFT_URI_LENGTH + // reveal_base_uri
+        8 +         // reveal_offset
+        8 +         // reveal_slot
+        1 +         // reveal_seeded
+        1 + 32 +    // migrating_from
+        8 +         // migration_pending
+        4 + MAX_COLLECTION_CREATORS * CollectionCreator::SIZE + // creators
+        2           // royalty_bps
+    }
+
+    /// Public bucket size for a total supply, keeping the admin allocation carved out
+    pub fn public_supply_for(max_supply: u64, admin_mint_limit: u64) -> Option<u64> {
+        if max_supply == 0 {
+            Some(0)
+        } else {
+            max_supply.checked_sub(admin_mint_limit)
+        }
+    }
+
+    /// Quantity price for a wallet's `ordinal`th reservation in this collection, if a break applies
+    pub fn quantity_price(&self, ordinal: u16) -> Option<u64> {
+        self.quantity_prices
+            .iter()
+            .rev()
+            .find(|quantity_price| ordinal >= quantity_price.min_quantity)
+            .map(|quantity_price| quantity_price.price)
+    }
+
+    /// Serial the next mint in this collection will carry
+    pub fn next_serial(&self) -> u64 {
+        self.last_serial.saturating_add(1)
+    }
+
+    /// Name, symbol and URI written for the NFT carrying `serial` - derived on-chain so minters
+    /// cannot choose what appears under the verified creator
+    pub fn nft_metadata(&self, serial: u64) -> Result<(String, String, String)> {
+        require!(!self.base_uri.is_empty(), ProgramErrorCode::CollectionMetadataNotSet);
+
+        let name = if self.serial_in_name {
+            format!("{} #{:04}", self.name_prefix, serial)
+        } else {
+            self.name_prefix.clone()
+        };
+        require!(name.len() <= MAX_NFT_NAME_LENGTH, ProgramErrorCode::NameTooLong);
+
+        // Serials past the committed art would share artwork once revealed
+        require!(
+            self.provenance_hash.is_none() || serial <= self.art_count,
+            ProgramErrorCode::ArtSupplyExhausted
+        );
+
+        let uri = if self.revealed {
+            self.revealed_uri(serial)
+        } else if self.provenance_hash.is_some() {
+            self.placeholder_uri.clone()
+        } else if self.base_uri.ends_with('/') {
+            format!("{}{}.json", self.base_uri, serial)
+        } else {
+            self.base_uri.clone()
+        };
+
+        Ok((name, self.symbol.clone(), uri))
+    }
+
+    /// Final art URI for `serial` once the collection is revealed
+    pub fn revealed_uri(&self, serial: u64) -> String {
+        let art_index = (serial.saturating_sub(1) % self.art_count + self.reveal_offset % self.art_count) % self.art_count;
+        format!("{}{}.json", self.reveal_base_uri, art_index)
+    }
+
+    /// Check a name prefix, symbol and base URI fit the account and Metaplex limits
+    pub fn validate_metadata_config(name_prefix: &str, symbol: &str, base_uri: &str) -> Result<()> {
+        require!(
+            !name_prefix.is_empty() && name_prefix.len() <= MAX_NFT_NAME_LENGTH,
+            ProgramErrorCode::InvalidMetadataConfig
+        );
+        require!(symbol.len() <= MAX_NFT_SYMBOL_LENGTH, ProgramErrorCode::InvalidMetadataConfig);
+        let max_base_uri_length = if base_uri.ends_with('/') {
+            MAX_NFT_URI_LENGTH - SERIAL_URI_SUFFIX_LENGTH
+        } else {
+            MAX_NFT_URI_LENGTH
+        };
+        require!(
+            !base_uri.is_empty() && base_uri.len() <= max_base_uri_length,
+            ProgramErrorCode::InvalidMetadataConfig
+        );
+        Ok(())
+    }
+
+    /// Check partner creators and royalty fit Metaplex limits - admin_state may not be listed itself
+    pub fn validate_royalties(creators: &[CollectionCreator], royalty_bps: u16, admin_state: Pubkey) -> Result<()> {
+        require!(royalty_bps <= MAX_ROYALTY_BPS, ProgramErrorCode::InvalidRoyaltyConfig);
+        require!(creators.len() <= MAX_COLLECTION_CREATORS, ProgramErrorCode::InvalidRoyaltyConfig);
+
+        let mut total_share: u16 = 0;
+        for (i, creator) in creators.iter().enumerate() {
+            require!(
+                creator.address != Pubkey::default() && creator.address != admin_state,
+                ProgramErrorCode::InvalidRoyaltyConfig
+            );
+            require!(
+                creators[..i].iter().all(|other| other.address != creator.address),
+                ProgramErrorCode::InvalidRoyaltyConfig
+            );
+            total_share += creator.share as u16;
+        }
+        require!(total_share <= 100, ProgramErrorCode::InvalidRoyaltyConfig);
+        Ok(())
+    }
+
+    /// Share of royalties admin_state keeps after the partner creators
+    pub fn admin_creator_share(&self) -> u8 {
+        let partner_share: u16 = self.creators.iter().map(|creator| creator.share as u16).sum();
+        100u16.saturating_sub(partner_share) as u8
+    }
+
+    /// Index of the phase open at `now`, if any
+    pub fn active_phase_index(&self, now: i64) -> Option<usize> {
+        self.sale_phases.iter().position(|phase| phase.is_active(now))
+    }
+}
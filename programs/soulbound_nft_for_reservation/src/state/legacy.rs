@@ -0,0 +1,60 @@
+//! Layouts written by earlier versions of the program.
+//! Only used to read accounts during migration.
+
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct LegacyCollectionConfig {
+    pub collection_mint: Pubkey,
+    pub mint_fee: u64,
+    pub max_supply: u64,
+    pub current_reserved_count: u64,
+    pub admin_mint_limit: u64,
+    pub current_admin_mint_count: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyAdminState {
+    pub bump: u8,
+    pub super_admin: Pubkey,
+    pub withdraw_wallet: Pubkey,
+    pub payment_mint: Pubkey,
+    pub mint_start_date: i64,
+    pub og_collection: LegacyCollectionConfig,
+    pub regular_collection: LegacyCollectionConfig,
+    pub basic_collection: LegacyCollectionConfig,
+}
+
+impl LegacyAdminState {
+    pub fn space() -> usize {
+        8 +                             // discriminator
+        1 +                             // bump
+        32 +                            // super_admin
+        32 +                            // withdraw_wallet
+        32 +                            // payment_mint
+        8 +                             // mint_start_date
+        3 * (32 + 8 + 8 + 8 + 8 + 8)    // og, regular and basic collection configs
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyUserState {
+    pub user: Pubkey,
+    pub has_minted: bool,
+    pub collection_type: u8,            // 0 = OG, 1 = Regular, 2 = Basic (same as tier id)
+    pub mint_address: Pubkey,
+    pub minted_at: i64,
+    pub bump: u8,
+}
+
+impl LegacyUserState {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // user
+        1 + // has_minted
+        1 + // collection_type (enum)
+        32 + // mint_address
+        8 + // minted_at
+        1 // bump
+    }
+}
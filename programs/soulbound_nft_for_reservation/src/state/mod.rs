@@ -1,5 +1,25 @@
 pub mod admin_state;
+pub mod collection_migration;
+pub mod collection_state;
+pub mod legacy;
+pub mod multisig;
+pub mod operator_quota;
+pub mod pending_change;
+pub mod reservation;
+pub mod role_assignment;
+pub mod serial_record;
 pub mod user_state;
+pub mod wallet_mint_state;
 
 pub use admin_state::*;
+pub use collection_migration::*;
+pub use collection_state::*;
+pub use legacy::*;
+pub use multisig::*;
+pub use operator_quota::*;
+pub use pending_change::*;
+pub use reservation::*;
+pub use role_assignment::*;
+pub use serial_record::*;
 pub use user_state::*;
+pub use wallet_mint_state::*;
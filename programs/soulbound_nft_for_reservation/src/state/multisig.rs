@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ProgramErrorCode;
+use crate::state::{CollectionCreator, QuantityPrice, Role};
+
+/// Maximum number of keys in a multisig signer set
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// On-chain signer set governing an AdminState once enabled
+#[account]
+pub struct Multisig {
+    pub signers: Vec<Pubkey>,            // Keys allowed to propose, approve and execute
+    pub threshold: u8,                   // Approvals required to execute a proposal
+    pub proposal_count: u64,             // Next proposal id (Proposal PDA seed)
+    pub bump: u8,                        // PDA bump
+}
+
+impl Multisig {
+    pub fn space() -> usize {
+        8 + // discriminator
+        4 + 32 * MAX_MULTISIG_SIGNERS + // signers
+        1 + // threshold
+        8 + // proposal_count
+        1 // bump
+    }
+
+    /// Signers must be unique non-default keys and the threshold reachable
+    pub fn validate(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+            ProgramErrorCode::InvalidMultisigConfig
+        );
+        require!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            ProgramErrorCode::InvalidMultisigConfig
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(
+                *signer != Pubkey::default() && !signers[..i].contains(signer),
+                ProgramErrorCode::InvalidMultisigConfig
+            );
+        }
+        Ok(())
+    }
+
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+}
+
+/// Privileged action carried by a proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    UpdateMintFee { tier_id: u16, mint_fee: u64 },
+    UpdateMaxSupply { tier_id: u16, max_supply: u64 },
+    UpdateAdminMintLimit { tier_id: u16, admin_mint_limit: u64 },
+    UpdateCollectionMint { tier_id: u16, collection_mint: Pubkey },
+    UpdateMintStartDate { mint_start_date: i64 },
+    Withdraw { amount: u64 },
+    UpdatePaymentMint { new_payment_mint: Pubkey },
+    UpdateSuperAdmin { new_super_admin: Pubkey },
+    UpdateWithdrawWallet { new_withdraw_wallet: Pubkey },
+    UpdateSigners { signers: Vec<Pubkey>, threshold: u8 },
+    UpdateConfigDelay { config_delay: i64 },
+    HandOffUpdateAuthority { new_update_authority: Pubkey },
+    RetireCollection { tier_id: u16 },
+    CloseSale { tier_id: u16 },
+    UpdateAllowlistRoot { tier_id: u16, allowlist_root: Option<[u8; 32]> },
+    UpdateCollectionMetadata { tier_id: u16, name_prefix: String, symbol: String, base_uri: String },
+    UpdateCollectionRoyalties { tier_id: u16, creators: Vec<CollectionCreator>, royalty_bps: u16 },
+    UpdateGuardian { new_guardian: Pubkey },
+    ReleaseAdminAllocation { tier_id: u16 },
+    UpdateSerialInName { tier_id: u16, serial_in_name: bool },
+    UpdateSaleWindow { tier_id: u16, sale_start: i64, sale_end: i64 },
+    UpdateAdminReleaseDate { tier_id: u16, admin_release_date: i64 },
+    AddSalePhase {
+        tier_id: u16,
+        start_time: i64,
+        end_time: i64,
+        allowlist_root: Option<[u8; 32]>,
+        price: Option<u64>,
+        supply_cap: u64,
+        per_wallet_limit: u16,
+    },
+    UpdateSalePhase {
+        tier_id: u16,
+        phase_index: u8,
+        start_time: i64,
+        end_time: i64,
+        allowlist_root: Option<[u8; 32]>,
+        price: Option<u64>,
+        supply_cap: u64,
+        per_wallet_limit: u16,
+    },
+    UpdateWalletReservationCap { max_reservations_per_wallet: u16 },
+    UpdateWalletLimits { tier_id: u16, max_per_wallet: u16, quantity_prices: Vec<QuantityPrice> },
+    CommitProvenance { tier_id: u16, provenance_hash: [u8; 32], placeholder_uri: String, reveal_slot: u64 },
+    PostponeReveal { tier_id: u16, reveal_slot: u64 },
+    RevealCollection { tier_id: u16, reveal_base_uri: String },
+    AddCollection { tier_id: u16, collection_mint: Pubkey, mint_fee: u64, max_supply: u64, admin_mint_limit: u64 },
+    GrantRole { role: Role, holder: Pubkey },
+    RevokeRole { role: Role, holder: Pubkey },
+    SetOperatorQuota { tier_id: u16, operator: Pubkey, quota: u64 },
+    RevokeOperatorQuota { tier_id: u16, operator: Pubkey },
+    UpdateCollectionNftMetadata { tier_id: u16, name: Option<String>, symbol: Option<String>, uri: Option<String> },
+    SetCollectionNftMutability { tier_id: u16, is_mutable: bool },
+    ApproveCollectionAuthority { tier_id: u16, delegate: Pubkey },
+    RevokeCollectionAuthority { tier_id: u16, delegate: Pubkey },
+    TransferCollectionNft { tier_id: u16, destination_token_account: Pubkey },
+}
+
+impl ProposalAction {
+    /// Largest serialized variant (UpdateSigners with a full signer set)
+    pub const MAX_SIZE: usize = 1 + 4 + 32 * MAX_MULTISIG_SIGNERS + 1;
+
+    /// Actions that must wait out AdminState.config_delay after the proposal is created
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            ProposalAction::UpdateMintFee { .. }
+                | ProposalAction::UpdateMaxSupply { .. }
+                | ProposalAction::UpdateAdminMintLimit { .. }
+                | ProposalAction::ReleaseAdminAllocation { .. }
+                | ProposalAction::UpdateMintStartDate { .. }
+                | ProposalAction::UpdateCollectionMint { .. }
+                | ProposalAction::UpdatePaymentMint { .. }
+                | ProposalAction::UpdateConfigDelay { .. }
+                | ProposalAction::HandOffUpdateAuthority { .. }
+                | ProposalAction::AddSalePhase { .. }
+                | ProposalAction::UpdateSalePhase { .. }
+                | ProposalAction::UpdateWalletLimits { .. }
+                | ProposalAction::UpdateAllowlistRoot { .. }
+                | ProposalAction::UpdateSaleWindow { .. }
+        )
+    }
+}
+
+/// A privileged action awaiting multisig approval
+#[account]
+pub struct Proposal {
+    pub id: u64,                         // Proposal id (PDA seed)
+    pub proposer: Pubkey,                // Signer who created the proposal
+    pub action: ProposalAction,          // Action applied on execution
+    pub approvals: Vec<Pubkey>,          // Signers who approved (proposer included)
+    pub executed: bool,                  // Whether the action has been applied
+    pub created_at: i64,                 // Timestamp when the proposal was created
+    pub bump: u8,                        // PDA bump
+}
+
+impl Proposal {
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // id
+        32 + // proposer
+        ProposalAction::MAX_SIZE + // action
+        4 + 32 * MAX_MULTISIG_SIGNERS + // approvals
+        1 + // executed
+        8 + // created_at
+        1 // bump
+    }
+
+    /// Approvals from keys still in the signer set - removed signers no longer count
+    pub fn approval_count(&self, multisig: &Multisig) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approver| multisig.is_signer(approver))
+            .count()
+    }
+}
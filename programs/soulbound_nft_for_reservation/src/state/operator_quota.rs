@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// Airdrop allowance of one operator for one collection tier
+#[account]
+pub struct OperatorQuota {
+    pub operator: Pubkey,                // Airdrop operator key
+    pub tier_id: u16,                    // Tier id of the collection
+    pub quota: u64,                      // Total admin mints this operator may make in the tier
+    pub minted_count: u64,               // Admin mints made by this operator in the tier
+    pub revoked: bool,                   // Revoked operators cannot mint regardless of quota
+    pub bump: u8,                        // PDA bump
+}
+
+impl OperatorQuota {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // operator
+        2 + // tier_id
+        8 + // quota
+        8 + // minted_count
+        1 + // revoked
+        1 // bump
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.quota.saturating_sub(self.minted_count)
+    }
+}
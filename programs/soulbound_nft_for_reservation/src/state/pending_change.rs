@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::state::QuantityPrice;
+
+/// Configuration change that must wait out AdminState.config_delay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ConfigChange {
+    UpdateMintFee { tier_id: u16, mint_fee: u64 },
+    UpdateMaxSupply { tier_id: u16, max_supply: u64 },
+    UpdateMintStartDate { mint_start_date: i64 },
+    UpdateCollectionMint { tier_id: u16, collection_mint: Pubkey },
+    UpdatePaymentMint { new_payment_mint: Pubkey },
+    UpdateConfigDelay { config_delay: i64 },
+    HandOffUpdateAuthority { new_update_authority: Pubkey },
+    UpdateAdminMintLimit { tier_id: u16, admin_mint_limit: u64 },
+    ReleaseAdminAllocation { tier_id: u16 },
+    UpdateAllowlistRoot { tier_id: u16, allowlist_root: Option<[u8; 32]> },
+    UpdateSaleWindow { tier_id: u16, sale_start: i64, sale_end: i64 },
+    AddSalePhase {
+        tier_id: u16,
+        start_time: i64,
+        end_time: i64,
+        allowlist_root: Option<[u8; 32]>,
+        price: Option<u64>,
+        supply_cap: u64,
+        per_wallet_limit: u16,
+    },
+    UpdateSalePhase {
+        tier_id: u16,
+        phase_index: u8,
+        start_time: i64,
+        end_time: i64,
+        allowlist_root: Option<[u8; 32]>,
+        price: Option<u64>,
+        supply_cap: u64,
+        per_wallet_limit: u16,
+    },
+    UpdateWalletLimits { tier_id: u16, max_per_wallet: u16, quantity_prices: Vec<QuantityPrice> },
+}
+
+impl ConfigChange {
+    /// Largest serialized variant (UpdateSalePhase)
+    pub const MAX_SIZE: usize = 1 + 2 + 1 + 8 + 8 + 33 + 9 + 8 + 2;
+}
+
+/// A queued configuration change - executable by anyone once executable_at has passed
+#[account]
+pub struct PendingChange {
+    pub id: u64,                         // Change id (PDA seed)
+    pub change: ConfigChange,            // Change applied on execution
+    pub queued_by: Pubkey,               // Signer who queued the change and paid its rent
+    pub queued_at: i64,                  // Timestamp when the change was queued
+    pub executable_at: i64,              // Earliest execution time
+    pub bump: u8,                        // PDA bump
+}
+
+impl PendingChange {
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // id
+        ConfigChange::MAX_SIZE + // change
+        32 + // queued_by
+        8 + // queued_at
+        8 + // executable_at
+        1 // bump
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::MintSource;
+
+/// One reserved NFT - a wallet holds as many as the campaign and collection caps allow
+#[account]
+pub struct Reservation {
+    pub wallet: Pubkey,                  // Wallet holding the reservation
+    pub tier_id: u16,                    // Tier id of the collection the NFT was minted in
+    pub mint_address: Pubkey,            // Address of the reserved NFT (PDA seed)
+    pub minted_at: i64,                  // Timestamp when NFT was minted
+    pub source: MintSource,              // Public or admin mint
+    pub amount_paid: u64,                // Amount paid in payment_mint base units (0 for admin mints)
+    pub payment_mint: Pubkey,            // Payment mint used (default for admin mints)
+    pub operator: Option<Pubkey>,        // Airdrop operator that minted (None for super admin and public mints)
+    pub collection_mint: Pubkey,         // Collection mint of the tier at mint time
+    pub serial: u64,                     // Serial within the collection (0 = minted before serials)
+    pub bump: u8,                        // PDA bump
+}
+
+impl Reservation {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // wallet
+        2 + // tier_id
+        32 + // mint_address
+        8 + // minted_at
+        1 + // source
+        8 + // amount_paid
+        32 + // payment_mint
+        1 + 32 + // operator
+        32 + // collection_mint
+        8 + // serial
+        1 // bump
+    }
+}
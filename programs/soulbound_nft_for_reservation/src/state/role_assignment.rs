@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::state::AdminState;
+
+/// Least-privilege roles the super_admin can delegate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    FeeManager,       // update_mint_fee / update_max_supply
+    MetadataOperator, // update_nft_metadata
+    AirdropOperator,  // admin_mint_nft
+    Treasurer,        // withdraw / withdraw_all
+}
+
+impl Role {
+    pub fn seed(&self) -> [u8; 1] {
+        [*self as u8]
+    }
+}
+
+/// Grants one role to one holder within a campaign
+#[account]
+pub struct RoleAssignment {
+    pub role: Role,                      // Granted role
+    pub holder: Pubkey,                  // Key allowed to act in this role
+    pub granted_at: i64,                 // Timestamp when the role was granted
+    pub bump: u8,                        // PDA bump
+}
+
+impl RoleAssignment {
+    pub fn space() -> usize {
+        8 + // discriminator
+        1 + // role
+        32 + // holder
+        8 + // granted_at
+        1 // bump
+    }
+
+    /// The super_admin always passes; anyone else needs a matching assignment
+    pub fn authorizes(
+        admin_state: &AdminState,
+        assignment: Option<&RoleAssignment>,
+        signer: Pubkey,
+        role: Role,
+    ) -> bool {
+        signer == admin_state.super_admin
+            || assignment.is_some_and(|assignment| assignment.role == role && assignment.holder == signer)
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Registry entry for a collection serial - created at mint and kept (marked burned) after a burn
+/// so serials are never reissued
+#[account]
+pub struct SerialRecord {
+    pub tier_id: u16,                    // Tier id of the collection that issued the serial
+    pub serial: u64,                     // Serial within the collection (starts at 1, PDA seed)
+    pub mint_address: Pubkey,            // NFT that carries the serial
+    pub wallet: Pubkey,                  // Wallet the NFT was minted to
+    pub burned: bool,                    // Whether the NFT has been burned
+    pub revealed: bool,                  // Whether the NFT's metadata points at its final art
+    pub bump: u8,                        // PDA bump
+}
+
+impl SerialRecord {
+    pub fn space() -> usize {
+        8 + // discriminator
+        2 + // tier_id
+        8 + // serial
+        32 + // mint_address
+        32 + // wallet
+        1 + // burned
+        1 + // revealed
+        1 // bump
+    }
+}
@@ -1,14 +1,30 @@
 use anchor_lang::prelude::*;
-use super::CollectionType;
 
+/// How the NFT recorded in a UserState was minted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MintSource {
+    Legacy,                              // Minted before provenance was recorded (migrated account)
+    Public,                              // Paid mint through mint_nft
+    Admin,                               // Airdrop through admin_mint_nft
+}
+
+/// Per-wallet reservation ledger for a campaign - the mint fields describe the latest reservation,
+/// each reservation is also recorded in its own Reservation PDA
 #[account]
 pub struct UserState {
     pub user: Pubkey,                    // User's wallet address
-    pub has_minted: bool,                // Whether user has minted an NFT
-    pub collection_type: CollectionType, // Type of NFT minted
+    pub has_minted: bool,                // Whether user holds at least one reservation
+    pub tier_id: u16,                    // Tier id of the collection the NFT was minted in
     pub mint_address: Pubkey,            // Address of the minted NFT
     pub minted_at: i64,                  // Timestamp when NFT was minted
     pub bump: u8,                        // PDA bump
+    pub source: MintSource,              // Public or admin mint
+    pub amount_paid: u64,                // Amount paid in payment_mint base units (0 for admin mints)
+    pub payment_mint: Pubkey,            // Payment mint used (default for admin mints)
+    pub operator: Option<Pubkey>,        // Airdrop operator that minted (None for super admin and public mints)
+    pub collection_mint: Pubkey,         // Collection mint of the tier at mint time
+    pub reservation_count: u16,          // Reservations currently held across all tiers
+    pub serial: u64,                     // Collection serial of the latest reservation (0 = minted before serials)
 }
 
 impl UserState {
@@ -16,9 +32,16 @@ impl UserState {
         8 + // discriminator
         32 + // user
         1 + // has_minted
-        1 + // collection_type (enum)
+        2 + // tier_id
         32 + // mint_address
         8 + // minted_at
-        1 // bump
+        1 + // bump
+        1 + // source
+        8 + // amount_paid
+        32 + // payment_mint
+        1 + 32 + // operator
+        32 + // collection_mint
+        2 + // reservation_count
+        8 // serial
     }
 }
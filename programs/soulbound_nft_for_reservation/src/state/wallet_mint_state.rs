@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::MAX_SALE_PHASES;
+
+/// Per-wallet mint counters for one collection, used to enforce sale phase and collection wallet limits
+#[account]
+pub struct WalletMintState {
+    pub wallet: Pubkey,                          // Wallet address
+    pub tier_id: u16,                            // Tier id of the collection
+    pub phase_mints: [u16; MAX_SALE_PHASES],     // Mints made by this wallet in each sale phase
+    pub bump: u8,                                // PDA bump
+    pub reserved_count: u16,                     // Public reservations currently held in this collection
+}
+
+impl WalletMintState {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // wallet
+        2 + // tier_id
+        2 * MAX_SALE_PHASES + // phase_mints
+        1 + // bump
+        2 // reserved_count
+    }
+}
@@ -0,0 +1,76 @@
+import { PublicKey } from "@solana/web3.js";
+import { keccak_256 } from "@noble/hashes/sha3";
+import * as anchor from "@coral-xyz/anchor";
+import * as fs from "fs";
+
+// ============================================================
+// CONFIGURATION - Modify these values before running
+// ============================================================
+
+// Presale export produced by query_presale_purchasers.ts
+const INPUT_CSV = "presale_purchasers.csv";
+
+// Output file with the Merkle root and one proof per wallet
+const OUTPUT_JSON = "allowlist.json";
+
+// Price encoded in every leaf (0 = collection mint fee applies)
+const LEAF_PRICE = 0;
+
+// ============================================================
+// SCRIPT
+// ============================================================
+
+// Leaf hash matching the program: keccak256(wallet || price as u64 LE)
+function leafHash(wallet: PublicKey, price: anchor.BN): Buffer {
+  return Buffer.from(keccak_256(Buffer.concat([wallet.toBuffer(), price.toArrayLike(Buffer, "le", 8)])));
+}
+
+// Sibling pairs are hashed in sorted order, matching allowlist::verify on-chain
+function hashPair(a: Buffer, b: Buffer): Buffer {
+  return Buffer.from(keccak_256(Buffer.compare(a, b) <= 0 ? Buffer.concat([a, b]) : Buffer.concat([b, a])));
+}
+
+function main() {
+  const rows = fs.readFileSync(INPUT_CSV, "utf-8").trim().split("\n").slice(1);
+  const wallets = Array.from(new Set(rows.map((row) => row.split(",")[0].trim()))).map(
+    (address) => new PublicKey(address)
+  );
+  if (wallets.length === 0) {
+    console.error("❌ No wallets found in", INPUT_CSV);
+    process.exit(1);
+  }
+  console.log(`Building allowlist for ${wallets.length} unique wallets from ${INPUT_CSV}`);
+
+  const price = new anchor.BN(LEAF_PRICE);
+  const layers: Buffer[][] = [wallets.map((wallet) => leafHash(wallet, price))];
+  while (layers[layers.length - 1].length > 1) {
+    const current = layers[layers.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < current.length; i += 2) {
+      // An odd node is promoted unchanged to the next layer
+      next.push(i + 1 < current.length ? hashPair(current[i], current[i + 1]) : current[i]);
+    }
+    layers.push(next);
+  }
+  const root = layers[layers.length - 1][0];
+
+  const proofs: Record<string, { price: number; proof: string[] }> = {};
+  wallets.forEach((wallet, leafIndex) => {
+    let index = leafIndex;
+    const proof: string[] = [];
+    for (const layer of layers.slice(0, -1)) {
+      const sibling = index % 2 === 0 ? index + 1 : index - 1;
+      if (sibling < layer.length) proof.push(layer[sibling].toString("hex"));
+      index = Math.floor(index / 2);
+    }
+    proofs[wallet.toBase58()] = { price: LEAF_PRICE, proof };
+  });
+
+  fs.writeFileSync(OUTPUT_JSON, JSON.stringify({ root: root.toString("hex"), proofs }, null, 2));
+
+  console.log("✅ Allowlist written to", OUTPUT_JSON);
+  console.log("Merkle root:", root.toString("hex"));
+  console.log("Set it on-chain with update_allowlist_root for the OG tier");
+}
+
+main();
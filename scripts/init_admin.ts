@@ -19,6 +19,15 @@ const WITHDRAW_WALLET = new PublicKey("BUj6XyFHkoPnfFkGiD32euAiFEjS8kLdBuqHqSST1
 // Program parameters
 const PAYMENT_DECIMALS = 6; // USDC has 6 decimals
 
+// Campaign id - 0 is the default campaign (empty PDA seed, legacy addresses)
+const CAMPAIGN_ID = 0;
+const CAMPAIGN_SEED = CAMPAIGN_ID === 0 ? Buffer.alloc(0) : new anchor.BN(CAMPAIGN_ID).toArrayLike(Buffer, "le", 8);
+
+// Collection tier ids (CollectionState PDA seeds)
+const OG_TIER_ID = 0;
+const REGULAR_TIER_ID = 1;
+const BASIC_TIER_ID = 2;
+
 // OG Collection parameters
 const OG_COLLECTION_MINT = Keypair.generate().publicKey;
 const OG_MINT_FEE = 5_000_000; // 5 USDC (5 * 10^6)
@@ -95,17 +104,24 @@ async function main() {
 
   // Derive PDAs
   const [adminState] = PublicKey.findProgramAddressSync(
-    [Buffer.from("admin_state")],
+    [Buffer.from("admin_state"), CAMPAIGN_SEED],
     programId
   );
   console.log("Admin State PDA:", adminState.toBase58());
 
   const [vault] = PublicKey.findProgramAddressSync(
-    [Buffer.from("vault"), PAYMENT_MINT.toBuffer()],
+    [Buffer.from("vault"), CAMPAIGN_SEED, PAYMENT_MINT.toBuffer()],
     programId
   );
   console.log("Vault PDA:", vault.toBase58());
 
+  // init_admin must be approved by the program's upgrade authority (this wallet)
+  const [programData] = PublicKey.findProgramAddressSync(
+    [programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+  console.log("Program Data:", programData.toBase58());
+
   // Check if admin state already exists
   try {
     const existingState = await program.account.adminState.fetch(adminState);
@@ -114,27 +130,6 @@ async function main() {
     console.log("Withdraw wallet:", existingState.withdrawWallet.toBase58());
     console.log("Payment mint:", existingState.paymentMint.toBase58());
     console.log("Mint start date:", existingState.mintStartDate.toString());
-    console.log("\nOG Collection:");
-    console.log("  - Mint:", existingState.ogCollection.collectionMint.toBase58());
-    console.log("  - Fee:", existingState.ogCollection.mintFee.toString());
-    console.log("  - Max Supply:", existingState.ogCollection.maxSupply.toString());
-    console.log("  - Admin Mint Limit:", existingState.ogCollection.adminMintLimit.toString());
-    console.log("  - Current Reserved Count:", existingState.ogCollection.currentReservedCount.toString());
-    console.log("  - Current Admin Mint Count:", existingState.ogCollection.currentAdminMintCount.toString());
-    console.log("\nRegular Collection:");
-    console.log("  - Mint:", existingState.regularCollection.collectionMint.toBase58());
-    console.log("  - Fee:", existingState.regularCollection.mintFee.toString());
-    console.log("  - Max Supply:", existingState.regularCollection.maxSupply.toString());
-    console.log("  - Admin Mint Limit:", existingState.regularCollection.adminMintLimit.toString());
-    console.log("  - Current Reserved Count:", existingState.regularCollection.currentReservedCount.toString());
-    console.log("  - Current Admin Mint Count:", existingState.regularCollection.currentAdminMintCount.toString());
-    console.log("\nBasic Collection:");
-    console.log("  - Mint:", existingState.basicCollection.collectionMint.toBase58());
-    console.log("  - Fee:", existingState.basicCollection.mintFee.toString());
-    console.log("  - Max Supply:", existingState.basicCollection.maxSupply.toString());
-    console.log("  - Admin Mint Limit:", existingState.basicCollection.adminMintLimit.toString());
-    console.log("  - Current Reserved Count:", existingState.basicCollection.currentReservedCount.toString());
-    console.log("  - Current Admin Mint Count:", existingState.basicCollection.currentAdminMintCount.toString());
     return;
   } catch (e) {
     // Account doesn't exist, continue with initialization
@@ -166,24 +161,17 @@ async function main() {
   try {
     const tx = await program.methods
       .initAdmin(
-        OG_COLLECTION_MINT,
-        new anchor.BN(OG_MINT_FEE),
-        new anchor.BN(OG_MAX_SUPPLY),
-        new anchor.BN(OG_ADMIN_MINT_LIMIT),
-        REGULAR_COLLECTION_MINT,
-        new anchor.BN(REGULAR_MINT_FEE),
-        new anchor.BN(REGULAR_MAX_SUPPLY),
-        new anchor.BN(REGULAR_ADMIN_MINT_LIMIT),
-        BASIC_COLLECTION_MINT,
-        new anchor.BN(BASIC_MINT_FEE),
-        new anchor.BN(BASIC_MAX_SUPPLY),
-        new anchor.BN(BASIC_ADMIN_MINT_LIMIT),
+        new anchor.BN(CAMPAIGN_ID),
         WITHDRAW_WALLET,
         new anchor.BN(MINT_START_DATE)
       )
       .accounts({
         superAdmin: wallet.publicKey,
+        upgradeAuthority: wallet.publicKey,
+        programData,
+        adminState,
         paymentMint: PAYMENT_MINT,
+        vault,
         paymentTokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc({ skipPreflight: true });
@@ -202,29 +190,43 @@ async function main() {
     console.log("Payment mint:", state.paymentMint.toBase58());
     console.log("Mint start date:", state.mintStartDate.toString());
     
-    console.log("\nOG Collection:");
-    console.log("  - Mint:", state.ogCollection.collectionMint.toBase58());
-    console.log("  - Fee:", state.ogCollection.mintFee.toString(), `(${state.ogCollection.mintFee.toNumber() / 10 ** PAYMENT_DECIMALS} USDC)`);
-    console.log("  - Max Supply:", state.ogCollection.maxSupply.toString());
-    console.log("  - Admin Mint Limit:", state.ogCollection.adminMintLimit.toString());
-    console.log("  - Current Reserved Count:", state.ogCollection.currentReservedCount.toString());
-    console.log("  - Current Admin Mint Count:", state.ogCollection.currentAdminMintCount.toString());
-
-    console.log("\nRegular Collection:");
-    console.log("  - Mint:", state.regularCollection.collectionMint.toBase58());
-    console.log("  - Fee:", state.regularCollection.mintFee.toString(), `(${state.regularCollection.mintFee.toNumber() / 10 ** PAYMENT_DECIMALS} USDC)`);
-    console.log("  - Max Supply:", state.regularCollection.maxSupply.toString());
-    console.log("  - Admin Mint Limit:", state.regularCollection.adminMintLimit.toString());
-    console.log("  - Current Reserved Count:", state.regularCollection.currentReservedCount.toString());
-    console.log("  - Current Admin Mint Count:", state.regularCollection.currentAdminMintCount.toString());
-
-    console.log("\nBasic Collection:");
-    console.log("  - Mint:", state.basicCollection.collectionMint.toBase58());
-    console.log("  - Fee:", state.basicCollection.mintFee.toString(), `(${state.basicCollection.mintFee.toNumber() / 10 ** PAYMENT_DECIMALS} USDC)`);
-    console.log("  - Max Supply:", state.basicCollection.maxSupply.toString());
-    console.log("  - Admin Mint Limit:", state.basicCollection.adminMintLimit.toString());
-    console.log("  - Current Reserved Count:", state.basicCollection.currentReservedCount.toString());
-    console.log("  - Current Admin Mint Count:", state.basicCollection.currentAdminMintCount.toString());
+    // Register the three collection tiers
+    const collections = [
+      { name: "OG", tierId: OG_TIER_ID, mint: OG_COLLECTION_MINT, fee: OG_MINT_FEE, maxSupply: OG_MAX_SUPPLY, adminMintLimit: OG_ADMIN_MINT_LIMIT },
+      { name: "Regular", tierId: REGULAR_TIER_ID, mint: REGULAR_COLLECTION_MINT, fee: REGULAR_MINT_FEE, maxSupply: REGULAR_MAX_SUPPLY, adminMintLimit: REGULAR_ADMIN_MINT_LIMIT },
+      { name: "Basic", tierId: BASIC_TIER_ID, mint: BASIC_COLLECTION_MINT, fee: BASIC_MINT_FEE, maxSupply: BASIC_MAX_SUPPLY, adminMintLimit: BASIC_ADMIN_MINT_LIMIT },
+    ];
+    for (const collection of collections) {
+      const tierSeed = Buffer.alloc(2);
+      tierSeed.writeUInt16LE(collection.tierId);
+      const [collectionStatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("collection_state"), CAMPAIGN_SEED, tierSeed],
+        programId
+      );
+
+      const addTx = await program.methods
+        .addCollection(
+          collection.tierId,
+          collection.mint,
+          new anchor.BN(collection.fee),
+          new anchor.BN(collection.maxSupply),
+          new anchor.BN(collection.adminMintLimit)
+        )
+        .accounts({
+          superAdmin: wallet.publicKey,
+          adminState,
+          collectionState: collectionStatePda,
+        })
+        .rpc({ skipPreflight: true });
+      console.log(`✅ ${collection.name} collection added (tier ${collection.tierId}):`, addTx);
+
+      const collectionState = await program.account.collectionState.fetch(collectionStatePda);
+      console.log(`\n${collection.name} Collection:`);
+      console.log("  - Mint:", collectionState.collectionMint.toBase58());
+      console.log("  - Fee:", collectionState.mintFee.toString(), `(${collectionState.mintFee.toNumber() / 10 ** PAYMENT_DECIMALS} USDC)`);
+      console.log("  - Max Supply:", collectionState.maxSupply.toString());
+      console.log("  - Admin Mint Limit:", collectionState.adminMintLimit.toString());
+    }
 
   } catch (error: any) {
     console.error("\n❌ Transaction failed!");
@@ -217,7 +217,7 @@ async function main() {
     console.log("\n⏳ Minting NFT...");
     
     const tx = await program.methods
-      .mintNft(collectionType, nftName, nftSymbol, nftUri)
+      .mintNft(collectionType, nftName, nftSymbol, nftUri, null)
       .accounts({
         signer: wallet.publicKey,
         tokenAccount: nftTokenAccount,
@@ -119,7 +119,7 @@ async function main() {
     const tx = await program.methods
       .updateMintFee(collectionType, new anchor.BN(mintFee))
       .accounts({
-        superAdmin: wallet.publicKey,
+        authority: wallet.publicKey,
       })
       .signers([wallet.payer])
       .rpc();
@@ -93,7 +93,7 @@ async function main() {
     const tx = await program.methods
       .updateNftMetadata(newName, newSymbol, newUri)
       .accounts({
-        superAdmin: wallet.publicKey,
+        authority: wallet.publicKey,
         mint: nftMint,
         metadataAccount: metadataAccount,
       })
@@ -13,6 +13,10 @@ import {
   BASIC_MAX_SUPPLY,
   BASIC_ADMIN_MINT_LIMIT,
   MINT_START_DATE,
+  DEFAULT_CAMPAIGN_ID,
+  OG_TIER_ID,
+  REGULAR_TIER_ID,
+  BASIC_TIER_ID,
   assertAdminState,
 } from "./setup";
 import { PublicKey, Keypair } from "@solana/web3.js";
@@ -38,23 +42,10 @@ describe("init_admin", () => {
 
     // Initialize admin
     const tx = await testContext.program.methods
-      .initAdmin(
-        testContext.ogCollectionMint,
-        OG_MINT_FEE,
-        OG_MAX_SUPPLY,
-        OG_ADMIN_MINT_LIMIT,
-        testContext.regularCollectionMint,
-        REGULAR_MINT_FEE,
-        REGULAR_MAX_SUPPLY,
-        REGULAR_ADMIN_MINT_LIMIT,
-        testContext.basicCollectionMint,
-        BASIC_MINT_FEE,
-        BASIC_MAX_SUPPLY,
-        BASIC_ADMIN_MINT_LIMIT,
-        testContext.withdrawWallet.publicKey,
-        MINT_START_DATE
-      )
+      .initAdmin(DEFAULT_CAMPAIGN_ID, testContext.withdrawWallet.publicKey, MINT_START_DATE)
       .accounts({
+        upgradeAuthority: testContext.provider.wallet.publicKey,
+        programData: testContext.programDataPda,
         superAdmin: testContext.admin.publicKey,
         adminState: testContext.adminStatePda,
         paymentMint: testContext.usdcMint,
@@ -66,6 +57,25 @@ describe("init_admin", () => {
       .signers([testContext.admin])
       .rpc();
 
+    // Register the three collection tiers
+    const collections: [number, PublicKey, BN, BN, BN][] = [
+      [OG_TIER_ID, testContext.ogCollectionMint!, OG_MINT_FEE, OG_MAX_SUPPLY, OG_ADMIN_MINT_LIMIT],
+      [REGULAR_TIER_ID, testContext.regularCollectionMint!, REGULAR_MINT_FEE, REGULAR_MAX_SUPPLY, REGULAR_ADMIN_MINT_LIMIT],
+      [BASIC_TIER_ID, testContext.basicCollectionMint!, BASIC_MINT_FEE, BASIC_MAX_SUPPLY, BASIC_ADMIN_MINT_LIMIT],
+    ];
+    for (const [tierId, collectionMint, mintFee, maxSupply, adminMintLimit] of collections) {
+      await testContext.program.methods
+        .addCollection(tierId, collectionMint, mintFee, maxSupply, adminMintLimit)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(tierId)[0],
+        })
+        .signers([testContext.admin])
+        .rpc();
+      await testContext.setCollectionMetadata(tierId);
+    }
+
     // Verify transaction succeeded
     expect(tx).to.be.a("string");
     testContext.adminInitialized = true;
@@ -118,4 +128,159 @@ describe("init_admin", () => {
     const adminState = await testContext.fetchAdminState();
     expect(adminState.paymentMint.toString()).to.equal(testContext.usdcMint.toString());
   });
-});
\ No newline at end of file
+
+  it("should reject adding a collection tier that already exists", async () => {
+    try {
+      await testContext.program.methods
+        .addCollection(OG_TIER_ID, Keypair.generate().publicKey, OG_MINT_FEE, OG_MAX_SUPPLY, OG_ADMIN_MINT_LIMIT)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+        })
+        .signers([testContext.admin])
+        .rpc();
+      expect.fail("Expected adding an existing tier to fail");
+    } catch (error: any) {
+      expect(error.message).to.include("already in use");
+    }
+  });
+
+  it("should add and retire a new collection tier", async () => {
+    const foundersTierId = 10;
+    const foundersCollectionMint = Keypair.generate().publicKey;
+
+    await testContext.program.methods
+      .addCollection(foundersTierId, foundersCollectionMint, OG_MINT_FEE, new BN(10), new BN(0))
+      .accounts({
+        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(foundersTierId)[0],
+      })
+      .signers([testContext.admin])
+      .rpc();
+
+    let foundersState = await testContext.fetchCollectionState(foundersTierId);
+    expect(foundersState.tierId).to.equal(foundersTierId);
+    expect(foundersState.collectionMint.toString()).to.equal(foundersCollectionMint.toString());
+    expect(foundersState.retired).to.be.false;
+
+    await testContext.program.methods
+      .retireCollection(foundersTierId)
+      .accounts({
+        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(foundersTierId)[0],
+      })
+      .signers([testContext.admin])
+      .rpc();
+
+    foundersState = await testContext.fetchCollectionState(foundersTierId);
+    expect(foundersState.retired).to.be.true;
+  });
+
+  it("should prevent non-admin from adding a collection tier", async () => {
+    try {
+      await testContext.program.methods
+        .addCollection(11, Keypair.generate().publicKey, OG_MINT_FEE, new BN(10), new BN(0))
+        .accounts({
+          superAdmin: testContext.user1.keypair.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(11)[0],
+        })
+        .signers([testContext.user1.keypair])
+        .rpc();
+      expect.fail("Expected non-admin add_collection to fail");
+    } catch (error: any) {
+      expect(error.message).to.include("Unauthorized");
+    }
+  });
+
+  it("should reject init_admin not approved by the upgrade authority", async () => {
+    const campaignId = new BN(37);
+    const attacker = testContext.user1.keypair;
+
+    try {
+      await testContext.program.methods
+        .initAdmin(campaignId, attacker.publicKey, MINT_START_DATE)
+        .accounts({
+          upgradeAuthority: attacker.publicKey,
+          programData: testContext.programDataPda,
+          superAdmin: attacker.publicKey,
+          adminState: testContext.getAdminStatePda(campaignId)[0],
+          paymentMint: testContext.usdcMint,
+          vault: testContext.getVaultPda(testContext.usdcMint, campaignId)[0],
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([attacker])
+        .rpc();
+      expect.fail("Expected init_admin to be rejected without the upgrade authority");
+    } catch (error: any) {
+      expect(error.message).to.include("Unauthorized");
+    }
+  });
+
+  it("should run an independent campaign alongside the default one", async () => {
+    const campaignId = new BN(7);
+    const campaignAdmin = testContext.user2.keypair;
+    const [campaignAdminStatePda] = testContext.getAdminStatePda(campaignId);
+    const [campaignVaultPda] = testContext.getVaultPda(testContext.usdcMint, campaignId);
+    const [campaignOgStatePda] = testContext.getCollectionStatePda(OG_TIER_ID, campaignId);
+
+    expect(campaignAdminStatePda.toBase58()).to.not.equal(testContext.adminStatePda.toBase58());
+    expect(campaignVaultPda.toBase58()).to.not.equal(testContext.vaultPda.toBase58());
+
+    await testContext.program.methods
+      .initAdmin(campaignId, campaignAdmin.publicKey, MINT_START_DATE)
+      .accounts({
+        upgradeAuthority: testContext.provider.wallet.publicKey,
+        programData: testContext.programDataPda,
+        superAdmin: campaignAdmin.publicKey,
+        adminState: campaignAdminStatePda,
+        paymentMint: testContext.usdcMint,
+        vault: campaignVaultPda,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([campaignAdmin])
+      .rpc();
+
+    const campaignAdminState = await testContext.program.account.adminState.fetch(campaignAdminStatePda);
+    expect(campaignAdminState.campaignId.toNumber()).to.equal(7);
+    expect(campaignAdminState.superAdmin.toBase58()).to.equal(campaignAdmin.publicKey.toBase58());
+
+    // The default campaign's super admin has no authority over this campaign
+    try {
+      await testContext.program.methods
+        .addCollection(OG_TIER_ID, Keypair.generate().publicKey, OG_MINT_FEE, OG_MAX_SUPPLY, OG_ADMIN_MINT_LIMIT)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: campaignAdminStatePda,
+          collectionState: campaignOgStatePda,
+        })
+        .signers([testContext.admin])
+        .rpc();
+      expect.fail("Expected default campaign admin to be rejected");
+    } catch (error: any) {
+      expect(error.message).to.include("Unauthorized");
+    }
+
+    // The same tier id can be registered independently in this campaign
+    const campaignCollectionMint = Keypair.generate().publicKey;
+    await testContext.program.methods
+      .addCollection(OG_TIER_ID, campaignCollectionMint, OG_MINT_FEE, OG_MAX_SUPPLY, OG_ADMIN_MINT_LIMIT)
+      .accounts({
+        superAdmin: campaignAdmin.publicKey,
+        adminState: campaignAdminStatePda,
+        collectionState: campaignOgStatePda,
+      })
+      .signers([campaignAdmin])
+      .rpc();
+
+    const campaignOgState = await testContext.program.account.collectionState.fetch(campaignOgStatePda);
+    expect(campaignOgState.collectionMint.toBase58()).to.equal(campaignCollectionMint.toBase58());
+
+    // Default campaign collection is untouched
+    const defaultOgState = await testContext.fetchCollectionState(OG_TIER_ID);
+    expect(defaultOgState.collectionMint.toBase58()).to.equal(testContext.ogCollectionMint!.toBase58());
+  });
+});
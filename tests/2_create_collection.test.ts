@@ -2,7 +2,8 @@ import * as anchor from "@coral-xyz/anchor";
 import { expect } from "chai";
 import { testContext, initializeTestContext, OG_MINT_FEE, OG_MAX_SUPPLY, OG_ADMIN_MINT_LIMIT, REGULAR_MINT_FEE, REGULAR_MAX_SUPPLY, REGULAR_ADMIN_MINT_LIMIT, BASIC_MINT_FEE, BASIC_MAX_SUPPLY, BASIC_ADMIN_MINT_LIMIT, MINT_START_DATE } from "./setup";
 import { Keypair, PublicKey, ComputeBudgetProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID, createAssociatedTokenAccount, getAccount } from "@solana/spl-token";
+import { BN } from "bn.js";
 
 const METAPLEX_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
 
@@ -18,30 +19,7 @@ describe("create_collection_nft", () => {
       const regularCollectionMint = Keypair.generate().publicKey;
       const basicCollectionMint = Keypair.generate().publicKey;
 
-      await testContext.program.methods
-        .initAdmin(
-          ogCollectionMint,
-          OG_MINT_FEE,
-          OG_MAX_SUPPLY,
-          OG_ADMIN_MINT_LIMIT,
-          regularCollectionMint,
-          REGULAR_MINT_FEE,
-          REGULAR_MAX_SUPPLY,
-          REGULAR_ADMIN_MINT_LIMIT,
-          basicCollectionMint,
-          BASIC_MINT_FEE,
-          BASIC_MAX_SUPPLY,
-          BASIC_ADMIN_MINT_LIMIT,
-          testContext.withdrawWallet.publicKey,
-          MINT_START_DATE
-        )
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-          paymentMint: testContext.usdcMint,
-          paymentTokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.initAdmin(ogCollectionMint, regularCollectionMint, basicCollectionMint);
       testContext.adminInitialized = true;
     }
   });
@@ -74,6 +52,7 @@ describe("create_collection_nft", () => {
     await testContext.program.methods
       .createCollectionNft("OG Collection", "OG", "https://example.com/og-metadata.json")
       .accounts({
+        adminState: testContext.adminStatePda,
         signer: testContext.admin.publicKey,
         collectionMint: ogCollectionMint.publicKey,
         collectionTokenAccount: collectionTokenAccount,
@@ -93,4 +72,139 @@ describe("create_collection_nft", () => {
     expect(testContext.ogCollectionMint).to.not.be.undefined;
     expect(testContext.ogCollectionMint?.toString()).to.be.a("string");
   });
-});
+}
+  describe("collection NFT maintenance", () => {
+    const MAINTENANCE_TIER_ID = 31;
+    const SYSVAR_INSTRUCTIONS_PUBKEY = new PublicKey("Sysvar1nstructions1111111111111111111111111");
+    let collectionMint: PublicKey;
+
+    const collectionNftAccounts = () => ({
+      superAdmin: testContext.admin.publicKey,
+      adminState: testContext.adminStatePda,
+      collectionState: testContext.getCollectionStatePda(MAINTENANCE_TIER_ID)[0],
+      collectionMint,
+      collectionMetadata: testContext.getMetadataPdas(collectionMint).metadata,
+      sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    });
+
+    const collectionMetadataData = async (): Promise<string> =>
+      (await testContext.connection.getAccountInfo(testContext.getMetadataPdas(collectionMint).metadata))!.data.toString("utf8");
+
+    before(async () => {
+      collectionMint = await testContext.addMintableCollection(MAINTENANCE_TIER_ID, OG_MINT_FEE, new BN(10), new BN(0));
+    });
+
+    it("should update only the provided collection metadata fields", async () => {
+      await testContext.program.methods
+        .updateCollectionNftMetadata(MAINTENANCE_TIER_ID, null, null, "https://example.com/tier-updated.json")
+        .accounts(collectionNftAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      const data = await collectionMetadataData();
+      expect(data).to.include("https://example.com/tier-updated.json");
+      expect(data).to.include(`Tier ${MAINTENANCE_TIER_ID} Collection`);
+    });
+
+    it("should reject collection NFT maintenance while metadata is paused", async () => {
+      const metadataPause = { mint: false, adminMint: false, burn: false, withdraw: false, metadata: true };
+      await testContext.program.methods
+        .setPauseFlags(metadataPause)
+        .accounts({ adminState: testContext.adminStatePda, authority: testContext.admin.publicKey })
+        .signers([testContext.admin])
+        .rpc();
+
+      try {
+        await testContext.program.methods
+          .updateCollectionNftMetadata(MAINTENANCE_TIER_ID, null, null, "https://example.com/tier-paused.json")
+          .accounts(collectionNftAccounts())
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected collection NFT update to fail while paused");
+      } catch (error: any) {
+        expect(error.toString()).to.include("ProgramPaused");
+      } finally {
+        await testContext.program.methods
+          .clearPauseFlags(metadataPause)
+          .accounts({ adminState: testContext.adminStatePda, superAdmin: testContext.admin.publicKey })
+          .signers([testContext.admin])
+          .rpc();
+      }
+    });
+
+    it("should approve and revoke a collection authority delegate", async () => {
+      const delegate = Keypair.generate().publicKey;
+      const [collectionAuthorityRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), collectionMint.toBuffer(), Buffer.from("collection_authority"), delegate.toBuffer()],
+        METAPLEX_PROGRAM_ID
+      );
+      const delegateAccounts = {
+        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(MAINTENANCE_TIER_ID)[0],
+        collectionMint,
+        collectionMetadata: testContext.getMetadataPdas(collectionMint).metadata,
+        delegate,
+        collectionAuthorityRecord,
+      };
+
+      await testContext.program.methods
+        .approveCollectionAuthority(MAINTENANCE_TIER_ID)
+        .accounts(delegateAccounts)
+        .signers([testContext.admin])
+        .rpc();
+      expect(await testContext.connection.getAccountInfo(collectionAuthorityRecord)).to.not.be.null;
+
+      await testContext.program.methods
+        .revokeCollectionAuthority(MAINTENANCE_TIER_ID)
+        .accounts(delegateAccounts)
+        .signers([testContext.admin])
+        .rpc();
+      expect(await testContext.connection.getAccountInfo(collectionAuthorityRecord)).to.be.null;
+    });
+
+    it("should transfer the collection NFT out of the admin_state ATA", async () => {
+      const recipient = Keypair.generate();
+      const destination = await createAssociatedTokenAccount(
+        testContext.connection,
+        testContext.admin,
+        collectionMint,
+        recipient.publicKey
+      );
+
+      await testContext.program.methods
+        .transferCollectionNft(MAINTENANCE_TIER_ID)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(MAINTENANCE_TIER_ID)[0],
+          collectionMint,
+          collectionTokenAccount: getAssociatedTokenAddressSync(collectionMint, testContext.adminStatePda, true),
+          destinationTokenAccount: destination,
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      expect((await getAccount(testContext.connection, destination)).amount).to.equal(BigInt(1));
+    });
+
+    it("should lock the collection metadata permanently", async () => {
+      await testContext.program.methods
+        .setCollectionNftMutability(MAINTENANCE_TIER_ID, false)
+        .accounts(collectionNftAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      try {
+        await testContext.program.methods
+          .updateCollectionNftMetadata(MAINTENANCE_TIER_ID, "Renamed", null, null)
+          .accounts(collectionNftAccounts())
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected update of locked collection metadata to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("CollectionNftImmutable");
+      }
+    });
+  });
+});
\ No newline at end of file
@@ -13,10 +13,14 @@ import {
   OG_ADMIN_MINT_LIMIT,
   REGULAR_ADMIN_MINT_LIMIT,
   BASIC_ADMIN_MINT_LIMIT,
-  MINT_START_DATE
+  MINT_START_DATE,
+  OG_TIER_ID,
+  REGULAR_TIER_ID,
+  ROLE_FEE_MANAGER,
+  METAPLEX_PROGRAM_ID
 } from "./setup";
-import { Keypair, PublicKey } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, PublicKey, ComputeBudgetProgram } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, createAssociatedTokenAccount, getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { BN } from "bn.js";
 
 describe("update_admin", () => {
@@ -30,30 +34,7 @@ describe("update_admin", () => {
       const regularCollectionMint = Keypair.generate().publicKey;
       const basicCollectionMint = Keypair.generate().publicKey;
 
-      await testContext.program.methods
-        .initAdmin(
-          ogCollectionMint,
-          OG_MINT_FEE,
-          OG_MAX_SUPPLY,
-          OG_ADMIN_MINT_LIMIT,
-          regularCollectionMint,
-          REGULAR_MINT_FEE,
-          REGULAR_MAX_SUPPLY,
-          REGULAR_ADMIN_MINT_LIMIT,
-          basicCollectionMint,
-          BASIC_MINT_FEE,
-          BASIC_MAX_SUPPLY,
-          BASIC_ADMIN_MINT_LIMIT,
-          testContext.withdrawWallet.publicKey,
-          MINT_START_DATE
-        )
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-          paymentMint: testContext.usdcMint,
-          paymentTokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.initAdmin(ogCollectionMint, regularCollectionMint, basicCollectionMint);
         
       testContext.ogCollectionMint = ogCollectionMint;
       testContext.regularCollectionMint = regularCollectionMint;
@@ -67,9 +48,11 @@ describe("update_admin", () => {
       const newMintFee = new anchor.BN(6000000); // 6 USDC for OG
 
       const tx = await testContext.program.methods
-        .updateMintFee({ og: {} }, newMintFee)
+        .updateMintFee(OG_TIER_ID, newMintFee)
         .accounts({
-          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+          authority: testContext.admin.publicKey,
         })
         .signers([testContext.admin])
         .rpc();
@@ -80,9 +63,11 @@ describe("update_admin", () => {
 
       // Reset to original value for other tests
       await testContext.program.methods
-        .updateMintFee({ og: {} }, OG_MINT_FEE)
+        .updateMintFee(OG_TIER_ID, OG_MINT_FEE)
         .accounts({
-          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+          authority: testContext.admin.publicKey,
         })
         .signers([testContext.admin])
         .rpc();
@@ -93,9 +78,11 @@ describe("update_admin", () => {
 
       try {
         await testContext.program.methods
-          .updateMintFee({ og: {} }, invalidMintFee)
+          .updateMintFee(OG_TIER_ID, invalidMintFee)
           .accounts({
-            superAdmin: testContext.admin.publicKey,
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+            authority: testContext.admin.publicKey,
           })
           .signers([testContext.admin])
           .rpc();
@@ -111,9 +98,11 @@ describe("update_admin", () => {
 
       try {
         await testContext.program.methods
-          .updateMintFee({ og: {} }, newMintFee)
+          .updateMintFee(OG_TIER_ID, newMintFee)
           .accounts({
-            superAdmin: testContext.user1.keypair.publicKey, // Non-admin
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+            authority: testContext.user1.keypair.publicKey, // Non-admin
           })
           .signers([testContext.user1.keypair])
           .rpc();
@@ -125,14 +114,75 @@ describe("update_admin", () => {
     });
   });
 
+  describe("role assignments", () => {
+    it("should let a fee manager update the mint fee until the role is revoked", async () => {
+      const feeManager = testContext.user2.keypair;
+      const [roleAssignment] = testContext.getRoleAssignmentPda(ROLE_FEE_MANAGER, feeManager.publicKey);
+      const collectionState = testContext.getCollectionStatePda(OG_TIER_ID)[0];
+
+      await testContext.program.methods
+        .grantRole({ feeManager: {} }, feeManager.publicKey)
+        .accounts({
+          adminState: testContext.adminStatePda,
+          superAdmin: testContext.admin.publicKey,
+          roleAssignment,
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      const assignment = await testContext.program.account.roleAssignment.fetch(roleAssignment);
+      expect(assignment.holder.toString()).to.equal(feeManager.publicKey.toString());
+
+      await testContext.program.methods
+        .updateMintFee(OG_TIER_ID, OG_MINT_FEE)
+        .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState,
+          authority: feeManager.publicKey,
+          roleAssignment,
+        })
+        .signers([feeManager])
+        .rpc();
+
+      await testContext.program.methods
+        .revokeRole({ feeManager: {} }, feeManager.publicKey)
+        .accounts({
+          adminState: testContext.adminStatePda,
+          superAdmin: testContext.admin.publicKey,
+          roleAssignment,
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      try {
+        await testContext.program.methods
+          .updateMintFee(OG_TIER_ID, OG_MINT_FEE)
+          .accounts({
+            adminState: testContext.adminStatePda,
+            collectionState,
+            authority: feeManager.publicKey,
+            roleAssignment: null,
+          })
+          .signers([feeManager])
+          .rpc();
+
+        expect.fail("Expected transaction to fail after the role was revoked");
+      } catch (error: any) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+    });
+  });
+
   describe("update_max_supply", () => {
     it("should update max supply successfully", async () => {
       const newMaxSupply = new anchor.BN(200); // Update OG to 200
 
       const tx = await testContext.program.methods
-        .updateMaxSupply({ og: {} }, newMaxSupply)
+        .updateMaxSupply(OG_TIER_ID, newMaxSupply)
         .accounts({
-          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+          authority: testContext.admin.publicKey,
         })
         .signers([testContext.admin])
         .rpc();
@@ -146,9 +196,11 @@ describe("update_admin", () => {
       const newMaxSupply = new anchor.BN(0); // Unlimited
 
       const tx = await testContext.program.methods
-        .updateMaxSupply({ regular: {} }, newMaxSupply)
+        .updateMaxSupply(REGULAR_TIER_ID, newMaxSupply)
         .accounts({
-          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(REGULAR_TIER_ID)[0],
+          authority: testContext.admin.publicKey,
         })
         .signers([testContext.admin])
         .rpc();
@@ -166,6 +218,7 @@ describe("update_admin", () => {
       const tx = await testContext.program.methods
         .updateMintStartDate(newMintStartDate)
         .accounts({
+          adminState: testContext.adminStatePda,
           superAdmin: testContext.admin.publicKey,
         })
         .signers([testContext.admin])
@@ -181,6 +234,7 @@ describe("update_admin", () => {
       const tx = await testContext.program.methods
         .updateMintStartDate(newMintStartDate)
         .accounts({
+          adminState: testContext.adminStatePda,
           superAdmin: testContext.admin.publicKey,
         })
         .signers([testContext.admin])
@@ -196,8 +250,10 @@ describe("update_admin", () => {
       const newOgCollectionMint = Keypair.generate().publicKey;
 
       const tx = await testContext.program.methods
-        .updateCollectionMint({ og: {} }, newOgCollectionMint)
+        .updateCollectionMint(OG_TIER_ID, newOgCollectionMint)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
           superAdmin: testContext.admin.publicKey,
         })
         .signers([testContext.admin])
@@ -209,8 +265,10 @@ describe("update_admin", () => {
       
       // Restore original for other tests
       await testContext.program.methods
-        .updateCollectionMint({ og: {} }, testContext.ogCollectionMint!)
+        .updateCollectionMint(OG_TIER_ID, testContext.ogCollectionMint!)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
           superAdmin: testContext.admin.publicKey,
         })
         .signers([testContext.admin])
@@ -222,8 +280,10 @@ describe("update_admin", () => {
 
       try {
         await testContext.program.methods
-          .updateCollectionMint({ og: {} }, invalidCollection)
+          .updateCollectionMint(OG_TIER_ID, invalidCollection)
           .accounts({
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
             superAdmin: testContext.admin.publicKey,
           })
           .signers([testContext.admin])
@@ -236,31 +296,859 @@ describe("update_admin", () => {
     });
   });
 
-  describe("update_super_admin", () => {
-    it("should update super admin successfully", async () => {
-      const newSuperAdmin = testContext.user1.keypair.publicKey;
+  describe("timelocked config changes", () => {
+    // The delay cannot be shortened instantly, so it runs on its own campaign
+    const campaignId = new BN(36);
 
-      const tx = await testContext.program.methods
-        .updateSuperAdmin(newSuperAdmin)
+    it("should only apply a queued fee change after the delay and allow cancelling", async () => {
+      const campaignAdmin = testContext.user3.keypair;
+      const [adminStatePda] = testContext.getAdminStatePda(campaignId);
+      const [collectionStatePda] = testContext.getCollectionStatePda(OG_TIER_ID, campaignId);
+      const configDelay = 3;
+
+      await testContext.program.methods
+        .initAdmin(campaignId, campaignAdmin.publicKey, MINT_START_DATE)
+        .accounts({
+          upgradeAuthority: testContext.provider.wallet.publicKey,
+          programData: testContext.programDataPda,
+          superAdmin: campaignAdmin.publicKey,
+          adminState: adminStatePda,
+          paymentMint: testContext.usdcMint,
+          vault: testContext.getVaultPda(testContext.usdcMint, campaignId)[0],
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([campaignAdmin])
+        .rpc();
+
+      await testContext.program.methods
+        .addCollection(OG_TIER_ID, Keypair.generate().publicKey, OG_MINT_FEE, OG_MAX_SUPPLY, OG_ADMIN_MINT_LIMIT)
+        .accounts({
+          superAdmin: campaignAdmin.publicKey,
+          adminState: adminStatePda,
+          collectionState: collectionStatePda,
+        })
+        .signers([campaignAdmin])
+        .rpc();
+
+      await testContext.program.methods
+        .updateConfigDelay(new BN(configDelay))
+        .accounts({
+          adminState: adminStatePda,
+          superAdmin: campaignAdmin.publicKey,
+        })
+        .signers([campaignAdmin])
+        .rpc();
+
+      // Direct updates are rejected while a delay is configured
+      try {
+        await testContext.program.methods
+          .updateMintFee(OG_TIER_ID, new BN(1))
+          .accounts({
+            adminState: adminStatePda,
+            collectionState: collectionStatePda,
+            authority: campaignAdmin.publicKey,
+          })
+          .signers([campaignAdmin])
+          .rpc();
+
+        expect.fail("Expected direct update to fail while timelocked");
+      } catch (error: any) {
+        expect(error.toString()).to.include("TimelockActive");
+      }
+
+      // Supply buckets and sale phase prices wait out the delay as well
+      const collectionAccounts = {
+        superAdmin: campaignAdmin.publicKey,
+        adminState: adminStatePda,
+        collectionState: collectionStatePda,
+      };
+      for (const directUpdate of [
+        testContext.program.methods.updateAdminMintLimit(OG_TIER_ID, new BN(0)),
+        testContext.program.methods.releaseAdminAllocation(OG_TIER_ID),
+        testContext.program.methods.addSalePhase(OG_TIER_ID, new BN(0), new BN(0), null, new BN(1), new BN(0), 0),
+        testContext.program.methods.updateCollectionWalletLimits(OG_TIER_ID, 1, []),
+        testContext.program.methods.updateAllowlistRoot(OG_TIER_ID, Array.from(Buffer.alloc(32, 1))),
+        testContext.program.methods.updateSaleWindow(OG_TIER_ID, new BN(1), new BN(0)),
+      ]) {
+        try {
+          await directUpdate.accounts(collectionAccounts).signers([campaignAdmin]).rpc();
+          expect.fail("Expected direct supply or price update to fail while timelocked");
+        } catch (error: any) {
+          expect(error.toString()).to.include("TimelockActive");
+        }
+      }
+
+      const newMintFee = new BN(8000000);
+      const [feeChangePda] = testContext.getPendingChangePda(new BN(0), campaignId);
+      const [supplyChangePda] = testContext.getPendingChangePda(new BN(1), campaignId);
+
+      await testContext.program.methods
+        .queueConfigChange({ updateMintFee: { tierId: OG_TIER_ID, mintFee: newMintFee } })
+        .accounts({
+          authority: campaignAdmin.publicKey,
+          adminState: adminStatePda,
+          pendingChange: feeChangePda,
+        })
+        .signers([campaignAdmin])
+        .rpc();
+
+      const queued = await testContext.program.account.pendingChange.fetch(feeChangePda);
+      expect(queued.executableAt.toNumber() - queued.queuedAt.toNumber()).to.equal(configDelay);
+
+      const executeAccounts = {
+        executor: testContext.user1.keypair.publicKey,
+        adminState: adminStatePda,
+        pendingChange: feeChangePda,
+        queuedBy: campaignAdmin.publicKey,
+        collectionState: collectionStatePda,
+      };
+
+      try {
+        await testContext.program.methods
+          .executeConfigChange(new BN(0))
+          .accounts(executeAccounts)
+          .signers([testContext.user1.keypair])
+          .rpc();
+
+        expect.fail("Expected execution to fail before the delay elapsed");
+      } catch (error: any) {
+        expect(error.toString()).to.include("TimelockNotElapsed");
+      }
+
+      // A second change is queued and cancelled before it can execute
+      await testContext.program.methods
+        .queueConfigChange({ updateMaxSupply: { tierId: OG_TIER_ID, maxSupply: new BN(1) } })
+        .accounts({
+          authority: campaignAdmin.publicKey,
+          adminState: adminStatePda,
+          pendingChange: supplyChangePda,
+        })
+        .signers([campaignAdmin])
+        .rpc();
+
+      await testContext.program.methods
+        .cancelConfigChange(new BN(1))
+        .accounts({
+          superAdmin: campaignAdmin.publicKey,
+          adminState: adminStatePda,
+          pendingChange: supplyChangePda,
+          queuedBy: campaignAdmin.publicKey,
+        })
+        .signers([campaignAdmin])
+        .rpc();
+
+      expect(await testContext.connection.getAccountInfo(supplyChangePda)).to.be.null;
+
+      await new Promise((resolve) => setTimeout(resolve, (configDelay + 1) * 1000));
+
+      // Anyone can execute once the delay has passed
+      await testContext.program.methods
+        .executeConfigChange(new BN(0))
+        .accounts(executeAccounts)
+        .signers([testContext.user1.keypair])
+        .rpc();
+
+      const collectionState = await testContext.program.account.collectionState.fetch(collectionStatePda);
+      expect(collectionState.mintFee.toString()).to.equal(newMintFee.toString());
+      expect(collectionState.maxSupply.toString()).to.equal(OG_MAX_SUPPLY.toString());
+    });
+
+    it("should queue sale schedule changes and cap the config delay", async () => {
+      const campaignAdmin = testContext.user3.keypair;
+      const [adminStatePda] = testContext.getAdminStatePda(campaignId);
+      const [collectionStatePda] = testContext.getCollectionStatePda(OG_TIER_ID, campaignId);
+      const configDelay = 3;
+      const maxConfigDelay = 30 * 24 * 60 * 60;
+
+      // A delay past 30 days is rejected both directly and when queued
+      try {
+        await testContext.program.methods
+          .updateConfigDelay(new BN(maxConfigDelay + 1))
+          .accounts({ adminState: adminStatePda, superAdmin: campaignAdmin.publicKey })
+          .signers([campaignAdmin])
+          .rpc();
+        expect.fail("Expected a config delay over the cap to fail");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidConfigDelay");
+      }
+
+      const queueChange = async (change: any) => {
+        const changeId = (await testContext.program.account.adminState.fetch(adminStatePda)).configChangeCount;
+        await testContext.program.methods
+          .queueConfigChange(change)
+          .accounts({
+            authority: campaignAdmin.publicKey,
+            adminState: adminStatePda,
+            pendingChange: testContext.getPendingChangePda(changeId, campaignId)[0],
+          })
+          .signers([campaignAdmin])
+          .rpc();
+        return changeId;
+      };
+
+      try {
+        await queueChange({ updateConfigDelay: { configDelay: new BN(maxConfigDelay + 1) } });
+        expect.fail("Expected queueing a config delay over the cap to fail");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidConfigDelay");
+      }
+
+      const now = Math.floor(Date.now() / 1000);
+      const allowlistRoot = Array.from(Buffer.alloc(32, 2));
+      const phase = { startTime: new BN(now + 3600), endTime: new BN(now + 7200), allowlistRoot: null, price: new BN(1), supplyCap: new BN(5), perWalletLimit: 1 };
+      const changeIds: BN[] = [];
+      for (const change of [
+        { updateAllowlistRoot: { tierId: OG_TIER_ID, allowlistRoot } },
+        { updateSaleWindow: { tierId: OG_TIER_ID, saleStart: new BN(now + 3600), saleEnd: new BN(now + 86400) } },
+        { addSalePhase: { tierId: OG_TIER_ID, ...phase } },
+        { updateSalePhase: { tierId: OG_TIER_ID, phaseIndex: 0, ...phase, supplyCap: new BN(3) } },
+        { updateWalletLimits: { tierId: OG_TIER_ID, maxPerWallet: 2, quantityPrices: [{ minQuantity: 2, price: new BN(1) }] } },
+      ]) {
+        changeIds.push(await queueChange(change));
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, (configDelay + 1) * 1000));
+      for (const changeId of changeIds) {
+        await testContext.program.methods
+          .executeConfigChange(changeId)
+          .accounts({
+            executor: testContext.user1.keypair.publicKey,
+            adminState: adminStatePda,
+            pendingChange: testContext.getPendingChangePda(changeId, campaignId)[0],
+            queuedBy: campaignAdmin.publicKey,
+            collectionState: collectionStatePda,
+          })
+          .signers([testContext.user1.keypair])
+          .rpc();
+      }
+
+      const collectionState = await testContext.program.account.collectionState.fetch(collectionStatePda);
+      expect(collectionState.allowlistRoot).to.deep.equal(allowlistRoot);
+      expect(collectionState.saleStart.toNumber()).to.equal(now + 3600);
+      expect(collectionState.salePhases).to.have.length(1);
+      expect(collectionState.salePhases[0].supplyCap.toNumber()).to.equal(3);
+      expect(collectionState.maxPerWallet).to.equal(2);
+      expect(collectionState.quantityPrices).to.have.length(1);
+    });
+
+    it("should hand off NFT update authority once, after the delay, through the crank", async () => {
+      const campaignAdmin = testContext.user3.keypair;
+      const [adminStatePda] = testContext.getAdminStatePda(campaignId);
+      const newUpdateAuthority = Keypair.generate().publicKey;
+      const configDelay = 3;
+
+      // A collection NFT controlled by this campaign's admin_state
+      const collectionMint = Keypair.generate();
+      const { metadata, masterEdition } = testContext.getMetadataPdas(collectionMint.publicKey);
+      await testContext.program.methods
+        .createCollectionNft("Handoff Collection", "HAND", "https://example.com/handoff.json")
+        .accounts({
+          adminState: adminStatePda,
+          signer: campaignAdmin.publicKey,
+          collectionMint: collectionMint.publicKey,
+          collectionTokenAccount: getAssociatedTokenAddressSync(collectionMint.publicKey, adminStatePda, true),
+          metadataAccount: metadata,
+          masterEditionAccount: masterEdition,
+        })
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+        .signers([campaignAdmin, collectionMint])
+        .rpc();
+
+      const queueHandoff = (changeId: BN) =>
+        testContext.program.methods
+          .queueConfigChange({ handOffUpdateAuthority: { newUpdateAuthority } })
+          .accounts({
+            authority: campaignAdmin.publicKey,
+            adminState: adminStatePda,
+            pendingChange: testContext.getPendingChangePda(changeId, campaignId)[0],
+          })
+          .signers([campaignAdmin])
+          .rpc();
+
+      const changeId = (await testContext.program.account.adminState.fetch(adminStatePda)).configChangeCount;
+      await queueHandoff(changeId);
+      await new Promise((resolve) => setTimeout(resolve, (configDelay + 1) * 1000));
+      await testContext.program.methods
+        .executeConfigChange(changeId)
+        .accounts({
+          executor: testContext.user1.keypair.publicKey,
+          adminState: adminStatePda,
+          pendingChange: testContext.getPendingChangePda(changeId, campaignId)[0],
+          queuedBy: campaignAdmin.publicKey,
+          collectionState: null,
+        })
+        .signers([testContext.user1.keypair])
+        .rpc();
+
+      const adminState = await testContext.program.account.adminState.fetch(adminStatePda);
+      expect(adminState.updateAuthorityHandoff.toBase58()).to.equal(newUpdateAuthority.toBase58());
+
+      // The handoff is recorded once and cannot be queued again
+      try {
+        await queueHandoff(adminState.configChangeCount);
+        expect.fail("Expected a second handoff to fail");
+      } catch (error: any) {
+        expect(error.toString()).to.include("UpdateAuthorityHandoffStarted");
+      }
+
+      // Anyone can crank the handoff
+      await testContext.program.methods
+        .handOffUpdateAuthority()
+        .accounts({
+          payer: testContext.user1.keypair.publicKey,
+          adminState: adminStatePda,
+          sysvarInstructions: new PublicKey("Sysvar1nstructions1111111111111111111111111"),
+        })
+        .remainingAccounts([
+          { pubkey: collectionMint.publicKey, isWritable: false, isSigner: false },
+          { pubkey: metadata, isWritable: true, isSigner: false },
+        ])
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+        .signers([testContext.user1.keypair])
+        .rpc();
+
+      // Update authority follows the 1-byte key in the metadata account
+      const metadataData = (await testContext.connection.getAccountInfo(metadata))!.data;
+      expect(new PublicKey(metadataData.subarray(1, 33)).toBase58()).to.equal(newUpdateAuthority.toBase58());
+      expect((await testContext.program.account.adminState.fetch(adminStatePda)).handedOffCount.toNumber()).to.equal(1);
+
+      // The program no longer controls new metadata, so reveals (like mints and metadata updates) are closed
+      try {
+        await testContext.program.methods
+          .revealCollection(OG_TIER_ID, "https://example.com/handoff/")
+          .accounts({
+            superAdmin: campaignAdmin.publicKey,
+            adminState: adminStatePda,
+            collectionState: testContext.getCollectionStatePda(OG_TIER_ID, campaignId)[0],
+            slotHashes: new PublicKey("SysvarS1otHashes111111111111111111111111111"),
+          })
+          .signers([campaignAdmin])
+          .rpc();
+        expect.fail("Expected a reveal after the handoff to fail");
+      } catch (error: any) {
+        expect(error.toString()).to.include("UpdateAuthorityHandedOff");
+      }
+    });
+  });
+
+  describe("multisig governance", () => {
+    // Enabling a multisig is one-way, so it runs on its own campaign
+    const campaignId = new BN(35);
+
+    it("should require threshold approvals before executing a fee change", async () => {
+      const [adminStatePda] = testContext.getAdminStatePda(campaignId);
+      const [multisigPda] = testContext.getMultisigPda(campaignId);
+      const [collectionStatePda] = testContext.getCollectionStatePda(OG_TIER_ID, campaignId);
+      const campaignAdmin = testContext.user2.keypair;
+      const [signerA, signerB, signerC] = [testContext.user1.keypair, testContext.user2.keypair, testContext.user3.keypair];
+
+      await testContext.program.methods
+        .initAdmin(campaignId, campaignAdmin.publicKey, MINT_START_DATE)
+        .accounts({
+          upgradeAuthority: testContext.provider.wallet.publicKey,
+          programData: testContext.programDataPda,
+          superAdmin: campaignAdmin.publicKey,
+          adminState: adminStatePda,
+          paymentMint: testContext.usdcMint,
+          vault: testContext.getVaultPda(testContext.usdcMint, campaignId)[0],
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([campaignAdmin])
+        .rpc();
+
+      await testContext.program.methods
+        .addCollection(OG_TIER_ID, Keypair.generate().publicKey, OG_MINT_FEE, OG_MAX_SUPPLY, OG_ADMIN_MINT_LIMIT)
+        .accounts({
+          superAdmin: campaignAdmin.publicKey,
+          adminState: adminStatePda,
+          collectionState: collectionStatePda,
+        })
+        .signers([campaignAdmin])
+        .rpc();
+
+      await testContext.program.methods
+        .createMultisig([signerA.publicKey, signerB.publicKey, signerC.publicKey], 2)
+        .accounts({
+          superAdmin: campaignAdmin.publicKey,
+          adminState: adminStatePda,
+          multisig: multisigPda,
+        })
+        .signers([campaignAdmin])
+        .rpc();
+
+      // The super admin can no longer change the fee directly
+      try {
+        await testContext.program.methods
+          .updateMintFee(OG_TIER_ID, new BN(1))
+          .accounts({
+            adminState: adminStatePda,
+            collectionState: collectionStatePda,
+            authority: campaignAdmin.publicKey,
+          })
+          .signers([campaignAdmin])
+          .rpc();
+
+        expect.fail("Expected direct update to fail under multisig governance");
+      } catch (error: any) {
+        expect(error.toString()).to.include("MultisigRequired");
+      }
+
+      const proposalId = new BN(0);
+      const [proposalPda] = testContext.getProposalPda(proposalId, campaignId);
+      const newMintFee = new BN(9000000);
+
+      await testContext.program.methods
+        .createProposal({ updateMintFee: { tierId: OG_TIER_ID, mintFee: newMintFee } })
+        .accounts({
+          proposer: signerA.publicKey,
+          adminState: adminStatePda,
+          multisig: multisigPda,
+          proposal: proposalPda,
+        })
+        .signers([signerA])
+        .rpc();
+
+      const executeAccounts = {
+        executor: signerA.publicKey,
+        adminState: adminStatePda,
+        multisig: multisigPda,
+        proposal: proposalPda,
+        collectionState: collectionStatePda,
+      };
+
+      try {
+        await testContext.program.methods
+          .executeProposal(proposalId)
+          .accounts(executeAccounts)
+          .signers([signerA])
+          .rpc();
+
+        expect.fail("Expected execution to fail with a single approval");
+      } catch (error: any) {
+        expect(error.toString()).to.include("ProposalThresholdNotMet");
+      }
+
+      await testContext.program.methods
+        .approveProposal(proposalId)
+        .accounts({
+          approver: signerC.publicKey,
+          adminState: adminStatePda,
+          multisig: multisigPda,
+          proposal: proposalPda,
+        })
+        .signers([signerC])
+        .rpc();
+
+      await testContext.program.methods
+        .executeProposal(proposalId)
+        .accounts(executeAccounts)
+        .signers([signerA])
+        .rpc();
+
+      const collectionState = await testContext.program.account.collectionState.fetch(collectionStatePda);
+      expect(collectionState.mintFee.toString()).to.equal(newMintFee.toString());
+
+      const proposal = await testContext.program.account.proposal.fetch(proposalPda);
+      expect(proposal.executed).to.be.true;
+    });
+
+    it("should route collection config updates through proposals", async () => {
+      const [adminStatePda] = testContext.getAdminStatePda(campaignId);
+      const [multisigPda] = testContext.getMultisigPda(campaignId);
+      const [collectionStatePda] = testContext.getCollectionStatePda(OG_TIER_ID, campaignId);
+      const campaignAdmin = testContext.user2.keypair;
+      const [signerA, signerB] = [testContext.user1.keypair, testContext.user2.keypair];
+      const baseUri = "https://example.com/multisig/";
+
+      try {
+        await testContext.program.methods
+          .updateCollectionMetadata(OG_TIER_ID, "OG", "OG", baseUri)
+          .accounts({
+            superAdmin: campaignAdmin.publicKey,
+            adminState: adminStatePda,
+            collectionState: collectionStatePda,
+          })
+          .signers([campaignAdmin])
+          .rpc();
+
+        expect.fail("Expected direct collection update to fail under multisig governance");
+      } catch (error: any) {
+        expect(error.toString()).to.include("MultisigRequired");
+      }
+
+      const proposalId = new BN(1);
+      const [proposalPda] = testContext.getProposalPda(proposalId, campaignId);
+      await testContext.program.methods
+        .createProposal({
+          updateCollectionMetadata: { tierId: OG_TIER_ID, namePrefix: "OG", symbol: "OG", baseUri },
+        })
+        .accounts({
+          proposer: signerA.publicKey,
+          adminState: adminStatePda,
+          multisig: multisigPda,
+          proposal: proposalPda,
+        })
+        .signers([signerA])
+        .rpc();
+      await testContext.program.methods
+        .approveProposal(proposalId)
+        .accounts({
+          approver: signerB.publicKey,
+          adminState: adminStatePda,
+          multisig: multisigPda,
+          proposal: proposalPda,
+        })
+        .signers([signerB])
+        .rpc();
+      await testContext.program.methods
+        .executeProposal(proposalId)
+        .accounts({
+          executor: signerB.publicKey,
+          adminState: adminStatePda,
+          multisig: multisigPda,
+          proposal: proposalPda,
+          collectionState: collectionStatePda,
+        })
+        .signers([signerB])
+        .rpc();
+
+      const collectionState = await testContext.program.account.collectionState.fetch(collectionStatePda);
+      expect(collectionState.baseUri).to.equal(baseUri);
+    });
+
+    it("should run every super admin operation through proposals", async () => {
+      const MULTISIG_TIER_ID = 3;
+      const SYSVAR_INSTRUCTIONS_PUBKEY = new PublicKey("Sysvar1nstructions1111111111111111111111111");
+      const SYSVAR_SLOT_HASHES_PUBKEY = new PublicKey("SysvarS1otHashes111111111111111111111111111");
+      const [adminStatePda] = testContext.getAdminStatePda(campaignId);
+      const [multisigPda] = testContext.getMultisigPda(campaignId);
+      const [collectionStatePda] = testContext.getCollectionStatePda(MULTISIG_TIER_ID, campaignId);
+      const campaignAdmin = testContext.user2.keypair;
+      const [signerA, signerB] = [testContext.user1.keypair, testContext.user2.keypair];
+      let nextProposalId = 2;
+
+      // Creates a proposal, approves it up to the 2-of-3 threshold and returns its accounts
+      const approvedProposal = async (action: any) => {
+        const proposalId = new BN(nextProposalId++);
+        const [proposalPda] = testContext.getProposalPda(proposalId, campaignId);
+        await testContext.program.methods
+          .createProposal(action)
+          .accounts({ proposer: signerA.publicKey, adminState: adminStatePda, multisig: multisigPda, proposal: proposalPda })
+          .signers([signerA])
+          .rpc();
+        await testContext.program.methods
+          .approveProposal(proposalId)
+          .accounts({ approver: signerB.publicKey, adminState: adminStatePda, multisig: multisigPda, proposal: proposalPda })
+          .signers([signerB])
+          .rpc();
+        return {
+          proposalId,
+          accounts: { executor: signerA.publicKey, adminState: adminStatePda, multisig: multisigPda, proposal: proposalPda },
+        };
+      };
+      const executeProposal = async (action: any, collectionState: PublicKey | null = collectionStatePda) => {
+        const { proposalId, accounts } = await approvedProposal(action);
+        await testContext.program.methods
+          .executeProposal(proposalId)
+          .accounts({ ...accounts, collectionState })
+          .signers([signerA])
+          .rpc();
+      };
+
+      // The collection NFT is still created by the super admin, but the tier is added by the multisig
+      const collectionMint = Keypair.generate();
+      const { metadata, masterEdition } = testContext.getMetadataPdas(collectionMint.publicKey);
+      const collectionTokenAccount = getAssociatedTokenAddressSync(collectionMint.publicKey, adminStatePda, true);
+      await testContext.program.methods
+        .createCollectionNft("Multisig Collection", "MSIG", "https://example.com/multisig.json")
+        .accounts({
+          adminState: adminStatePda,
+          signer: campaignAdmin.publicKey,
+          collectionMint: collectionMint.publicKey,
+          collectionTokenAccount,
+          metadataAccount: metadata,
+          masterEditionAccount: masterEdition,
+        })
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+        .signers([campaignAdmin, collectionMint])
+        .rpc();
+
+      const addCollection = await approvedProposal({
+        addCollection: {
+          tierId: MULTISIG_TIER_ID,
+          collectionMint: collectionMint.publicKey,
+          mintFee: OG_MINT_FEE,
+          maxSupply: new BN(10),
+          adminMintLimit: new BN(2),
+        },
+      });
+      await testContext.program.methods
+        .executeAddCollectionProposal(addCollection.proposalId, MULTISIG_TIER_ID)
+        .accounts({ ...addCollection.accounts, collectionState: collectionStatePda })
+        .signers([signerA])
+        .rpc();
+
+      // Collection and campaign config
+      const now = Math.floor(Date.now() / 1000);
+      await executeProposal({ updateSerialInName: { tierId: MULTISIG_TIER_ID, serialInName: true } });
+      await executeProposal({ updateSaleWindow: { tierId: MULTISIG_TIER_ID, saleStart: new BN(now - 60), saleEnd: new BN(now + 3600) } });
+      await executeProposal({ updateAdminReleaseDate: { tierId: MULTISIG_TIER_ID, adminReleaseDate: new BN(now + 3600) } });
+      const phase = { startTime: new BN(now - 60), endTime: new BN(now + 600), allowlistRoot: null, price: new BN(1), supplyCap: new BN(5), perWalletLimit: 1 };
+      await executeProposal({ addSalePhase: { tierId: MULTISIG_TIER_ID, ...phase } });
+      await executeProposal({ updateSalePhase: { tierId: MULTISIG_TIER_ID, phaseIndex: 0, ...phase, supplyCap: new BN(4) } });
+      await executeProposal({ updateWalletReservationCap: { maxReservationsPerWallet: 3 } }, null);
+      await executeProposal({
+        updateWalletLimits: { tierId: MULTISIG_TIER_ID, maxPerWallet: 2, quantityPrices: [{ minQuantity: 2, price: new BN(1) }] },
+      });
+
+      // Reveal: commit and postpone through proposals, seed permissionlessly, reveal through a proposal
+      const provenanceHash = Array.from(Buffer.alloc(32, 9));
+      const committedSlot = new BN((await testContext.connection.getSlot()) + 30);
+      await executeProposal({
+        commitProvenance: {
+          tierId: MULTISIG_TIER_ID,
+          provenanceHash,
+          placeholderUri: "https://example.com/unrevealed.json",
+          revealSlot: committedSlot,
+        },
+      });
+      const revealSlot = committedSlot.addn(10);
+      await executeProposal({ postponeReveal: { tierId: MULTISIG_TIER_ID, revealSlot } });
+      while ((await testContext.connection.getSlot()) <= revealSlot.toNumber()) {
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+      }
+      await testContext.program.methods
+        .seedReveal(MULTISIG_TIER_ID)
         .accounts({
+          payer: signerA.publicKey,
+          adminState: adminStatePda,
+          collectionState: collectionStatePda,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        })
+        .signers([signerA])
+        .rpc();
+      await executeProposal({ revealCollection: { tierId: MULTISIG_TIER_ID, revealBaseUri: "https://example.com/revealed/" } });
+
+      const collectionState = await testContext.program.account.collectionState.fetch(collectionStatePda);
+      expect(collectionState.collectionMint.toBase58()).to.equal(collectionMint.publicKey.toBase58());
+      expect(collectionState.serialInName).to.be.true;
+      expect(collectionState.saleEnd.toNumber()).to.equal(now + 3600);
+      expect(collectionState.adminReleaseDate.toNumber()).to.equal(now + 3600);
+      expect(collectionState.salePhases[0].supplyCap.toNumber()).to.equal(4);
+      expect(collectionState.maxPerWallet).to.equal(2);
+      expect(collectionState.provenanceHash).to.deep.equal(provenanceHash);
+      expect(collectionState.revealSlot.toString()).to.equal(revealSlot.toString());
+      expect(collectionState.revealed).to.be.true;
+      const adminState = await testContext.program.account.adminState.fetch(adminStatePda);
+      expect(adminState.maxReservationsPerWallet).to.equal(3);
+
+      // Roles: granting creates the assignment, revoking closes it
+      const feeManager = Keypair.generate().publicKey;
+      const [roleAssignment] = testContext.getRoleAssignmentPda(ROLE_FEE_MANAGER, feeManager, campaignId);
+      for (const action of [{ grantRole: { role: { feeManager: {} }, holder: feeManager } }, { revokeRole: { role: { feeManager: {} }, holder: feeManager } }]) {
+        const { proposalId, accounts } = await approvedProposal(action);
+        await testContext.program.methods
+          .executeRoleProposal(proposalId, { feeManager: {} }, feeManager)
+          .accounts({ ...accounts, roleAssignment })
+          .signers([signerA])
+          .rpc();
+        const assignment = await testContext.program.account.roleAssignment.fetchNullable(roleAssignment);
+        expect(assignment === null).to.equal("revokeRole" in action);
+      }
+
+      // Operator quotas
+      const operator = Keypair.generate().publicKey;
+      const [operatorQuota] = testContext.getOperatorQuotaPda(MULTISIG_TIER_ID, operator, campaignId);
+      for (const action of [
+        { setOperatorQuota: { tierId: MULTISIG_TIER_ID, operator, quota: new BN(2) } },
+        { revokeOperatorQuota: { tierId: MULTISIG_TIER_ID, operator } },
+      ]) {
+        const { proposalId, accounts } = await approvedProposal(action);
+        await testContext.program.methods
+          .executeOperatorQuotaProposal(proposalId, MULTISIG_TIER_ID, operator)
+          .accounts({ ...accounts, collectionState: collectionStatePda, operatorQuota })
+          .signers([signerA])
+          .rpc();
+      }
+      const quota = await testContext.program.account.operatorQuota.fetch(operatorQuota);
+      expect(quota.quota.toNumber()).to.equal(2);
+      expect(quota.revoked).to.be.true;
+
+      // Collection NFT maintenance
+      const collectionNftAccounts = {
+        collectionState: collectionStatePda,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: metadata,
+        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      };
+      const updateNft = await approvedProposal({
+        updateCollectionNftMetadata: { tierId: MULTISIG_TIER_ID, name: null, symbol: null, uri: "https://example.com/multisig-updated.json" },
+      });
+      await testContext.program.methods
+        .executeCollectionNftProposal(updateNft.proposalId, MULTISIG_TIER_ID)
+        .accounts({ ...updateNft.accounts, ...collectionNftAccounts })
+        .signers([signerA])
+        .rpc();
+      expect((await testContext.connection.getAccountInfo(metadata))!.data.toString("utf8")).to.include(
+        "https://example.com/multisig-updated.json"
+      );
+
+      const delegate = Keypair.generate().publicKey;
+      const [collectionAuthorityRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), collectionMint.publicKey.toBuffer(), Buffer.from("collection_authority"), delegate.toBuffer()],
+        METAPLEX_PROGRAM_ID
+      );
+      for (const action of [
+        { approveCollectionAuthority: { tierId: MULTISIG_TIER_ID, delegate } },
+        { revokeCollectionAuthority: { tierId: MULTISIG_TIER_ID, delegate } },
+      ]) {
+        const { proposalId, accounts } = await approvedProposal(action);
+        await testContext.program.methods
+          .executeCollectionAuthorityProposal(proposalId, MULTISIG_TIER_ID)
+          .accounts({
+            ...accounts,
+            collectionState: collectionStatePda,
+            collectionMint: collectionMint.publicKey,
+            collectionMetadata: metadata,
+            delegate,
+            collectionAuthorityRecord,
+          })
+          .signers([signerA])
+          .rpc();
+        const record = await testContext.connection.getAccountInfo(collectionAuthorityRecord);
+        expect(record === null).to.equal("revokeCollectionAuthority" in action);
+      }
+
+      const lockNft = await approvedProposal({ setCollectionNftMutability: { tierId: MULTISIG_TIER_ID, isMutable: false } });
+      await testContext.program.methods
+        .executeCollectionNftProposal(lockNft.proposalId, MULTISIG_TIER_ID)
+        .accounts({ ...lockNft.accounts, ...collectionNftAccounts })
+        .signers([signerA])
+        .rpc();
+
+      const recipient = Keypair.generate();
+      const destination = await createAssociatedTokenAccount(
+        testContext.connection,
+        testContext.admin,
+        collectionMint.publicKey,
+        recipient.publicKey
+      );
+      const transferNft = await approvedProposal({
+        transferCollectionNft: { tierId: MULTISIG_TIER_ID, destinationTokenAccount: destination },
+      });
+      await testContext.program.methods
+        .executeTransferCollectionNftProposal(transferNft.proposalId, MULTISIG_TIER_ID)
+        .accounts({
+          ...transferNft.accounts,
+          collectionState: collectionStatePda,
+          collectionMint: collectionMint.publicKey,
+          collectionTokenAccount,
+          destinationTokenAccount: destination,
+        })
+        .signers([signerA])
+        .rpc();
+      expect((await getAccount(testContext.connection, destination)).amount).to.equal(BigInt(1));
+    });
+  });
+
+  describe("super_admin handoff", () => {
+    it("should hand off super admin once the new key accepts", async () => {
+      const newSuperAdmin = testContext.user1.keypair;
+
+      await testContext.program.methods
+        .proposeSuperAdmin(newSuperAdmin.publicKey)
+        .accounts({
+          adminState: testContext.adminStatePda,
           superAdmin: testContext.admin.publicKey,
         })
         .signers([testContext.admin])
         .rpc();
 
-      expect(tx).to.be.a("string");
-      await assertAdminState({ superAdmin: newSuperAdmin });
+      // Nothing changes until the proposed key accepts
+      await assertAdminState({ superAdmin: testContext.admin.publicKey });
+
+      await testContext.program.methods
+        .acceptSuperAdmin()
+        .accounts({
+          adminState: testContext.adminStatePda,
+          newSuperAdmin: newSuperAdmin.publicKey,
+        })
+        .signers([newSuperAdmin])
+        .rpc();
+
+      await assertAdminState({ superAdmin: newSuperAdmin.publicKey });
 
       // Change it back for other tests
-      const tx2 = await testContext.program.methods
-        .updateSuperAdmin(testContext.admin.publicKey)
+      await testContext.program.methods
+        .proposeSuperAdmin(testContext.admin.publicKey)
         .accounts({
-          superAdmin: newSuperAdmin, // New admin signs
+          adminState: testContext.adminStatePda,
+          superAdmin: newSuperAdmin.publicKey, // New admin signs
         })
-        .signers([testContext.user1.keypair])
+        .signers([newSuperAdmin])
+        .rpc();
+
+      await testContext.program.methods
+        .acceptSuperAdmin()
+        .accounts({
+          adminState: testContext.adminStatePda,
+          newSuperAdmin: testContext.admin.publicKey,
+        })
+        .signers([testContext.admin])
         .rpc();
 
-      expect(tx2).to.be.a("string");
+      await assertAdminState({ superAdmin: testContext.admin.publicKey });
+    });
+
+    it("should reject acceptance by any key other than the proposed one", async () => {
+      await testContext.program.methods
+        .proposeSuperAdmin(testContext.user1.keypair.publicKey)
+        .accounts({
+          adminState: testContext.adminStatePda,
+          superAdmin: testContext.admin.publicKey,
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      try {
+        await testContext.program.methods
+          .acceptSuperAdmin()
+          .accounts({
+            adminState: testContext.adminStatePda,
+            newSuperAdmin: testContext.user2.keypair.publicKey,
+          })
+          .signers([testContext.user2.keypair])
+          .rpc();
+
+        expect.fail("Expected transaction to fail with wrong accepting key");
+      } catch (error: any) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await testContext.program.methods
+        .cancelSuperAdminHandoff()
+        .accounts({
+          adminState: testContext.adminStatePda,
+          superAdmin: testContext.admin.publicKey,
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      try {
+        await testContext.program.methods
+          .acceptSuperAdmin()
+          .accounts({
+            adminState: testContext.adminStatePda,
+            newSuperAdmin: testContext.user1.keypair.publicKey,
+          })
+          .signers([testContext.user1.keypair])
+          .rpc();
+
+        expect.fail("Expected transaction to fail after the handoff was cancelled");
+      } catch (error: any) {
+        expect(error.toString()).to.include("NoPendingHandoff");
+      }
+
       await assertAdminState({ superAdmin: testContext.admin.publicKey });
     });
 
@@ -269,8 +1157,9 @@ describe("update_admin", () => {
 
       try {
         await testContext.program.methods
-          .updateSuperAdmin(invalidSuperAdmin)
+          .proposeSuperAdmin(invalidSuperAdmin)
           .accounts({
+            adminState: testContext.adminStatePda,
             superAdmin: testContext.admin.publicKey,
           })
           .signers([testContext.admin])
@@ -285,8 +1174,9 @@ describe("update_admin", () => {
     it("should fail when trying to set same super admin", async () => {
       try {
         await testContext.program.methods
-          .updateSuperAdmin(testContext.admin.publicKey) // Same as current
+          .proposeSuperAdmin(testContext.admin.publicKey) // Same as current
           .accounts({
+            adminState: testContext.adminStatePda,
             superAdmin: testContext.admin.publicKey,
           })
           .signers([testContext.admin])
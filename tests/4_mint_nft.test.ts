@@ -12,15 +12,112 @@ import {
   OG_ADMIN_MINT_LIMIT,
   REGULAR_ADMIN_MINT_LIMIT,
   BASIC_ADMIN_MINT_LIMIT,
-  MINT_START_DATE
+  MINT_START_DATE,
+  OG_TIER_ID,
+  REGULAR_TIER_ID,
+  BASIC_TIER_ID,
+  buildAllowlist,
 } from "./setup";
 import { Keypair, PublicKey, ComputeBudgetProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, mintTo, getAccount } from "@solana/spl-token";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, mintTo, getAccount, createAssociatedTokenAccount } from "@solana/spl-token";
 import { BN } from "bn.js";
 
 const METAPLEX_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
 const SYSVAR_INSTRUCTIONS_PUBKEY = new PublicKey("Sysvar1nstructions1111111111111111111111111");
 
+// Create a funded wallet with its own USDC account
+const createMinter = async (): Promise<[Keypair, PublicKey]> => {
+  const minter = Keypair.generate();
+  await testContext.airdropSol(minter.publicKey, 5);
+  const tokenAccount = await createAssociatedTokenAccount(
+    testContext.provider.connection,
+    testContext.admin,
+    testContext.usdcMint,
+    minter.publicKey
+  );
+  await testContext.mintUsdcTo(tokenAccount, 20_000_000);
+  return [minter, tokenAccount];
+};
+
+// Data of a token metadata account: key (1) + update authority (32) + mint (32), then borsh strings,
+// seller fee basis points and the optional creators list
+const readMetadata = async (
+  mint: PublicKey
+): Promise<{
+  name: string;
+  symbol: string;
+  uri: string;
+  sellerFeeBasisPoints: number;
+  creators: { address: PublicKey; verified: boolean; share: number }[];
+}> => {
+  const { metadata } = testContext.getMetadataPdas(mint);
+  const data = (await testContext.connection.getAccountInfo(metadata))!.data;
+  let offset = 65;
+  const readString = (): string => {
+    const length = data.readUInt32LE(offset);
+    const value = data.subarray(offset + 4, offset + 4 + length).toString("utf8").replace(/\0/g, "");
+    offset += 4 + length;
+    return value;
+  };
+  const name = readString();
+  const symbol = readString();
+  const uri = readString();
+  const sellerFeeBasisPoints = data.readUInt16LE(offset);
+  offset += 2;
+  const creators: { address: PublicKey; verified: boolean; share: number }[] = [];
+  if (data[offset++] === 1) {
+    const count = data.readUInt32LE(offset);
+    offset += 4;
+    for (let i = 0; i < count; i++, offset += 34) {
+      creators.push({
+        address: new PublicKey(data.subarray(offset, offset + 32)),
+        verified: data[offset + 32] === 1,
+        share: data[offset + 33],
+      });
+    }
+  }
+  return { name, symbol, uri, sellerFeeBasisPoints, creators };
+};
+
+// Mint an NFT in a tier, verified into the tier's collection
+const mintInTier = async (
+  tierId: number,
+  minter: Keypair,
+  payerTokenAccount: PublicKey,
+  allowlistProof: { price: BN; proof: number[][] } | null = null
+) => {
+  const nftMint = Keypair.generate();
+  const collectionAccounts = await testContext.getCollectionAccounts(tierId);
+  const [metadataAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), nftMint.publicKey.toBuffer()],
+    METAPLEX_PROGRAM_ID
+  );
+
+  await testContext.program.methods
+    .mintNft(tierId, allowlistProof)
+    .accounts({
+      adminState: testContext.adminStatePda,
+      collectionState: testContext.getCollectionStatePda(tierId)[0],
+      vault: testContext.vaultPda,
+      userState: testContext.getUserStatePda(minter.publicKey)[0],
+      walletMintState: testContext.getWalletMintStatePda(tierId, minter.publicKey)[0],
+      signer: minter.publicKey,
+      tokenAccount: getAssociatedTokenAddressSync(nftMint.publicKey, minter.publicKey),
+      mint: nftMint.publicKey,
+      reservation: testContext.getReservationPda(nftMint.publicKey)[0],
+      serialRecord: await testContext.getNextSerialRecordPda(tierId),
+      metadataAccount: metadataAccount,
+      paymentMint: testContext.usdcMint,
+      payerTokenAccount: payerTokenAccount,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+      ...collectionAccounts,
+      sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+    .signers([minter, nftMint])
+    .rpc();
+};
+
 describe("mint_nft", () => {
   let user1OgNftMint: Keypair;
   let user2RegularNftMint: Keypair;
@@ -38,30 +135,7 @@ describe("mint_nft", () => {
       const basicCollectionMintKeypair = Keypair.generate();
 
       // We need to initialize admin first with placeholder mints, then create the collection NFTs
-      await testContext.program.methods
-        .initAdmin(
-          ogCollectionMintKeypair.publicKey,
-          OG_MINT_FEE,
-          OG_MAX_SUPPLY,
-          OG_ADMIN_MINT_LIMIT,
-          regularCollectionMintKeypair.publicKey,
-          REGULAR_MINT_FEE,
-          REGULAR_MAX_SUPPLY,
-          REGULAR_ADMIN_MINT_LIMIT,
-          basicCollectionMintKeypair.publicKey,
-          BASIC_MINT_FEE,
-          BASIC_MAX_SUPPLY,
-          BASIC_ADMIN_MINT_LIMIT,
-          testContext.withdrawWallet.publicKey,
-          MINT_START_DATE
-        )
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-          paymentMint: testContext.usdcMint,
-          paymentTokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.initAdmin(ogCollectionMintKeypair.publicKey, regularCollectionMintKeypair.publicKey, basicCollectionMintKeypair.publicKey);
       
       // Now create the collection NFTs with metadata and master editions
       // OG Collection
@@ -82,6 +156,7 @@ describe("mint_nft", () => {
       await testContext.program.methods
         .createCollectionNft("OG Collection", "OG", "https://example.com/og.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: ogCollectionMintKeypair.publicKey,
           collectionTokenAccount: ogCollectionTokenAccount,
@@ -110,6 +185,7 @@ describe("mint_nft", () => {
       await testContext.program.methods
         .createCollectionNft("Regular Collection", "REG", "https://example.com/regular.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: regularCollectionMintKeypair.publicKey,
           collectionTokenAccount: regularCollectionTokenAccount,
@@ -138,6 +214,7 @@ describe("mint_nft", () => {
       await testContext.program.methods
         .createCollectionNft("Basic Collection", "BASIC", "https://example.com/basic.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: basicCollectionMintKeypair.publicKey,
           collectionTokenAccount: basicCollectionTokenAccount,
@@ -190,6 +267,7 @@ describe("mint_nft", () => {
         await testContext.program.methods
           .createCollectionNft("Regular Collection", "REG", "https://example.com/regular.json")
           .accounts({
+            adminState: testContext.adminStatePda,
             signer: testContext.admin.publicKey,
             collectionMint: regularCollectionMintKeypair.publicKey,
             collectionTokenAccount: regularCollectionTokenAccount,
@@ -218,6 +296,7 @@ describe("mint_nft", () => {
         await testContext.program.methods
           .createCollectionNft("Basic Collection", "BASIC", "https://example.com/basic.json")
           .accounts({
+            adminState: testContext.adminStatePda,
             signer: testContext.admin.publicKey,
             collectionMint: basicCollectionMintKeypair.publicKey,
             collectionTokenAccount: basicCollectionTokenAccount,
@@ -230,14 +309,22 @@ describe("mint_nft", () => {
 
         // Update admin state with new collection mints
         await testContext.program.methods
-          .updateCollectionMint({ regular: {} }, regularCollectionMintKeypair.publicKey)
-          .accounts({ superAdmin: testContext.admin.publicKey })
+          .updateCollectionMint(REGULAR_TIER_ID, regularCollectionMintKeypair.publicKey)
+          .accounts({
+            superAdmin: testContext.admin.publicKey,
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(REGULAR_TIER_ID)[0],
+          })
           .signers([testContext.admin])
           .rpc();
 
         await testContext.program.methods
-          .updateCollectionMint({ basic: {} }, basicCollectionMintKeypair.publicKey)
-          .accounts({ superAdmin: testContext.admin.publicKey })
+          .updateCollectionMint(BASIC_TIER_ID, basicCollectionMintKeypair.publicKey)
+          .accounts({
+            superAdmin: testContext.admin.publicKey,
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
+          })
           .signers([testContext.admin])
           .rpc();
 
@@ -318,11 +405,18 @@ describe("mint_nft", () => {
     const vaultBalanceBefore = await testContext.getVaultBalance();
 
     await testContext.program.methods
-      .mintNft({ og: {} }, "OG NFT #1", "OG", "https://example.com/og1.json")
+      .mintNft(OG_TIER_ID, null)
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+        vault: testContext.vaultPda,
+        userState: testContext.getUserStatePda(testContext.user1.keypair.publicKey)[0],
+        walletMintState: testContext.getWalletMintStatePda(OG_TIER_ID, testContext.user1.keypair.publicKey)[0],
         signer: testContext.user1.keypair.publicKey,
         tokenAccount: nftTokenAccount,
         mint: user1OgNftMint.publicKey,
+        reservation: testContext.getReservationPda(user1OgNftMint.publicKey)[0],
+        serialRecord: await testContext.getNextSerialRecordPda(OG_TIER_ID),
         metadataAccount: metadataAccount,
         paymentMint: testContext.usdcMint,
         payerTokenAccount: testContext.user1.tokenAccount,
@@ -380,11 +474,18 @@ describe("mint_nft", () => {
     const vaultBalanceBefore = await testContext.getVaultBalance();
 
     await testContext.program.methods
-      .mintNft({ regular: {} }, "Regular NFT #1", "REG", "https://example.com/regular1.json")
+      .mintNft(REGULAR_TIER_ID, null)
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(REGULAR_TIER_ID)[0],
+        vault: testContext.vaultPda,
+        userState: testContext.getUserStatePda(testContext.user2.keypair.publicKey)[0],
+        walletMintState: testContext.getWalletMintStatePda(REGULAR_TIER_ID, testContext.user2.keypair.publicKey)[0],
         signer: testContext.user2.keypair.publicKey,
         tokenAccount: nftTokenAccount,
         mint: user2RegularNftMint.publicKey,
+        reservation: testContext.getReservationPda(user2RegularNftMint.publicKey)[0],
+        serialRecord: await testContext.getNextSerialRecordPda(REGULAR_TIER_ID),
         metadataAccount: metadataAccount,
         paymentMint: testContext.usdcMint,
         payerTokenAccount: testContext.user2.tokenAccount,
@@ -441,11 +542,18 @@ describe("mint_nft", () => {
     const vaultBalanceBefore = await testContext.getVaultBalance();
 
     await testContext.program.methods
-      .mintNft({ basic: {} }, "Basic NFT #1", "BASIC", "https://example.com/basic1.json")
+      .mintNft(BASIC_TIER_ID, null)
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
+        vault: testContext.vaultPda,
+        userState: testContext.getUserStatePda(testContext.user3.keypair.publicKey)[0],
+        walletMintState: testContext.getWalletMintStatePda(BASIC_TIER_ID, testContext.user3.keypair.publicKey)[0],
         signer: testContext.user3.keypair.publicKey,
         tokenAccount: nftTokenAccount,
         mint: user3BasicNftMint.publicKey,
+        reservation: testContext.getReservationPda(user3BasicNftMint.publicKey)[0],
+        serialRecord: await testContext.getNextSerialRecordPda(BASIC_TIER_ID),
         metadataAccount: metadataAccount,
         paymentMint: testContext.usdcMint,
         payerTokenAccount: testContext.user3.tokenAccount,
@@ -503,11 +611,18 @@ describe("mint_nft", () => {
     const vaultBalanceBefore = await testContext.getVaultBalance();
 
     await testContext.program.methods
-      .mintNft({ og: {} }, "OG NFT #2", "OG", "https://example.com/og2.json")
+      .mintNft(OG_TIER_ID, null)
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+        vault: testContext.vaultPda,
+        userState: testContext.getUserStatePda(testContext.user4.keypair.publicKey)[0],
+        walletMintState: testContext.getWalletMintStatePda(OG_TIER_ID, testContext.user4.keypair.publicKey)[0],
         signer: testContext.user4.keypair.publicKey,
         tokenAccount: nftTokenAccount,
         mint: user4OgNftMint.publicKey,
+        reservation: testContext.getReservationPda(user4OgNftMint.publicKey)[0],
+        serialRecord: await testContext.getNextSerialRecordPda(OG_TIER_ID),
         metadataAccount: metadataAccount,
         paymentMint: testContext.usdcMint,
         payerTokenAccount: testContext.user4.tokenAccount,
@@ -561,11 +676,18 @@ describe("mint_nft", () => {
     // This should fail because user1 already minted an NFT
     try {
       await testContext.program.methods
-        .mintNft({ regular: {} }, "Regular NFT #2", "REG", "https://example.com/regular2.json")
+        .mintNft(REGULAR_TIER_ID, null)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(REGULAR_TIER_ID)[0],
+          vault: testContext.vaultPda,
+          userState: testContext.getUserStatePda(testContext.user1.keypair.publicKey)[0],
+          walletMintState: testContext.getWalletMintStatePda(REGULAR_TIER_ID, testContext.user1.keypair.publicKey)[0],
           signer: testContext.user1.keypair.publicKey, // Same user that already minted
           tokenAccount: nftTokenAccount,
           mint: secondMint.publicKey,
+          reservation: testContext.getReservationPda(secondMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(REGULAR_TIER_ID),
           metadataAccount: metadataAccount,
           paymentMint: testContext.usdcMint,
           payerTokenAccount: testContext.user1.tokenAccount,
@@ -592,6 +714,7 @@ describe("mint_nft", () => {
     await testContext.program.methods
       .updateMintStartDate(futureDate)
       .accounts({
+        adminState: testContext.adminStatePda,
         superAdmin: testContext.admin.publicKey,
       })
       .signers([testContext.admin])
@@ -604,6 +727,7 @@ describe("mint_nft", () => {
     await testContext.program.methods
       .updateMintStartDate(new anchor.BN(0))
       .accounts({
+        adminState: testContext.adminStatePda,
         superAdmin: testContext.admin.publicKey,
       })
       .signers([testContext.admin])
@@ -616,4 +740,1037 @@ describe("mint_nft", () => {
     expect(adminState.regularCollection.mintFee.toString()).to.equal(REGULAR_MINT_FEE.toString());
     expect(adminState.basicCollection.mintFee.toString()).to.equal(BASIC_MINT_FEE.toString());
   });
+
+  describe("allowlist", () => {
+    const ALLOWLIST_TIER_ID = 20;
+    const DISCOUNT_PRICE = new BN(2_000_000); // 2 USDC
+
+    let discountedMinter: Keypair;
+    let standardMinter: Keypair;
+    let outsider: Keypair;
+    let allowlist: ReturnType<typeof buildAllowlist>;
+
+    let discountedTokenAccount: PublicKey;
+    let standardTokenAccount: PublicKey;
+    let outsiderTokenAccount: PublicKey;
+
+    before(async () => {
+      [discountedMinter, discountedTokenAccount] = await createMinter();
+      [standardMinter, standardTokenAccount] = await createMinter();
+      [outsider, outsiderTokenAccount] = await createMinter();
+
+      allowlist = buildAllowlist([
+        { wallet: discountedMinter.publicKey, price: DISCOUNT_PRICE },
+        { wallet: standardMinter.publicKey, price: new BN(0) },
+        { wallet: Keypair.generate().publicKey, price: new BN(0) },
+      ]);
+
+      await testContext.addMintableCollection(ALLOWLIST_TIER_ID, OG_MINT_FEE, new BN(10), new BN(0));
+
+      await testContext.program.methods
+        .updateAllowlistRoot(ALLOWLIST_TIER_ID, allowlist.root)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(ALLOWLIST_TIER_ID)[0],
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      const collectionState = await testContext.fetchCollectionState(ALLOWLIST_TIER_ID);
+      expect(collectionState.allowlistRoot).to.deep.equal(allowlist.root);
+    });
+
+    it("should reject a mint without a proof", async () => {
+      try {
+        await mintInTier(ALLOWLIST_TIER_ID, outsider, outsiderTokenAccount, null);
+        expect.fail("Expected mint without allowlist proof to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("AllowlistProofRequired");
+      }
+    });
+
+    it("should reject a proof presented by another wallet", async () => {
+      try {
+        await mintInTier(ALLOWLIST_TIER_ID, outsider, outsiderTokenAccount, allowlist.getProof(standardMinter.publicKey));
+        expect.fail("Expected borrowed allowlist proof to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidAllowlistProof");
+      }
+    });
+
+    it("should charge the leaf price for a discounted wallet", async () => {
+      const vaultBalanceBefore = await testContext.getVaultBalance();
+      await mintInTier(ALLOWLIST_TIER_ID, discountedMinter, discountedTokenAccount, allowlist.getProof(discountedMinter.publicKey));
+      const vaultBalanceAfter = await testContext.getVaultBalance();
+      expect(Number(vaultBalanceAfter - vaultBalanceBefore)).to.equal(DISCOUNT_PRICE.toNumber());
+    });
+
+    it("should charge the collection fee when the leaf price is 0", async () => {
+      const vaultBalanceBefore = await testContext.getVaultBalance();
+      await mintInTier(ALLOWLIST_TIER_ID, standardMinter, standardTokenAccount, allowlist.getProof(standardMinter.publicKey));
+      const vaultBalanceAfter = await testContext.getVaultBalance();
+      expect(Number(vaultBalanceAfter - vaultBalanceBefore)).to.equal(OG_MINT_FEE.toNumber());
+    });
+
+    it("should open the collection to everyone once the root is cleared", async () => {
+      await testContext.program.methods
+        .updateAllowlistRoot(ALLOWLIST_TIER_ID, null)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(ALLOWLIST_TIER_ID)[0],
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      await mintInTier(ALLOWLIST_TIER_ID, outsider, outsiderTokenAccount, null);
+      const collectionState = await testContext.fetchCollectionState(ALLOWLIST_TIER_ID);
+      expect(collectionState.currentReservedCount.toNumber()).to.equal(3);
+    });
+  });
+
+  describe("sale phases", () => {
+    const PHASED_TIER_ID = 21;
+    const UPCOMING_TIER_ID = 22;
+    const PHASE_PRICE = new BN(4_000_000); // 4 USDC
+
+    const addTier = async (tierId: number) => {
+      await testContext.addMintableCollection(tierId, OG_MINT_FEE, new BN(10), new BN(0));
+    };
+
+    const addPhase = async (tierId: number, startTime: number, endTime: number, price: BN | null, supplyCap: BN) => {
+      await testContext.program.methods
+        .addSalePhase(tierId, new BN(startTime), new BN(endTime), null, price, supplyCap, 0)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(tierId)[0],
+        })
+        .signers([testContext.admin])
+        .rpc();
+    };
+
+    before(async () => {
+      const now = Math.floor(Date.now() / 1000);
+
+      // Finished presale, then a capped public phase running now
+      await addTier(PHASED_TIER_ID);
+      await addPhase(PHASED_TIER_ID, now - 7200, now - 3600, null, new BN(0));
+      await addPhase(PHASED_TIER_ID, now - 3600, now + 3600, PHASE_PRICE, new BN(1));
+
+      // Only a phase that has not started yet
+      await addTier(UPCOMING_TIER_ID);
+      await addPhase(UPCOMING_TIER_ID, now + 3600, now + 7200, null, new BN(0));
+    });
+
+    it("should reject overlapping phases", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      try {
+        await addPhase(PHASED_TIER_ID, now, now + 60, null, new BN(0));
+        expect.fail("Expected overlapping sale phase to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidSalePhase");
+      }
+    });
+
+    it("should charge the active phase price and count the mint against the phase", async () => {
+      const [minter, tokenAccount] = await createMinter();
+      const vaultBalanceBefore = await testContext.getVaultBalance();
+
+      await mintInTier(PHASED_TIER_ID, minter, tokenAccount);
+
+      const vaultBalanceAfter = await testContext.getVaultBalance();
+      expect(Number(vaultBalanceAfter - vaultBalanceBefore)).to.equal(PHASE_PRICE.toNumber());
+
+      const collectionState = await testContext.fetchCollectionState(PHASED_TIER_ID);
+      expect(collectionState.salePhases[0].mintedCount.toNumber()).to.equal(0);
+      expect(collectionState.salePhases[1].mintedCount.toNumber()).to.equal(1);
+
+      const [walletMintStatePda] = testContext.getWalletMintStatePda(PHASED_TIER_ID, minter.publicKey);
+      const walletMintState = await testContext.program.account.walletMintState.fetch(walletMintStatePda);
+      expect(walletMintState.phaseMints[1]).to.equal(1);
+    });
+
+    it("should reject mints once the phase cap is reached", async () => {
+      const [minter, tokenAccount] = await createMinter();
+      try {
+        await mintInTier(PHASED_TIER_ID, minter, tokenAccount);
+        expect.fail("Expected mint beyond phase cap to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("PhaseSupplyCapReached");
+      }
+    });
+
+    it("should reject mints outside any sale phase", async () => {
+      const [minter, tokenAccount] = await createMinter();
+      try {
+        await mintInTier(UPCOMING_TIER_ID, minter, tokenAccount);
+        expect.fail("Expected mint outside the sale schedule to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("NoActiveSalePhase");
+      }
+    });
+  });
+
+  describe("sale window and close", () => {
+    const WINDOW_TIER_ID = 23;
+
+    const collectionAccounts = () => ({
+      superAdmin: testContext.admin.publicKey,
+      adminState: testContext.adminStatePda,
+      collectionState: testContext.getCollectionStatePda(WINDOW_TIER_ID)[0],
+    });
+
+    before(async () => {
+      await testContext.addMintableCollection(WINDOW_TIER_ID, OG_MINT_FEE, new BN(10), new BN(0));
+    });
+
+    it("should reject mints after the collection sale window ends", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      await testContext.program.methods
+        .updateSaleWindow(WINDOW_TIER_ID, new BN(now - 7200), new BN(now - 3600))
+        .accounts(collectionAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      const [minter, tokenAccount] = await createMinter();
+      try {
+        await mintInTier(WINDOW_TIER_ID, minter, tokenAccount);
+        expect.fail("Expected mint after sale end to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("SaleEnded");
+      }
+    });
+
+    it("should track revenue and freeze the collection on close", async () => {
+      await testContext.program.methods
+        .updateSaleWindow(WINDOW_TIER_ID, new BN(0), new BN(0))
+        .accounts(collectionAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      const [minter, tokenAccount] = await createMinter();
+      await mintInTier(WINDOW_TIER_ID, minter, tokenAccount);
+
+      await testContext.program.methods
+        .closeSale(WINDOW_TIER_ID)
+        .accounts(collectionAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      const collectionState = await testContext.fetchCollectionState(WINDOW_TIER_ID);
+      expect(collectionState.saleClosed).to.be.true;
+      expect(collectionState.currentReservedCount.toNumber()).to.equal(1);
+      expect(collectionState.totalRevenue.toString()).to.equal(OG_MINT_FEE.toString());
+    });
+
+    it("should reject mints and price changes once the sale is closed", async () => {
+      const [minter, tokenAccount] = await createMinter();
+      try {
+        await mintInTier(WINDOW_TIER_ID, minter, tokenAccount);
+        expect.fail("Expected mint after close to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("SaleClosed");
+      }
+
+      try {
+        await testContext.program.methods
+          .updateMintFee(WINDOW_TIER_ID, new BN(1))
+          .accounts({
+            authority: testContext.admin.publicKey,
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(WINDOW_TIER_ID)[0],
+          })
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected mint fee update after close to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("SaleClosed");
+      }
+    });
+  });
+
+  describe("supply buckets", () => {
+    const BUCKET_TIER_ID = 24;
+
+    const collectionAccounts = () => ({
+      superAdmin: testContext.admin.publicKey,
+      adminState: testContext.adminStatePda,
+      collectionState: testContext.getCollectionStatePda(BUCKET_TIER_ID)[0],
+    });
+
+    before(async () => {
+      // 2 total: 1 public + 1 reserved for airdrops
+      await testContext.addMintableCollection(BUCKET_TIER_ID, OG_MINT_FEE, new BN(2), new BN(1));
+
+      const collectionState = await testContext.fetchCollectionState(BUCKET_TIER_ID);
+      expect(collectionState.publicSupply.toNumber()).to.equal(1);
+      expect(collectionState.adminMintLimit.toNumber()).to.equal(1);
+    });
+
+    it("should not let public mints consume the admin allocation", async () => {
+      const [firstMinter, firstTokenAccount] = await createMinter();
+      await mintInTier(BUCKET_TIER_ID, firstMinter, firstTokenAccount);
+
+      const [secondMinter, secondTokenAccount] = await createMinter();
+      try {
+        await mintInTier(BUCKET_TIER_ID, secondMinter, secondTokenAccount);
+        expect.fail("Expected public mint beyond the public bucket to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("MaxSupplyReached");
+      }
+    });
+
+    it("should only release unused admin allocation after the release date", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      await testContext.program.methods
+        .updateAdminReleaseDate(BUCKET_TIER_ID, new BN(now + 3600))
+        .accounts(collectionAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      try {
+        await testContext.program.methods
+          .releaseAdminAllocation(BUCKET_TIER_ID)
+          .accounts(collectionAccounts())
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected release before the release date to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("AdminAllocationLocked");
+      }
+
+      await testContext.program.methods
+        .updateAdminReleaseDate(BUCKET_TIER_ID, new BN(now - 60))
+        .accounts(collectionAccounts())
+        .signers([testContext.admin])
+        .rpc();
+      await testContext.program.methods
+        .releaseAdminAllocation(BUCKET_TIER_ID)
+        .accounts(collectionAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      const collectionState = await testContext.fetchCollectionState(BUCKET_TIER_ID);
+      expect(collectionState.publicSupply.toNumber()).to.equal(2);
+      expect(collectionState.adminMintLimit.toNumber()).to.equal(0);
+
+      const [minter, tokenAccount] = await createMinter();
+      await mintInTier(BUCKET_TIER_ID, minter, tokenAccount);
+      const updatedState = await testContext.fetchCollectionState(BUCKET_TIER_ID);
+      expect(updatedState.publicMintedCount.toNumber()).to.equal(2);
+    });
+  });
+
+  describe("multiple reservations", () => {
+    const MULTI_TIER_ID = 25;
+    const UNCAPPED_TIER_ID = 26;
+    const SECOND_UNIT_PRICE = new BN(3_000_000); // 3 USDC from the second unit on
+
+    const collectionAccounts = () => ({
+      superAdmin: testContext.admin.publicKey,
+      adminState: testContext.adminStatePda,
+      collectionState: testContext.getCollectionStatePda(MULTI_TIER_ID)[0],
+    });
+
+    const setWalletCap = async (cap: number) => {
+      await testContext.program.methods
+        .updateWalletReservationCap(cap)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+        })
+        .signers([testContext.admin])
+        .rpc();
+    };
+
+    before(async () => {
+      for (const tierId of [MULTI_TIER_ID, UNCAPPED_TIER_ID]) {
+        await testContext.addMintableCollection(tierId, OG_MINT_FEE, new BN(10), new BN(0));
+      }
+
+      await testContext.program.methods
+        .updateCollectionWalletLimits(MULTI_TIER_ID, 2, [{ minQuantity: 2, price: SECOND_UNIT_PRICE }])
+        .accounts(collectionAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      await setWalletCap(3);
+    });
+
+    after(async () => {
+      // Restore the one-per-wallet policy for the remaining suites
+      await setWalletCap(1);
+    });
+
+    it("should reject quantity breaks that do not ascend", async () => {
+      try {
+        await testContext.program.methods
+          .updateCollectionWalletLimits(MULTI_TIER_ID, 2, [
+            { minQuantity: 3, price: SECOND_UNIT_PRICE },
+            { minQuantity: 2, price: SECOND_UNIT_PRICE },
+          ])
+          .accounts(collectionAccounts())
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected unordered quantity prices to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidQuantityPrices");
+      }
+    });
+
+    it("should let a wallet reserve several units up to the collection cap at quantity prices", async () => {
+      const [minter, tokenAccount] = await createMinter();
+
+      let vaultBalanceBefore = await testContext.getVaultBalance();
+      await mintInTier(MULTI_TIER_ID, minter, tokenAccount);
+      let vaultBalanceAfter = await testContext.getVaultBalance();
+      expect(Number(vaultBalanceAfter - vaultBalanceBefore)).to.equal(OG_MINT_FEE.toNumber());
+
+      vaultBalanceBefore = vaultBalanceAfter;
+      await mintInTier(MULTI_TIER_ID, minter, tokenAccount);
+      vaultBalanceAfter = await testContext.getVaultBalance();
+      expect(Number(vaultBalanceAfter - vaultBalanceBefore)).to.equal(SECOND_UNIT_PRICE.toNumber());
+
+      const userState = await testContext.program.account.userState.fetch(testContext.getUserStatePda(minter.publicKey)[0]);
+      expect(userState.reservationCount).to.equal(2);
+
+      const walletMintState = await testContext.program.account.walletMintState.fetch(
+        testContext.getWalletMintStatePda(MULTI_TIER_ID, minter.publicKey)[0]
+      );
+      expect(walletMintState.reservedCount).to.equal(2);
+
+      try {
+        await mintInTier(MULTI_TIER_ID, minter, tokenAccount);
+        expect.fail("Expected mint beyond the collection wallet cap to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("WalletCollectionLimitReached");
+      }
+    });
+
+    it("should enforce the campaign-wide wallet cap across collections", async () => {
+      const [minter, tokenAccount] = await createMinter();
+      await mintInTier(MULTI_TIER_ID, minter, tokenAccount);
+      await mintInTier(MULTI_TIER_ID, minter, tokenAccount);
+      await mintInTier(UNCAPPED_TIER_ID, minter, tokenAccount);
+
+      try {
+        await mintInTier(UNCAPPED_TIER_ID, minter, tokenAccount);
+        expect.fail("Expected mint beyond the wallet cap to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("WalletReservationLimitReached");
+      }
+    });
+  });
+
+  describe("serial numbers", () => {
+    const SERIAL_TIER_ID = 27;
+
+    const latestMint = async (minter: Keypair): Promise<PublicKey> => {
+      const userState = await testContext.program.account.userState.fetch(testContext.getUserStatePda(minter.publicKey)[0]);
+      return userState.mintAddress;
+    };
+
+    before(async () => {
+      await testContext.addMintableCollection(SERIAL_TIER_ID, OG_MINT_FEE, new BN(10), new BN(0));
+    });
+
+    it("should issue sequential serials and register them", async () => {
+      const [firstMinter, firstTokenAccount] = await createMinter();
+      const [secondMinter, secondTokenAccount] = await createMinter();
+      await mintInTier(SERIAL_TIER_ID, firstMinter, firstTokenAccount);
+      await mintInTier(SERIAL_TIER_ID, secondMinter, secondTokenAccount);
+
+      const collectionState = await testContext.program.account.collectionState.fetch(
+        testContext.getCollectionStatePda(SERIAL_TIER_ID)[0]
+      );
+      expect(collectionState.lastSerial.toNumber()).to.equal(2);
+
+      for (const [minter, serial] of [[firstMinter, 1], [secondMinter, 2]] as [Keypair, number][]) {
+        const mint = await latestMint(minter);
+        const userState = await testContext.program.account.userState.fetch(testContext.getUserStatePda(minter.publicKey)[0]);
+        expect(userState.serial.toNumber()).to.equal(serial);
+
+        const reservation = await testContext.program.account.reservation.fetch(testContext.getReservationPda(mint)[0]);
+        expect(reservation.serial.toNumber()).to.equal(serial);
+
+        const serialRecord = await testContext.program.account.serialRecord.fetch(
+          testContext.getSerialRecordPda(SERIAL_TIER_ID, new BN(serial))[0]
+        );
+        expect(serialRecord.mintAddress.toBase58()).to.equal(mint.toBase58());
+        expect(serialRecord.wallet.toBase58()).to.equal(minter.publicKey.toBase58());
+        expect(serialRecord.burned).to.be.false;
+      }
+
+      // Without serial naming the name is written as given
+      expect((await readMetadata(await latestMint(firstMinter))).name).to.equal("Reservation NFT");
+    });
+
+    it("should append the serial to the NFT name when enabled", async () => {
+      await testContext.program.methods
+        .updateSerialInName(SERIAL_TIER_ID, true)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(SERIAL_TIER_ID)[0],
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      const [minter, tokenAccount] = await createMinter();
+      await mintInTier(SERIAL_TIER_ID, minter, tokenAccount);
+
+      expect((await readMetadata(await latestMint(minter))).name).to.equal("Reservation NFT #0003");
+    });
+  });
+
+  describe("collection metadata", () => {
+    const METADATA_TIER_ID = 28;
+
+    const metadataAccounts = () => ({
+      superAdmin: testContext.admin.publicKey,
+      adminState: testContext.adminStatePda,
+      collectionState: testContext.getCollectionStatePda(METADATA_TIER_ID)[0],
+    });
+
+    before(async () => {
+      // Registered without metadata so minting stays closed until it is configured
+      const collectionMint = await testContext.createCollectionNft("Metadata Collection", "META", "https://example.com/meta.json");
+      await testContext.program.methods
+        .addCollection(METADATA_TIER_ID, collectionMint, OG_MINT_FEE, new BN(10), new BN(0))
+        .accounts(metadataAccounts())
+        .signers([testContext.admin])
+        .rpc();
+    });
+
+    it("should reject mints until the collection metadata is configured", async () => {
+      const [minter, tokenAccount] = await createMinter();
+      try {
+        await mintInTier(METADATA_TIER_ID, minter, tokenAccount);
+        expect.fail("Expected mint without collection metadata to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("CollectionMetadataNotSet");
+      }
+    });
+
+    it("should reject metadata that does not fit token metadata limits", async () => {
+      for (const [namePrefix, symbol, baseUri] of [
+        ["", "META", "https://example.com/meta/"],
+        ["A name prefix that is far too long", "META", "https://example.com/meta/"],
+        ["Meta", "SYMBOLTOOLONG", "https://example.com/meta/"],
+        ["Meta", "META", "https://example.com/" + "a".repeat(160) + "/"],
+      ]) {
+        try {
+          await testContext.program.methods
+            .updateCollectionMetadata(METADATA_TIER_ID, namePrefix, symbol, baseUri)
+            .accounts(metadataAccounts())
+            .signers([testContext.admin])
+            .rpc();
+          expect.fail("Expected invalid metadata config to fail");
+        } catch (error: any) {
+          expect(error.message).to.include("InvalidMetadataConfig");
+        }
+      }
+    });
+
+    it("should derive name, symbol and uri on-chain and verify the collection", async () => {
+      await testContext.program.methods
+        .updateCollectionMetadata(METADATA_TIER_ID, "Meta Pass", "META", "https://example.com/meta/")
+        .accounts(metadataAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      const [minter, tokenAccount] = await createMinter();
+      await mintInTier(METADATA_TIER_ID, minter, tokenAccount);
+
+      const userState = await testContext.program.account.userState.fetch(testContext.getUserStatePda(minter.publicKey)[0]);
+      const metadata = await readMetadata(userState.mintAddress);
+      expect(metadata.name).to.equal("Meta Pass");
+      expect(metadata.symbol).to.equal("META");
+      expect(metadata.uri).to.equal(`https://example.com/meta/${userState.serial.toNumber()}.json`);
+
+      const collectionState = await testContext.fetchCollectionState(METADATA_TIER_ID);
+      expect(userState.collectionMint.toBase58()).to.equal(collectionState.collectionMint.toBase58());
+    });
+  });
+
+  describe("delayed reveal", () => {
+    const REVEAL_TIER_ID = 29;
+    const PLACEHOLDER_URI = "https://example.com/unrevealed.json";
+    const REVEAL_BASE_URI = "https://example.com/revealed/";
+    const PROVENANCE_HASH = Array.from(Buffer.alloc(32, 7));
+    const SYSVAR_SLOT_HASHES_PUBKEY = new PublicKey("SysvarS1otHashes111111111111111111111111111");
+
+    const superAdminAccounts = () => ({
+      superAdmin: testContext.admin.publicKey,
+      adminState: testContext.adminStatePda,
+      collectionState: testContext.getCollectionStatePda(REVEAL_TIER_ID)[0],
+    });
+
+    const revealedMints: PublicKey[] = [];
+    let revealSlot: BN;
+
+    before(async () => {
+      await testContext.addMintableCollection(REVEAL_TIER_ID, OG_MINT_FEE, new BN(10), new BN(0));
+    });
+
+    it("should mint placeholder metadata once provenance is committed", async () => {
+      // The reveal is seeded by a slot that has not been produced yet
+      revealSlot = new BN((await testContext.connection.getSlot()) + 20);
+      await testContext.program.methods
+        .commitProvenance(REVEAL_TIER_ID, PROVENANCE_HASH, PLACEHOLDER_URI, revealSlot)
+        .accounts(superAdminAccounts())
+        .signers([testContext.admin])
+        .rpc();
+
+      for (let i = 0; i < 2; i++) {
+        const [minter, tokenAccount] = await createMinter();
+        await mintInTier(REVEAL_TIER_ID, minter, tokenAccount);
+        const userState = await testContext.program.account.userState.fetch(testContext.getUserStatePda(minter.publicKey)[0]);
+        expect((await readMetadata(userState.mintAddress)).uri).to.equal(PLACEHOLDER_URI);
+        revealedMints.push(userState.mintAddress);
+      }
+
+      const collectionState = await testContext.fetchCollectionState(REVEAL_TIER_ID);
+      expect(collectionState.artCount.toNumber()).to.equal(10);
+      expect(collectionState.provenanceHash).to.deep.equal(PROVENANCE_HASH);
+      expect(collectionState.revealSlot.toString()).to.equal(revealSlot.toString());
+    });
+
+    it("should lock the provenance hash and max supply after the first mint", async () => {
+      try {
+        await testContext.program.methods
+          .updateMaxSupply(REVEAL_TIER_ID, new BN(20))
+          .accounts({
+            authority: testContext.admin.publicKey,
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(REVEAL_TIER_ID)[0],
+          })
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected max supply change after the commitment to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("SupplyLockedByProvenance");
+      }
+
+      try {
+        await testContext.program.methods
+          .commitProvenance(REVEAL_TIER_ID, PROVENANCE_HASH, PLACEHOLDER_URI, revealSlot)
+          .accounts(superAdminAccounts())
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected provenance commit after minting to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("ProvenanceLocked");
+      }
+    });
+
+    it("should reveal once and point minted NFTs at their final art through the crank", async () => {
+      while ((await testContext.connection.getSlot()) <= revealSlot.toNumber()) {
+        await new Promise((resolve) => setTimeout(resolve, 400));
+      }
+
+      await testContext.program.methods
+        .revealCollection(REVEAL_TIER_ID, REVEAL_BASE_URI)
+        .accounts({ ...superAdminAccounts(), slotHashes: SYSVAR_SLOT_HASHES_PUBKEY })
+        .signers([testContext.admin])
+        .rpc();
+
+      try {
+        await testContext.program.methods
+          .revealCollection(REVEAL_TIER_ID, REVEAL_BASE_URI)
+          .accounts({ ...superAdminAccounts(), slotHashes: SYSVAR_SLOT_HASHES_PUBKEY })
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected a second reveal to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("AlreadyRevealed");
+      }
+
+      // Anyone can crank the reveal
+      const [cranker] = await createMinter();
+      const remainingAccounts = [];
+      for (const mint of revealedMints) {
+        const reservation = await testContext.program.account.reservation.fetch(testContext.getReservationPda(mint)[0]);
+        remainingAccounts.push(
+          { pubkey: testContext.getSerialRecordPda(REVEAL_TIER_ID, reservation.serial)[0], isWritable: true, isSigner: false },
+          { pubkey: mint, isWritable: false, isSigner: false },
+          { pubkey: testContext.getMetadataPdas(mint).metadata, isWritable: true, isSigner: false }
+        );
+      }
+      await testContext.program.methods
+        .crankReveal(REVEAL_TIER_ID)
+        .accounts({
+          payer: cranker.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(REVEAL_TIER_ID)[0],
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .remainingAccounts(remainingAccounts)
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+        .signers([cranker])
+        .rpc();
+
+      // art index = (serial - 1 + offset) % art count, checkable against the committed provenance hash
+      const collectionState = await testContext.fetchCollectionState(REVEAL_TIER_ID);
+      expect(collectionState.revealed).to.be.true;
+      const offset = collectionState.revealOffset.toNumber();
+      const artCount = collectionState.artCount.toNumber();
+      for (const mint of revealedMints) {
+        const reservation = await testContext.program.account.reservation.fetch(testContext.getReservationPda(mint)[0]);
+        const serial = reservation.serial.toNumber();
+        expect((await readMetadata(mint)).uri).to.equal(`${REVEAL_BASE_URI}${(serial - 1 + offset) % artCount}.json`);
+
+        const serialRecord = await testContext.program.account.serialRecord.fetch(
+          testContext.getSerialRecordPda(REVEAL_TIER_ID, reservation.serial)[0]
+        );
+        expect(serialRecord.revealed).to.be.true;
+      }
+
+      // Mints after the reveal carry their final art straight away
+      const [minter, tokenAccount] = await createMinter();
+      await mintInTier(REVEAL_TIER_ID, minter, tokenAccount);
+      const userState = await testContext.program.account.userState.fetch(testContext.getUserStatePda(minter.publicKey)[0]);
+      expect((await readMetadata(userState.mintAddress)).uri).to.equal(`${REVEAL_BASE_URI}${(2 + offset) % artCount}.json`);
+    });
+
+    it("should recover a reveal whose committed slot left the SlotHashes window", async () => {
+      const EXPIRED_TIER_ID = 34;
+      const tierAccounts = {
+        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(EXPIRED_TIER_ID)[0],
+      };
+      const waitForSlot = async (slot: number) => {
+        while ((await testContext.connection.getSlot()) <= slot) {
+          await new Promise((resolve) => setTimeout(resolve, 1000));
+        }
+      };
+
+      await testContext.addMintableCollection(EXPIRED_TIER_ID, OG_MINT_FEE, new BN(10), new BN(0));
+      const expiredSlot = new BN((await testContext.connection.getSlot()) + 5);
+      await testContext.program.methods
+        .commitProvenance(EXPIRED_TIER_ID, PROVENANCE_HASH, PLACEHOLDER_URI, expiredSlot)
+        .accounts(tierAccounts)
+        .signers([testContext.admin])
+        .rpc();
+      const [minter, tokenAccount] = await createMinter();
+      await mintInTier(EXPIRED_TIER_ID, minter, tokenAccount);
+
+      // Nobody seeded the reveal while the committed slot was in the SlotHashes window (512 slots)
+      await waitForSlot(expiredSlot.toNumber() + 512);
+      try {
+        await testContext.program.methods
+          .revealCollection(EXPIRED_TIER_ID, REVEAL_BASE_URI)
+          .accounts({ ...tierAccounts, slotHashes: SYSVAR_SLOT_HASHES_PUBKEY })
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected a reveal from an expired slot to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("RevealSlotExpired");
+      }
+
+      // The expired slot is replaced by a new future one, then anyone can seed the reveal from it
+      const newSlot = new BN((await testContext.connection.getSlot()) + 10);
+      await testContext.program.methods
+        .postponeReveal(EXPIRED_TIER_ID, newSlot)
+        .accounts(tierAccounts)
+        .signers([testContext.admin])
+        .rpc();
+      await waitForSlot(newSlot.toNumber());
+
+      await testContext.program.methods
+        .seedReveal(EXPIRED_TIER_ID)
+        .accounts({
+          payer: minter.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: tierAccounts.collectionState,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        })
+        .signers([minter])
+        .rpc();
+
+      // A drawn offset can no longer be re-rolled
+      try {
+        await testContext.program.methods
+          .postponeReveal(EXPIRED_TIER_ID, newSlot.addn(1000))
+          .accounts(tierAccounts)
+          .signers([testContext.admin])
+          .rpc();
+        expect.fail("Expected postponing a seeded reveal to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("AlreadyRevealed");
+      }
+
+      await testContext.program.methods
+        .revealCollection(EXPIRED_TIER_ID, REVEAL_BASE_URI)
+        .accounts({ ...tierAccounts, slotHashes: SYSVAR_SLOT_HASHES_PUBKEY })
+        .signers([testContext.admin])
+        .rpc();
+
+      const collectionState = await testContext.fetchCollectionState(EXPIRED_TIER_ID);
+      expect(collectionState.revealSeeded).to.be.true;
+      expect(collectionState.revealed).to.be.true;
+      expect(collectionState.revealSlot.toString()).to.equal(newSlot.toString());
+    });
+  });
+
+  describe("update nft metadata", () => {
+    const UPDATE_TIER_ID = 30;
+    let nftMint: PublicKey;
+
+    const updateMetadata = (
+      mint: PublicKey,
+      tierId: number,
+      name: string | null,
+      symbol: string | null,
+      uri: string | null
+    ) =>
+      testContext.program.methods
+        .updateNftMetadata(name, symbol, uri)
+        .accounts({
+          authority: testContext.admin.publicKey,
+          mint,
+          metadataAccount: testContext.getMetadataPdas(mint).metadata,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(tierId)[0],
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          roleAssignment: null,
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+    before(async () => {
+      await testContext.addMintableCollection(UPDATE_TIER_ID, OG_MINT_FEE, new BN(10), new BN(0));
+      const [minter, tokenAccount] = await createMinter();
+      await mintInTier(UPDATE_TIER_ID, minter, tokenAccount);
+      nftMint = (await testContext.program.account.userState.fetch(testContext.getUserStatePda(minter.publicKey)[0])).mintAddress;
+    });
+
+    it("should update only the provided fields", async () => {
+      const before = await readMetadata(nftMint);
+      await updateMetadata(nftMint, UPDATE_TIER_ID, null, null, "https://example.com/updated.json");
+
+      const after = await readMetadata(nftMint);
+      expect(after.name).to.equal(before.name);
+      expect(after.symbol).to.equal(before.symbol);
+      expect(after.uri).to.equal("https://example.com/updated.json");
+    });
+
+    it("should reject an update with no fields", async () => {
+      try {
+        await updateMetadata(nftMint, UPDATE_TIER_ID, null, null, null);
+        expect.fail("Expected empty metadata update to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidMetadata");
+      }
+    });
+
+    it("should reject an NFT that is not verified into the given collection", async () => {
+      try {
+        await updateMetadata(nftMint, OG_TIER_ID, "Renamed", null, null);
+        expect.fail("Expected update against another collection to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("NftNotInCollection");
+      }
+    });
+  });
+
+  describe("collection migration", () => {
+    const MIGRATION_TIER_ID = 32;
+    const mintedNfts: PublicKey[] = [];
+    let oldCollectionMint: PublicKey;
+    let newCollectionMint: PublicKey;
+
+    const updateCollectionMint = (collectionMint: PublicKey) =>
+      testContext.program.methods
+        .updateCollectionMint(MIGRATION_TIER_ID, collectionMint)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(MIGRATION_TIER_ID)[0],
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+    before(async () => {
+      oldCollectionMint = await testContext.addMintableCollection(MIGRATION_TIER_ID, OG_MINT_FEE, new BN(10), new BN(0));
+      const minters: Keypair[] = [];
+      for (let i = 0; i < 3; i++) {
+        const [minter, tokenAccount] = await createMinter();
+        await mintInTier(MIGRATION_TIER_ID, minter, tokenAccount);
+        const userState = await testContext.program.account.userState.fetch(testContext.getUserStatePda(minter.publicKey)[0]);
+        mintedNfts.push(userState.mintAddress);
+        minters.push(minter);
+      }
+
+      // The burned NFT stays verified in the old collection but must not hold up the migration
+      const burnedMint = mintedNfts.pop()!;
+      const burner = minters[2];
+      await testContext.program.methods
+        .burnNft()
+        .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(MIGRATION_TIER_ID)[0],
+          signer: burner.publicKey,
+          oldTokenAccount: getAssociatedTokenAddressSync(burnedMint, burner.publicKey),
+          oldMint: burnedMint,
+          reservation: testContext.getReservationPda(burnedMint)[0],
+          serialRecord: await testContext.getBurnSerialRecordPda(burnedMint),
+          userState: testContext.getUserStatePda(burner.publicKey)[0],
+          walletMintState: testContext.getWalletMintStatePda(MIGRATION_TIER_ID, burner.publicKey)[0],
+        })
+        .signers([burner])
+        .rpc();
+      newCollectionMint = await testContext.createCollectionNft("Migrated Collection", "MIG", "https://example.com/migrated.json");
+    });
+
+    it("should record a pending migration and block further mint changes", async () => {
+      await updateCollectionMint(newCollectionMint);
+
+      const collectionState = await testContext.fetchCollectionState(MIGRATION_TIER_ID);
+      expect(collectionState.migratingFrom.toBase58()).to.equal(oldCollectionMint.toBase58());
+      expect(collectionState.migrationPending.toNumber()).to.equal(mintedNfts.length);
+
+      try {
+        await updateCollectionMint(oldCollectionMint);
+        expect.fail("Expected a second collection mint change to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("CollectionMigrationPending");
+      }
+    });
+
+    it("should move minted NFTs into the new collection through the crank", async () => {
+      const [cranker] = await createMinter();
+      const crank = (nfts: PublicKey[]) =>
+        testContext.program.methods
+          .migrateCollectionNfts(MIGRATION_TIER_ID)
+          .accounts({
+            payer: cranker.publicKey,
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(MIGRATION_TIER_ID)[0],
+            collectionMigration: testContext.getCollectionMigrationPda(MIGRATION_TIER_ID)[0],
+            oldCollectionMint,
+            oldCollectionMetadata: testContext.getMetadataPdas(oldCollectionMint).metadata,
+            collectionMint: newCollectionMint,
+            collectionMetadata: testContext.getMetadataPdas(newCollectionMint).metadata,
+            collectionMasterEdition: testContext.getMetadataPdas(newCollectionMint).masterEdition,
+            sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .remainingAccounts(
+            nfts.flatMap((mint) => [
+              { pubkey: testContext.getReservationPda(mint)[0], isWritable: true, isSigner: false },
+              { pubkey: mint, isWritable: false, isSigner: false },
+              { pubkey: testContext.getMetadataPdas(mint).metadata, isWritable: true, isSigner: false },
+            ])
+          )
+          .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 600_000 })])
+          .signers([cranker])
+          .rpc();
+
+      // Resumable - one NFT per crank, the second call skips the first NFT again
+      await crank([mintedNfts[0]]);
+      expect((await testContext.fetchCollectionState(MIGRATION_TIER_ID)).migratingFrom.toBase58()).to.equal(
+        oldCollectionMint.toBase58()
+      );
+      await crank(mintedNfts);
+
+      for (const mint of mintedNfts) {
+        const reservation = await testContext.program.account.reservation.fetch(testContext.getReservationPda(mint)[0]);
+        expect(reservation.collectionMint.toBase58()).to.equal(newCollectionMint.toBase58());
+      }
+      const collectionState = await testContext.fetchCollectionState(MIGRATION_TIER_ID);
+      expect(collectionState.migratingFrom).to.be.null;
+      expect(collectionState.migrationPending.toNumber()).to.equal(0);
+
+      const migration = await testContext.program.account.collectionMigration.fetch(
+        testContext.getCollectionMigrationPda(MIGRATION_TIER_ID)[0]
+      );
+      expect(migration.migratedCount.toNumber()).to.equal(2);
+      expect(migration.completed).to.be.true;
+    });
+  });
+
+  describe("collection royalties", () => {
+    const ROYALTY_TIER_ID = 33;
+    const partner = Keypair.generate().publicKey;
+
+    const updateRoyalties = (creators: { address: PublicKey; share: number }[], royaltyBps: number) =>
+      testContext.program.methods
+        .updateCollectionRoyalties(ROYALTY_TIER_ID, creators, royaltyBps)
+        .accounts({
+          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(ROYALTY_TIER_ID)[0],
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+    before(async () => {
+      await testContext.addMintableCollection(ROYALTY_TIER_ID, OG_MINT_FEE, new BN(10), new BN(0));
+    });
+
+    it("should reject creators and royalties outside Metaplex limits", async () => {
+      for (const [creators, royaltyBps] of [
+        [[{ address: partner, share: 101 }], 500],
+        [[{ address: partner, share: 10 }, { address: partner, share: 10 }], 500],
+        [[{ address: testContext.adminStatePda, share: 10 }], 500],
+        [[{ address: partner, share: 10 }], 10_001],
+      ] as [{ address: PublicKey; share: number }[], number][]) {
+        try {
+          await updateRoyalties(creators, royaltyBps);
+          expect.fail("Expected invalid royalty config to fail");
+        } catch (error: any) {
+          expect(error.message).to.include("InvalidRoyaltyConfig");
+        }
+      }
+    });
+
+    it("should credit partner creators and royalty on mint and on metadata updates", async () => {
+      await updateRoyalties([{ address: partner, share: 30 }], 500);
+
+      const [minter, tokenAccount] = await createMinter();
+      await mintInTier(ROYALTY_TIER_ID, minter, tokenAccount);
+      const userState = await testContext.program.account.userState.fetch(testContext.getUserStatePda(minter.publicKey)[0]);
+
+      let metadata = await readMetadata(userState.mintAddress);
+      expect(metadata.sellerFeeBasisPoints).to.equal(500);
+      expect(metadata.creators.map((creator) => [creator.address.toBase58(), creator.verified, creator.share])).to.deep.equal([
+        [testContext.adminStatePda.toBase58(), true, 70],
+        [partner.toBase58(), false, 30],
+      ]);
+
+      // Minted NFTs list the partner, who may have verified itself, so it cannot be dropped any more
+      try {
+        await updateRoyalties([], 250);
+        expect.fail("Expected removing a creator after a mint to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("CreatorRemovalAfterMint");
+      }
+
+      // Metadata updates rewrite creators and royalty from the current collection config
+      await updateRoyalties([{ address: partner, share: 10 }], 250);
+      await testContext.program.methods
+        .updateNftMetadata(null, null, "https://example.com/royalty.json")
+        .accounts({
+          authority: testContext.admin.publicKey,
+          mint: userState.mintAddress,
+          metadataAccount: testContext.getMetadataPdas(userState.mintAddress).metadata,
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(ROYALTY_TIER_ID)[0],
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          roleAssignment: null,
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      metadata = await readMetadata(userState.mintAddress);
+      expect(metadata.sellerFeeBasisPoints).to.equal(250);
+      expect(metadata.creators.map((creator) => [creator.address.toBase58(), creator.verified, creator.share])).to.deep.equal([
+        [testContext.adminStatePda.toBase58(), true, 90],
+        [partner.toBase58(), false, 10],
+      ]);
+    });
+  });
 });
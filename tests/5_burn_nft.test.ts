@@ -12,7 +12,10 @@ import {
   OG_ADMIN_MINT_LIMIT,
   REGULAR_ADMIN_MINT_LIMIT,
   BASIC_ADMIN_MINT_LIMIT,
-  MINT_START_DATE
+  MINT_START_DATE,
+  OG_TIER_ID,
+  REGULAR_TIER_ID,
+  BASIC_TIER_ID
 } from "./setup";
 import { Keypair, PublicKey, ComputeBudgetProgram } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, mintTo, createAssociatedTokenAccount } from "@solana/spl-token";
@@ -38,30 +41,7 @@ describe("burn_nft", () => {
       const regularCollectionMintKeypair = Keypair.generate();
       const basicCollectionMintKeypair = Keypair.generate();
 
-      await testContext.program.methods
-        .initAdmin(
-          ogCollectionMintKeypair.publicKey,
-          OG_MINT_FEE,
-          OG_MAX_SUPPLY,
-          OG_ADMIN_MINT_LIMIT,
-          regularCollectionMintKeypair.publicKey,
-          REGULAR_MINT_FEE,
-          REGULAR_MAX_SUPPLY,
-          REGULAR_ADMIN_MINT_LIMIT,
-          basicCollectionMintKeypair.publicKey,
-          BASIC_MINT_FEE,
-          BASIC_MAX_SUPPLY,
-          BASIC_ADMIN_MINT_LIMIT,
-          testContext.withdrawWallet.publicKey,
-          MINT_START_DATE
-        )
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-          paymentMint: testContext.usdcMint,
-          paymentTokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.initAdmin(ogCollectionMintKeypair.publicKey, regularCollectionMintKeypair.publicKey, basicCollectionMintKeypair.publicKey);
       
       // Create collection NFTs...
       const modifyComputeUnits = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
@@ -84,6 +64,7 @@ describe("burn_nft", () => {
       await testContext.program.methods
         .createCollectionNft("OG Collection", "OG", "https://example.com/og.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: ogCollectionMintKeypair.publicKey,
           collectionTokenAccount: ogCollectionTokenAccount,
@@ -112,6 +93,7 @@ describe("burn_nft", () => {
       await testContext.program.methods
         .createCollectionNft("Regular Collection", "REG", "https://example.com/regular.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: regularCollectionMintKeypair.publicKey,
           collectionTokenAccount: regularCollectionTokenAccount,
@@ -140,6 +122,7 @@ describe("burn_nft", () => {
       await testContext.program.methods
         .createCollectionNft("Basic Collection", "BASIC", "https://example.com/basic.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: basicCollectionMintKeypair.publicKey,
           collectionTokenAccount: basicCollectionTokenAccount,
@@ -188,6 +171,7 @@ describe("burn_nft", () => {
         await testContext.program.methods
           .createCollectionNft("Regular Collection", "REG", "https://example.com/regular.json")
           .accounts({
+            adminState: testContext.adminStatePda,
             signer: testContext.admin.publicKey,
             collectionMint: regularCollectionMintKeypair.publicKey,
             collectionTokenAccount: regularCollectionTokenAccount,
@@ -216,6 +200,7 @@ describe("burn_nft", () => {
         await testContext.program.methods
           .createCollectionNft("Basic Collection", "BASIC", "https://example.com/basic.json")
           .accounts({
+            adminState: testContext.adminStatePda,
             signer: testContext.admin.publicKey,
             collectionMint: basicCollectionMintKeypair.publicKey,
             collectionTokenAccount: basicCollectionTokenAccount,
@@ -228,14 +213,22 @@ describe("burn_nft", () => {
 
         // Update admin state with new collection mints
         await testContext.program.methods
-          .updateCollectionMint({ regular: {} }, regularCollectionMintKeypair.publicKey)
-          .accounts({ superAdmin: testContext.admin.publicKey })
+          .updateCollectionMint(REGULAR_TIER_ID, regularCollectionMintKeypair.publicKey)
+          .accounts({
+            superAdmin: testContext.admin.publicKey,
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(REGULAR_TIER_ID)[0],
+          })
           .signers([testContext.admin])
           .rpc();
 
         await testContext.program.methods
-          .updateCollectionMint({ basic: {} }, basicCollectionMintKeypair.publicKey)
-          .accounts({ superAdmin: testContext.admin.publicKey })
+          .updateCollectionMint(BASIC_TIER_ID, basicCollectionMintKeypair.publicKey)
+          .accounts({
+            superAdmin: testContext.admin.publicKey,
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
+          })
           .signers([testContext.admin])
           .rpc();
 
@@ -289,11 +282,18 @@ describe("burn_nft", () => {
       );
 
       await testContext.program.methods
-        .mintNft({ og: {} }, "OG Burn Test", "OGBURN", "https://example.com/og-burn.json")
+        .mintNft(OG_TIER_ID, null)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+          vault: testContext.vaultPda,
+          userState: testContext.getUserStatePda(burnOgUser.keypair.publicKey)[0],
+          walletMintState: testContext.getWalletMintStatePda(OG_TIER_ID, burnOgUser.keypair.publicKey)[0],
           signer: burnOgUser.keypair.publicKey,
           tokenAccount: ogNftTokenAccount,
           mint: burnOgNftMint.publicKey,
+          reservation: testContext.getReservationPda(burnOgNftMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(OG_TIER_ID),
           metadataAccount: ogMetadataAccount,
           paymentMint: testContext.usdcMint,
           payerTokenAccount: burnOgUser.tokenAccount,
@@ -348,11 +348,18 @@ describe("burn_nft", () => {
       );
 
       await testContext.program.methods
-        .mintNft({ regular: {} }, "Regular Burn Test", "REGBURN", "https://example.com/reg-burn.json")
+        .mintNft(REGULAR_TIER_ID, null)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(REGULAR_TIER_ID)[0],
+          vault: testContext.vaultPda,
+          userState: testContext.getUserStatePda(burnRegularUser.keypair.publicKey)[0],
+          walletMintState: testContext.getWalletMintStatePda(REGULAR_TIER_ID, burnRegularUser.keypair.publicKey)[0],
           signer: burnRegularUser.keypair.publicKey,
           tokenAccount: regularNftTokenAccount,
           mint: burnRegularNftMint.publicKey,
+          reservation: testContext.getReservationPda(burnRegularNftMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(REGULAR_TIER_ID),
           metadataAccount: regularMetadataAccount,
           paymentMint: testContext.usdcMint,
           payerTokenAccount: burnRegularUser.tokenAccount,
@@ -407,11 +414,18 @@ describe("burn_nft", () => {
       );
 
       await testContext.program.methods
-        .mintNft({ basic: {} }, "Basic Burn Test", "BASICBURN", "https://example.com/basic-burn.json")
+        .mintNft(BASIC_TIER_ID, null)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
+          vault: testContext.vaultPda,
+          userState: testContext.getUserStatePda(burnBasicUser.keypair.publicKey)[0],
+          walletMintState: testContext.getWalletMintStatePda(BASIC_TIER_ID, burnBasicUser.keypair.publicKey)[0],
           signer: burnBasicUser.keypair.publicKey,
           tokenAccount: basicNftTokenAccount,
           mint: burnBasicNftMint.publicKey,
+          reservation: testContext.getReservationPda(burnBasicNftMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(BASIC_TIER_ID),
           metadataAccount: basicMetadataAccount,
           paymentMint: testContext.usdcMint,
           payerTokenAccount: burnBasicUser.tokenAccount,
@@ -443,17 +457,28 @@ describe("burn_nft", () => {
     const userStateBefore = await testContext.program.account.userState.fetch(userStatePda);
     expect(userStateBefore.hasMinted).to.be.true;
     expect(userStateBefore.mintAddress.toBase58()).to.equal(burnOgNftMint.publicKey.toBase58());
+    expect(userStateBefore.source).to.deep.equal({ public: {} });
+    expect(userStateBefore.paymentMint.toBase58()).to.equal(testContext.usdcMint.toBase58());
+    expect(userStateBefore.amountPaid.toNumber()).to.be.greaterThan(0);
 
     // Get OG collection count before
     const adminStateBefore = await testContext.fetchAdminState();
     const ogCountBefore = adminStateBefore.ogCollection.currentReservedCount.toNumber();
+    const ogCollectionBefore = await testContext.program.account.collectionState.fetch(
+      testContext.getCollectionStatePda(OG_TIER_ID)[0]
+    );
+    const serialRecordPda = testContext.getSerialRecordPda(OG_TIER_ID, userStateBefore.serial)[0];
 
     await testContext.program.methods
       .burnNft()
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
         signer: burnOgUser.keypair.publicKey,
         oldTokenAccount: nftTokenAccount,
         oldMint: burnOgNftMint.publicKey,
+        reservation: testContext.getReservationPda(burnOgNftMint.publicKey)[0],
+        serialRecord: await testContext.getBurnSerialRecordPda(burnOgNftMint.publicKey),
         userState: userStatePda,
         metadataAccount: null,
       })
@@ -463,6 +488,13 @@ describe("burn_nft", () => {
     // Verify OG collection count decreased
     const adminStateAfter = await testContext.fetchAdminState();
     expect(adminStateAfter.ogCollection.currentReservedCount.toNumber()).to.equal(ogCountBefore - 1);
+
+    // A public mint returns its slot to the public bucket; the admin bucket is untouched
+    const ogCollectionAfter = await testContext.program.account.collectionState.fetch(
+      testContext.getCollectionStatePda(OG_TIER_ID)[0]
+    );
+    expect(ogCollectionAfter.publicMintedCount.toNumber()).to.equal(ogCollectionBefore.publicMintedCount.toNumber() - 1);
+    expect(ogCollectionAfter.currentAdminMintCount.toNumber()).to.equal(ogCollectionBefore.currentAdminMintCount.toNumber());
     
     // Verify other collections unchanged
     expect(adminStateAfter.regularCollection.currentReservedCount.toNumber()).to.equal(
@@ -477,6 +509,12 @@ describe("burn_nft", () => {
     expect(userStateAfter.hasMinted).to.be.false;
     expect(userStateAfter.mintAddress.toBase58()).to.equal(PublicKey.default.toBase58());
     expect(userStateAfter.mintedAt.toNumber()).to.equal(0);
+
+    // The serial stays registered and is not reissued
+    const serialRecord = await testContext.program.account.serialRecord.fetch(serialRecordPda);
+    expect(serialRecord.burned).to.be.true;
+    expect(serialRecord.mintAddress.toBase58()).to.equal(burnOgNftMint.publicKey.toBase58());
+    expect(ogCollectionAfter.lastSerial.toNumber()).to.equal(ogCollectionBefore.lastSerial.toNumber());
   });
 
   it("should burn Regular NFT successfully", async () => {
@@ -503,9 +541,13 @@ describe("burn_nft", () => {
     await testContext.program.methods
       .burnNft()
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(REGULAR_TIER_ID)[0],
         signer: burnRegularUser.keypair.publicKey,
         oldTokenAccount: nftTokenAccount,
         oldMint: burnRegularNftMint.publicKey,
+        reservation: testContext.getReservationPda(burnRegularNftMint.publicKey)[0],
+        serialRecord: await testContext.getBurnSerialRecordPda(burnRegularNftMint.publicKey),
         userState: userStatePda,
         metadataAccount: null,
       })
@@ -547,9 +589,13 @@ describe("burn_nft", () => {
     await testContext.program.methods
       .burnNft()
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
         signer: burnBasicUser.keypair.publicKey,
         oldTokenAccount: nftTokenAccount,
         oldMint: burnBasicNftMint.publicKey,
+        reservation: testContext.getReservationPda(burnBasicNftMint.publicKey)[0],
+        serialRecord: await testContext.getBurnSerialRecordPda(burnBasicNftMint.publicKey),
         userState: userStatePda,
         metadataAccount: null,
       })
@@ -611,11 +657,18 @@ describe("burn_nft", () => {
     );
 
     await testContext.program.methods
-      .mintNft({ basic: {} }, "First Mint", "FIRST", "https://example.com/first.json")
+      .mintNft(BASIC_TIER_ID, null)
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
+        vault: testContext.vaultPda,
+        userState: testContext.getUserStatePda(testUser.publicKey)[0],
+        walletMintState: testContext.getWalletMintStatePda(BASIC_TIER_ID, testUser.publicKey)[0],
         signer: testUser.publicKey,
         tokenAccount: firstNftTokenAccount,
         mint: firstMint.publicKey,
+        reservation: testContext.getReservationPda(firstMint.publicKey)[0],
+        serialRecord: await testContext.getNextSerialRecordPda(BASIC_TIER_ID),
         metadataAccount: firstMetadataAccount,
         paymentMint: testContext.usdcMint,
         payerTokenAccount: testUserTokenAccount,
@@ -644,9 +697,13 @@ describe("burn_nft", () => {
     await testContext.program.methods
       .burnNft()
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
         signer: testUser.publicKey,
         oldTokenAccount: firstNftTokenAccount,
         oldMint: firstMint.publicKey,
+        reservation: testContext.getReservationPda(firstMint.publicKey)[0],
+        serialRecord: await testContext.getBurnSerialRecordPda(firstMint.publicKey),
         userState: userStatePda,
         metadataAccount: null,
       })
@@ -670,11 +727,18 @@ describe("burn_nft", () => {
     );
 
     await testContext.program.methods
-      .mintNft({ basic: {} }, "Second Mint", "SECOND", "https://example.com/second.json")
+      .mintNft(BASIC_TIER_ID, null)
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
+        vault: testContext.vaultPda,
+        userState: testContext.getUserStatePda(testUser.publicKey)[0],
+        walletMintState: testContext.getWalletMintStatePda(BASIC_TIER_ID, testUser.publicKey)[0],
         signer: testUser.publicKey,
         tokenAccount: secondNftTokenAccount,
         mint: secondMint.publicKey,
+        reservation: testContext.getReservationPda(secondMint.publicKey)[0],
+        serialRecord: await testContext.getNextSerialRecordPda(BASIC_TIER_ID),
         metadataAccount: secondMetadataAccount,
         paymentMint: testContext.usdcMint,
         payerTokenAccount: testUserTokenAccount,
@@ -739,11 +803,18 @@ describe("burn_nft", () => {
     });
 
     await testContext.program.methods
-      .mintNft({ basic: {} }, "Test NFT 2", "TEST2", "https://example.com/test2.json")
+      .mintNft(BASIC_TIER_ID, null)
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
+        vault: testContext.vaultPda,
+        userState: testContext.getUserStatePda(testKeypair.publicKey)[0],
+        walletMintState: testContext.getWalletMintStatePda(BASIC_TIER_ID, testKeypair.publicKey)[0],
         signer: testKeypair.publicKey,
         tokenAccount: nftTokenAccount,
         mint: testUserNftMint.publicKey,
+        reservation: testContext.getReservationPda(testUserNftMint.publicKey)[0],
+        serialRecord: await testContext.getNextSerialRecordPda(BASIC_TIER_ID),
         metadataAccount: testMetadataAccount,
         paymentMint: testContext.usdcMint,
         payerTokenAccount: testTokenAccount,
@@ -773,9 +844,13 @@ describe("burn_nft", () => {
       await testContext.program.methods
         .burnNft()
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
           signer: testKeypair.publicKey,
           oldTokenAccount: wrongTokenAccount,
           oldMint: testUserNftMint.publicKey,
+          reservation: testContext.getReservationPda(testUserNftMint.publicKey)[0],
+          serialRecord: await testContext.getBurnSerialRecordPda(testUserNftMint.publicKey),
           userState: testUserStatePda,
           metadataAccount: null,
         })
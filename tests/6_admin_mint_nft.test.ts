@@ -12,7 +12,11 @@ import {
   OG_ADMIN_MINT_LIMIT,
   REGULAR_ADMIN_MINT_LIMIT,
   BASIC_ADMIN_MINT_LIMIT,
-  MINT_START_DATE
+  MINT_START_DATE,
+  OG_TIER_ID,
+  REGULAR_TIER_ID,
+  BASIC_TIER_ID,
+  ROLE_AIRDROP_OPERATOR
 } from "./setup";
 import { Keypair, PublicKey, ComputeBudgetProgram } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
@@ -37,30 +41,7 @@ describe("admin_mint_nft", () => {
       const regularCollectionMintKeypair = Keypair.generate();
       const basicCollectionMintKeypair = Keypair.generate();
 
-      await testContext.program.methods
-        .initAdmin(
-          ogCollectionMintKeypair.publicKey,
-          OG_MINT_FEE,
-          OG_MAX_SUPPLY,
-          OG_ADMIN_MINT_LIMIT,
-          regularCollectionMintKeypair.publicKey,
-          REGULAR_MINT_FEE,
-          REGULAR_MAX_SUPPLY,
-          REGULAR_ADMIN_MINT_LIMIT,
-          basicCollectionMintKeypair.publicKey,
-          BASIC_MINT_FEE,
-          BASIC_MAX_SUPPLY,
-          BASIC_ADMIN_MINT_LIMIT,
-          testContext.withdrawWallet.publicKey,
-          MINT_START_DATE
-        )
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-          paymentMint: testContext.usdcMint,
-          paymentTokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.initAdmin(ogCollectionMintKeypair.publicKey, regularCollectionMintKeypair.publicKey, basicCollectionMintKeypair.publicKey);
       
       // Create collection NFTs
       const modifyComputeUnits = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
@@ -83,6 +64,7 @@ describe("admin_mint_nft", () => {
       await testContext.program.methods
         .createCollectionNft("OG Collection", "OG", "https://example.com/og.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: ogCollectionMintKeypair.publicKey,
           collectionTokenAccount: ogCollectionTokenAccount,
@@ -111,6 +93,7 @@ describe("admin_mint_nft", () => {
       await testContext.program.methods
         .createCollectionNft("Regular Collection", "REG", "https://example.com/regular.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: regularCollectionMintKeypair.publicKey,
           collectionTokenAccount: regularCollectionTokenAccount,
@@ -139,6 +122,7 @@ describe("admin_mint_nft", () => {
       await testContext.program.methods
         .createCollectionNft("Basic Collection", "BASIC", "https://example.com/basic.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: basicCollectionMintKeypair.publicKey,
           collectionTokenAccount: basicCollectionTokenAccount,
@@ -200,12 +184,16 @@ describe("admin_mint_nft", () => {
     const adminMintCountBefore = adminStateBefore.ogCollection.currentAdminMintCount.toNumber();
 
     await testContext.program.methods
-      .adminMintNft({ og: {} }, "Admin OG NFT", "ADMINOG", "https://example.com/admin-og.json")
+      .adminMintNft(OG_TIER_ID)
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
         admin: testContext.admin.publicKey,
         recipient: adminMintRecipient1.publicKey,
         recipientTokenAccount: recipientTokenAccount,
         mint: ogNftMint.publicKey,
+        reservation: testContext.getReservationPda(ogNftMint.publicKey)[0],
+        serialRecord: await testContext.getNextSerialRecordPda(OG_TIER_ID),
         metadataAccount: metadataAccount,
         collectionMint: testContext.ogCollectionMint,
         collectionMetadata: collectionMetadata,
@@ -227,7 +215,10 @@ describe("admin_mint_nft", () => {
     expect(recipientUserState.user.toBase58()).to.equal(adminMintRecipient1.publicKey.toBase58());
     expect(recipientUserState.hasMinted).to.be.true;
     expect(recipientUserState.mintAddress.toBase58()).to.equal(ogNftMint.publicKey.toBase58());
-    expect(recipientUserState.collectionType).to.deep.equal({ og: {} });
+    expect(recipientUserState.tierId).to.equal(OG_TIER_ID);
+    expect(recipientUserState.source).to.deep.equal({ admin: {} });
+    expect(recipientUserState.amountPaid.toNumber()).to.equal(0);
+    expect(recipientUserState.collectionMint.toBase58()).to.equal(testContext.ogCollectionMint!.toBase58());
 
     console.log("✅ Admin minted OG NFT and recipient user state initialized");
   });
@@ -261,12 +252,16 @@ describe("admin_mint_nft", () => {
 
     try {
       await testContext.program.methods
-        .adminMintNft({ regular: {} }, "Admin Regular NFT", "ADMINREG", "https://example.com/admin-reg.json")
+        .adminMintNft(REGULAR_TIER_ID)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(REGULAR_TIER_ID)[0],
           admin: testContext.admin.publicKey,
           recipient: adminMintRecipient2.publicKey,
           recipientTokenAccount: recipientTokenAccount,
           mint: regularNftMint.publicKey,
+          reservation: testContext.getReservationPda(regularNftMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(REGULAR_TIER_ID),
           metadataAccount: metadataAccount,
           collectionMint: testContext.regularCollectionMint,
           collectionMetadata: collectionMetadata,
@@ -314,12 +309,16 @@ describe("admin_mint_nft", () => {
 
     try {
       await testContext.program.methods
-        .adminMintNft({ basic: {} }, "Admin Basic NFT", "ADMINBASIC", "https://example.com/admin-basic.json")
+        .adminMintNft(BASIC_TIER_ID)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
           admin: testContext.admin.publicKey,
           recipient: adminMintRecipient3.publicKey,
           recipientTokenAccount: recipientTokenAccount,
           mint: basicNftMint.publicKey,
+          reservation: testContext.getReservationPda(basicNftMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(BASIC_TIER_ID),
           metadataAccount: metadataAccount,
           collectionMint: testContext.basicCollectionMint,
           collectionMetadata: collectionMetadata,
@@ -379,11 +378,18 @@ describe("admin_mint_nft", () => {
 
     try {
       await testContext.program.methods
-        .mintNft({ og: {} }, "Should Fail", "FAIL", "https://example.com/fail.json")
+        .mintNft(OG_TIER_ID, null)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+          vault: testContext.vaultPda,
+          userState: testContext.getUserStatePda(adminMintRecipient1.publicKey)[0],
+          walletMintState: testContext.getWalletMintStatePda(OG_TIER_ID, adminMintRecipient1.publicKey)[0],
           signer: adminMintRecipient1.publicKey,
           tokenAccount: newTokenAccount,
           mint: newMint.publicKey,
+          reservation: testContext.getReservationPda(newMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(OG_TIER_ID),
           metadataAccount: metadataAccount,
           paymentMint: testContext.usdcMint,
           payerTokenAccount: recipientTokenAccount,
@@ -431,12 +437,16 @@ describe("admin_mint_nft", () => {
 
     try {
       await testContext.program.methods
-        .adminMintNft({ og: {} }, "Duplicate", "DUP", "https://example.com/dup.json")
+        .adminMintNft(OG_TIER_ID)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
           admin: testContext.admin.publicKey,
           recipient: adminMintRecipient1.publicKey,
           recipientTokenAccount: recipientTokenAccount,
           mint: duplicateMint.publicKey,
+          reservation: testContext.getReservationPda(duplicateMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(OG_TIER_ID),
           metadataAccount: metadataAccount,
           collectionMint: testContext.ogCollectionMint,
           collectionMetadata: collectionMetadata,
@@ -487,12 +497,16 @@ describe("admin_mint_nft", () => {
 
     try {
       await testContext.program.methods
-        .adminMintNft({ basic: {} }, "Unauthorized", "UNAUTH", "https://example.com/unauth.json")
+        .adminMintNft(BASIC_TIER_ID)
         .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(BASIC_TIER_ID)[0],
           admin: nonAdmin.publicKey,
           recipient: newRecipient.publicKey,
           recipientTokenAccount: recipientTokenAccount,
           mint: newMint.publicKey,
+          reservation: testContext.getReservationPda(newMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(BASIC_TIER_ID),
           metadataAccount: metadataAccount,
           collectionMint: testContext.basicCollectionMint,
           collectionMetadata: collectionMetadata,
@@ -509,5 +523,193 @@ describe("admin_mint_nft", () => {
       expect(error.toString()).to.include("Unauthorized");
     }
   });
-});
 
+  it("should let an airdrop operator mint within its quota until revoked", async () => {
+    const modifyComputeUnits = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+    const operator = Keypair.generate();
+    await testContext.airdropSol(operator.publicKey, 2);
+
+    const [roleAssignment] = testContext.getRoleAssignmentPda(ROLE_AIRDROP_OPERATOR, operator.publicKey);
+    const [operatorQuota] = testContext.getOperatorQuotaPda(OG_TIER_ID, operator.publicKey);
+
+    await testContext.program.methods
+      .grantRole({ airdropOperator: {} }, operator.publicKey)
+      .accounts({
+        adminState: testContext.adminStatePda,
+        superAdmin: testContext.admin.publicKey,
+        roleAssignment,
+      })
+      .signers([testContext.admin])
+      .rpc();
+
+    await testContext.program.methods
+      .setOperatorQuota(OG_TIER_ID, operator.publicKey, new anchor.BN(1))
+      .accounts({
+        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+        operatorQuota,
+      })
+      .signers([testContext.admin])
+      .rpc();
+
+    const operatorMint = async () => {
+      const recipient = Keypair.generate();
+      const newMint = Keypair.generate();
+      const [metadataAccount] = PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), newMint.publicKey.toBuffer()],
+        METAPLEX_PROGRAM_ID
+      );
+      const [collectionMetadata] = PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), testContext.ogCollectionMint!.toBuffer()],
+        METAPLEX_PROGRAM_ID
+      );
+      const [collectionMasterEdition] = PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), testContext.ogCollectionMint!.toBuffer(), Buffer.from("edition")],
+        METAPLEX_PROGRAM_ID
+      );
+
+      await testContext.program.methods
+        .adminMintNft(OG_TIER_ID)
+        .accounts({
+          adminState: testContext.adminStatePda,
+          collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+          admin: operator.publicKey,
+          recipient: recipient.publicKey,
+          recipientTokenAccount: getAssociatedTokenAddressSync(newMint.publicKey, recipient.publicKey),
+          mint: newMint.publicKey,
+          reservation: testContext.getReservationPda(newMint.publicKey)[0],
+          serialRecord: await testContext.getNextSerialRecordPda(OG_TIER_ID),
+          metadataAccount: metadataAccount,
+          collectionMint: testContext.ogCollectionMint,
+          collectionMetadata: collectionMetadata,
+          collectionMasterEdition: collectionMasterEdition,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          recipientUserState: testContext.getUserStatePda(recipient.publicKey)[0],
+          roleAssignment,
+          operatorQuota,
+        })
+        .preInstructions([modifyComputeUnits])
+        .signers([operator, newMint])
+        .rpc();
+    };
+
+    await operatorMint();
+    let quota = await testContext.program.account.operatorQuota.fetch(operatorQuota);
+    expect(quota.mintedCount.toNumber()).to.equal(1);
+
+    try {
+      await operatorMint();
+      expect.fail("Should have thrown OperatorQuotaExhausted error");
+    } catch (error: any) {
+      expect(error.toString()).to.include("OperatorQuotaExhausted");
+    }
+
+    // Raising the quota keeps the counter; revoking blocks further mints
+    await testContext.program.methods
+      .setOperatorQuota(OG_TIER_ID, operator.publicKey, new anchor.BN(2))
+      .accounts({
+        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+        operatorQuota,
+      })
+      .signers([testContext.admin])
+      .rpc();
+
+    await testContext.program.methods
+      .revokeOperatorQuota(OG_TIER_ID, operator.publicKey)
+      .accounts({
+        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        operatorQuota,
+      })
+      .signers([testContext.admin])
+      .rpc();
+
+    quota = await testContext.program.account.operatorQuota.fetch(operatorQuota);
+    expect(quota.revoked).to.be.true;
+    expect(quota.mintedCount.toNumber()).to.equal(1);
+
+    try {
+      await operatorMint();
+      expect.fail("Should have thrown OperatorRevoked error");
+    } catch (error: any) {
+      expect(error.toString()).to.include("OperatorRevoked");
+    }
+  });
+
+  it("should batch admin mint to several recipients and skip ones that already hold a reservation", async () => {
+    const modifyComputeUnits = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
+    const [collectionMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), testContext.ogCollectionMint!.toBuffer()],
+      METAPLEX_PROGRAM_ID
+    );
+    const [collectionMasterEdition] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), testContext.ogCollectionMint!.toBuffer(), Buffer.from("edition")],
+      METAPLEX_PROGRAM_ID
+    );
+
+    // adminMintRecipient1 already received an OG NFT above, so it is skipped
+    const recipients = [Keypair.generate().publicKey, adminMintRecipient1.publicKey, Keypair.generate().publicKey];
+    const mints = recipients.map(() => Keypair.generate());
+    const collectionStateBefore = await testContext.program.account.collectionState.fetch(
+      testContext.getCollectionStatePda(OG_TIER_ID)[0]
+    );
+    // Serial records are consumed by minted recipients in order, so slot i carries serial last + 1 + i
+    const remainingAccounts = recipients.flatMap((recipient, i) => {
+      const mint = mints[i].publicKey;
+      const [metadataAccount] = PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+        METAPLEX_PROGRAM_ID
+      );
+      return [
+        { pubkey: recipient, isSigner: false, isWritable: false },
+        { pubkey: mint, isSigner: true, isWritable: true },
+        { pubkey: getAssociatedTokenAddressSync(mint, recipient), isSigner: false, isWritable: true },
+        { pubkey: metadataAccount, isSigner: false, isWritable: true },
+        { pubkey: testContext.getUserStatePda(recipient)[0], isSigner: false, isWritable: true },
+        { pubkey: testContext.getReservationPda(mint)[0], isSigner: false, isWritable: true },
+        {
+          pubkey: testContext.getSerialRecordPda(OG_TIER_ID, collectionStateBefore.lastSerial.addn(i + 1))[0],
+          isSigner: false,
+          isWritable: true,
+        },
+      ];
+    });
+
+    await testContext.program.methods
+      .batchAdminMintNft(OG_TIER_ID)
+      .accounts({
+        admin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+        collectionMint: testContext.ogCollectionMint,
+        collectionMetadata: collectionMetadata,
+        collectionMasterEdition: collectionMasterEdition,
+        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .remainingAccounts(remainingAccounts)
+      .preInstructions([modifyComputeUnits])
+      .signers([testContext.admin, ...mints])
+      .rpc();
+
+    const collectionStateAfter = await testContext.program.account.collectionState.fetch(
+      testContext.getCollectionStatePda(OG_TIER_ID)[0]
+    );
+    expect(collectionStateAfter.currentAdminMintCount.toNumber()).to.equal(
+      collectionStateBefore.currentAdminMintCount.toNumber() + 2
+    );
+    expect(collectionStateAfter.lastSerial.toNumber()).to.equal(collectionStateBefore.lastSerial.toNumber() + 2);
+
+    // The skipped recipient does not consume a serial
+    for (const [i, offset] of [[0, 1], [2, 2]]) {
+      const userState = await testContext.program.account.userState.fetch(testContext.getUserStatePda(recipients[i])[0]);
+      expect(userState.hasMinted).to.be.true;
+      expect(userState.mintAddress.toBase58()).to.equal(mints[i].publicKey.toBase58());
+      expect(userState.serial.toNumber()).to.equal(collectionStateBefore.lastSerial.toNumber() + offset);
+    }
+    const skippedMintInfo = await testContext.provider.connection.getAccountInfo(mints[1].publicKey);
+    expect(skippedMintInfo).to.be.null;
+  });
+});
@@ -12,7 +12,8 @@ import {
   OG_ADMIN_MINT_LIMIT,
   REGULAR_ADMIN_MINT_LIMIT,
   BASIC_ADMIN_MINT_LIMIT,
-  MINT_START_DATE
+  MINT_START_DATE,
+  OG_TIER_ID
 } from "./setup";
 import { Keypair, PublicKey, ComputeBudgetProgram } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, getAccount, createAssociatedTokenAccount, mintTo, getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
@@ -32,30 +33,7 @@ describe("withdraw", () => {
       const regularCollectionMintKeypair = Keypair.generate();
       const basicCollectionMintKeypair = Keypair.generate();
 
-      await testContext.program.methods
-        .initAdmin(
-          ogCollectionMintKeypair.publicKey,
-          OG_MINT_FEE,
-          OG_MAX_SUPPLY,
-          OG_ADMIN_MINT_LIMIT,
-          regularCollectionMintKeypair.publicKey,
-          REGULAR_MINT_FEE,
-          REGULAR_MAX_SUPPLY,
-          REGULAR_ADMIN_MINT_LIMIT,
-          basicCollectionMintKeypair.publicKey,
-          BASIC_MINT_FEE,
-          BASIC_MAX_SUPPLY,
-          BASIC_ADMIN_MINT_LIMIT,
-          testContext.withdrawWallet.publicKey,
-          MINT_START_DATE
-        )
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-          paymentMint: testContext.usdcMint,
-          paymentTokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.initAdmin(ogCollectionMintKeypair.publicKey, regularCollectionMintKeypair.publicKey, basicCollectionMintKeypair.publicKey);
       
       const modifyComputeUnits = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
 
@@ -77,6 +55,7 @@ describe("withdraw", () => {
       await testContext.program.methods
         .createCollectionNft("OG Collection", "OG", "https://example.com/og.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: ogCollectionMintKeypair.publicKey,
           collectionTokenAccount: ogCollectionTokenAccount,
@@ -105,6 +84,7 @@ describe("withdraw", () => {
       await testContext.program.methods
         .createCollectionNft("Regular Collection", "REG", "https://example.com/regular.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: regularCollectionMintKeypair.publicKey,
           collectionTokenAccount: regularCollectionTokenAccount,
@@ -133,6 +113,7 @@ describe("withdraw", () => {
       await testContext.program.methods
         .createCollectionNft("Basic Collection", "BASIC", "https://example.com/basic.json")
         .accounts({
+          adminState: testContext.adminStatePda,
           signer: testContext.admin.publicKey,
           collectionMint: basicCollectionMintKeypair.publicKey,
           collectionTokenAccount: basicCollectionTokenAccount,
@@ -179,6 +160,7 @@ describe("withdraw", () => {
         await testContext.program.methods
           .createCollectionNft("OG Collection", "OG", "https://example.com/og.json")
           .accounts({
+            adminState: testContext.adminStatePda,
             signer: testContext.admin.publicKey,
             collectionMint: ogCollectionMintKeypair.publicKey,
             collectionTokenAccount: ogCollectionTokenAccount,
@@ -191,8 +173,12 @@ describe("withdraw", () => {
 
         // Update admin state
         await testContext.program.methods
-          .updateCollectionMint({ og: {} }, ogCollectionMintKeypair.publicKey)
-          .accounts({ superAdmin: testContext.admin.publicKey })
+          .updateCollectionMint(OG_TIER_ID, ogCollectionMintKeypair.publicKey)
+          .accounts({
+            superAdmin: testContext.admin.publicKey,
+            adminState: testContext.adminStatePda,
+            collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+          })
           .signers([testContext.admin])
           .rpc();
 
@@ -206,13 +192,7 @@ describe("withdraw", () => {
     // Update withdraw wallet to admin's public key if different
     const adminState = await testContext.fetchAdminState();
     if (adminState.withdrawWallet.toString() !== testContext.admin.publicKey.toString()) {
-      await testContext.program.methods
-        .updateWithdrawWallet(testContext.admin.publicKey)
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.setWithdrawWallet(testContext.admin);
     }
   });
 
@@ -238,10 +218,17 @@ describe("withdraw", () => {
     );
 
     await testContext.program.methods
-      .mintNft({ og: {} }, "Test NFT", "TEST", "https://example.com/nft.json")
+      .mintNft(OG_TIER_ID, null)
       .accounts({
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+        vault: testContext.vaultPda,
+        userState: testContext.getUserStatePda(user.publicKey)[0],
+        walletMintState: testContext.getWalletMintStatePda(OG_TIER_ID, user.publicKey)[0],
         signer: user.publicKey,
         mint: nftMint.publicKey,
+        reservation: testContext.getReservationPda(nftMint.publicKey)[0],
+        serialRecord: await testContext.getNextSerialRecordPda(OG_TIER_ID),
         paymentMint: testContext.usdcMint,
         payerTokenAccount: userUsdcAccount,
         paymentTokenProgram: TOKEN_PROGRAM_ID,
@@ -296,7 +283,9 @@ describe("withdraw", () => {
     const tx = await testContext.program.methods
       .withdraw(new anchor.BN(withdrawAmount.toString()))
       .accounts({
-        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        vault: testContext.vaultPda,
+        authority: testContext.admin.publicKey,
         paymentMint: testContext.usdcMint,
         withdrawTokenAccount: testContext.adminUsdcAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -358,7 +347,9 @@ describe("withdraw", () => {
     const tx = await testContext.program.methods
       .withdrawAll()
       .accounts({
-        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        vault: testContext.vaultPda,
+        authority: testContext.admin.publicKey,
         paymentMint: testContext.usdcMint,
         withdrawTokenAccount: testContext.adminUsdcAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -387,7 +378,9 @@ describe("withdraw", () => {
       await testContext.program.methods
         .withdraw(new anchor.BN(withdrawAmount.toString()))
         .accounts({
-          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          vault: testContext.vaultPda,
+          authority: testContext.admin.publicKey,
           paymentMint: testContext.usdcMint,
           withdrawTokenAccount: testContext.adminUsdcAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -409,7 +402,9 @@ describe("withdraw", () => {
       await testContext.program.methods
         .withdrawAll()
         .accounts({
-          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          vault: testContext.vaultPda,
+          authority: testContext.admin.publicKey,
           paymentMint: testContext.usdcMint,
           withdrawTokenAccount: testContext.adminUsdcAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -423,7 +418,9 @@ describe("withdraw", () => {
       await testContext.program.methods
         .withdrawAll()
         .accounts({
-          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          vault: testContext.vaultPda,
+          authority: testContext.admin.publicKey,
           paymentMint: testContext.usdcMint,
           withdrawTokenAccount: testContext.adminUsdcAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -442,7 +439,9 @@ describe("withdraw", () => {
       await testContext.program.methods
         .withdraw(new anchor.BN(0))
         .accounts({
-          superAdmin: testContext.admin.publicKey,
+          adminState: testContext.adminStatePda,
+          vault: testContext.vaultPda,
+          authority: testContext.admin.publicKey,
           paymentMint: testContext.usdcMint,
           withdrawTokenAccount: testContext.adminUsdcAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -486,7 +485,9 @@ describe("withdraw", () => {
       await testContext.program.methods
         .withdraw(new anchor.BN(1000000))
         .accounts({
-          superAdmin: testContext.user1.keypair.publicKey, // Non-admin
+          adminState: testContext.adminStatePda,
+          vault: testContext.vaultPda,
+          authority: testContext.user1.keypair.publicKey, // Non-admin
           paymentMint: testContext.usdcMint,
           withdrawTokenAccount: testContext.user1.tokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -499,4 +500,78 @@ describe("withdraw", () => {
       expect(error.toString()).to.include("Unauthorized");
     }
   });
+
+  it("should block withdraw while paused by the guardian until super admin clears it", async () => {
+    const guardian = testContext.user3.keypair;
+    const withdrawPause = { mint: false, adminMint: false, burn: false, withdraw: true, metadata: false };
+
+    await testContext.program.methods
+      .updateGuardian(guardian.publicKey)
+      .accounts({
+        adminState: testContext.adminStatePda,
+        superAdmin: testContext.admin.publicKey,
+      })
+      .signers([testContext.admin])
+      .rpc();
+
+    await testContext.program.methods
+      .setPauseFlags(withdrawPause)
+      .accounts({
+        adminState: testContext.adminStatePda,
+        authority: guardian.publicKey,
+      })
+      .signers([guardian])
+      .rpc();
+
+    const pausedState = await testContext.program.account.adminState.fetch(testContext.adminStatePda);
+    expect(pausedState.pauseFlags.withdraw).to.be.true;
+    expect(pausedState.pauseFlags.mint).to.be.false;
+
+    try {
+      await testContext.program.methods
+        .withdraw(new anchor.BN(1))
+        .accounts({
+          adminState: testContext.adminStatePda,
+          vault: testContext.vaultPda,
+          authority: testContext.admin.publicKey,
+          paymentMint: testContext.usdcMint,
+          withdrawTokenAccount: testContext.adminUsdcAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([testContext.admin])
+        .rpc();
+
+      expect.fail("Expected withdraw to fail while paused");
+    } catch (error: any) {
+      expect(error.toString()).to.include("ProgramPaused");
+    }
+
+    // Guardian can pause but not unpause
+    try {
+      await testContext.program.methods
+        .clearPauseFlags(withdrawPause)
+        .accounts({
+          adminState: testContext.adminStatePda,
+          superAdmin: guardian.publicKey,
+        })
+        .signers([guardian])
+        .rpc();
+
+      expect.fail("Expected guardian to be unable to clear pause flags");
+    } catch (error: any) {
+      expect(error.toString()).to.include("Unauthorized");
+    }
+
+    await testContext.program.methods
+      .clearPauseFlags(withdrawPause)
+      .accounts({
+        adminState: testContext.adminStatePda,
+        superAdmin: testContext.admin.publicKey,
+      })
+      .signers([testContext.admin])
+      .rpc();
+
+    const resumedState = await testContext.program.account.adminState.fetch(testContext.adminStatePda);
+    expect(resumedState.pauseFlags.withdraw).to.be.false;
+  });
 });
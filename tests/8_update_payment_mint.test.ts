@@ -14,48 +14,14 @@ describe("update_payment_mint", () => {
       const regularCollectionMint = Keypair.generate().publicKey;
       const basicCollectionMint = Keypair.generate().publicKey;
 
-      await testContext.program.methods
-        .initAdmin(
-          ogCollectionMint,
-          OG_MINT_FEE,
-          OG_MAX_SUPPLY,
-          OG_ADMIN_MINT_LIMIT,
-          regularCollectionMint,
-          REGULAR_MINT_FEE,
-          REGULAR_MAX_SUPPLY,
-          REGULAR_ADMIN_MINT_LIMIT,
-          basicCollectionMint,
-          BASIC_MINT_FEE,
-          BASIC_MAX_SUPPLY,
-          BASIC_ADMIN_MINT_LIMIT,
-          testContext.withdrawWallet.publicKey,
-          MINT_START_DATE
-        )
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-          adminState: testContext.adminStatePda,
-          paymentMint: testContext.usdcMint,
-          vault: testContext.vaultPda,
-          paymentTokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.initAdmin(ogCollectionMint, regularCollectionMint, basicCollectionMint);
       testContext.adminInitialized = true;
     }
 
     // Update withdraw wallet if different
     const adminState = await testContext.fetchAdminState();
     if (adminState.withdrawWallet.toString() !== testContext.admin.publicKey.toString()) {
-      await testContext.program.methods
-        .updateWithdrawWallet(testContext.admin.publicKey)
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-          adminState: testContext.adminStatePda,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.setWithdrawWallet(testContext.admin);
     }
   });
 
@@ -67,7 +33,7 @@ describe("update_payment_mint", () => {
       await testContext.program.methods
         .withdrawAll()
         .accounts({
-          superAdmin: testContext.admin.publicKey,
+          authority: testContext.admin.publicKey,
           adminState: testContext.adminStatePda,
           paymentMint: testContext.usdcMint,
           vault: testContext.vaultPda,
@@ -12,7 +12,8 @@ import {
   OG_ADMIN_MINT_LIMIT,
   REGULAR_ADMIN_MINT_LIMIT,
   BASIC_ADMIN_MINT_LIMIT,
-  MINT_START_DATE
+  MINT_START_DATE,
+  OG_TIER_ID
 } from "./setup";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, createMint } from "@solana/spl-token";
@@ -63,30 +64,7 @@ describe("Complete User Journey (Admin + Basic Operations)", () => {
         TOKEN_PROGRAM_ID
       );
 
-      const initTx = await testContext.program.methods
-        .initAdmin(
-          testContext.ogCollectionMint,
-          OG_MINT_FEE,
-          OG_MAX_SUPPLY,
-          OG_ADMIN_MINT_LIMIT,
-          testContext.regularCollectionMint,
-          REGULAR_MINT_FEE,
-          REGULAR_MAX_SUPPLY,
-          REGULAR_ADMIN_MINT_LIMIT,
-          testContext.basicCollectionMint,
-          BASIC_MINT_FEE,
-          BASIC_MAX_SUPPLY,
-          BASIC_ADMIN_MINT_LIMIT,
-          testContext.withdrawWallet.publicKey,
-          MINT_START_DATE
-        )
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-          paymentMint: testContext.usdcMint,
-          paymentTokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      const initTx = await testContext.initAdmin(testContext.ogCollectionMint, testContext.regularCollectionMint, testContext.basicCollectionMint);
 
       console.log("✅ Admin initialized:", initTx);
       testContext.adminInitialized = true;
@@ -97,13 +75,7 @@ describe("Complete User Journey (Admin + Basic Operations)", () => {
     // Update withdraw wallet to admin if different
     const currentAdminState = await testContext.fetchAdminState();
     if (currentAdminState.withdrawWallet.toString() !== testContext.admin.publicKey.toString()) {
-      await testContext.program.methods
-        .updateWithdrawWallet(testContext.admin.publicKey)
-        .accounts({
-          superAdmin: testContext.admin.publicKey,
-        })
-        .signers([testContext.admin])
-        .rpc();
+      await testContext.setWithdrawWallet(testContext.admin);
     }
 
     console.log("✅ Admin setup complete");
@@ -131,9 +103,11 @@ describe("Complete User Journey (Admin + Basic Operations)", () => {
     // Update OG collection mint fee
     const newMintFee = new anchor.BN(7000000); // 7 USDC
     await testContext.program.methods
-      .updateMintFee({ og: {} }, newMintFee)
+      .updateMintFee(OG_TIER_ID, newMintFee)
       .accounts({
-        superAdmin: testContext.admin.publicKey,
+        adminState: testContext.adminStatePda,
+        collectionState: testContext.getCollectionStatePda(OG_TIER_ID)[0],
+        authority: testContext.admin.publicKey,
       })
       .signers([testContext.admin])
       .rpc();
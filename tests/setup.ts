@@ -7,15 +7,18 @@ import {
   mintTo,
   getAccount,
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
   createAssociatedTokenAccount,
 } from "@solana/spl-token";
 import {
   PublicKey,
   Keypair,
   LAMPORTS_PER_SOL,
+  ComputeBudgetProgram,
 } from "@solana/web3.js";
 import { expect } from "chai";
 import { BN } from "bn.js";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 // Test constants
 export const OG_MINT_FEE = new BN(5000000); // 5 USDC (6 decimals)
@@ -29,6 +32,39 @@ export const BASIC_MAX_SUPPLY = new BN(1000);
 export const BASIC_ADMIN_MINT_LIMIT = new BN(0); // Admin cannot mint Basic NFTs
 export const MINT_START_DATE = new BN(0); // No restriction for tests
 
+// Upgradeable loader - owns the ProgramData account init_admin checks
+export const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+// Campaign ids - the default campaign uses an empty PDA seed (legacy addresses)
+export const DEFAULT_CAMPAIGN_ID = new BN(0);
+
+// Role enum indices, matching the on-chain Role declaration order
+export const ROLE_FEE_MANAGER = 0;
+export const ROLE_METADATA_OPERATOR = 1;
+export const ROLE_AIRDROP_OPERATOR = 2;
+export const ROLE_TREASURER = 3;
+
+// Collection tier ids (CollectionState PDA seeds)
+export const OG_TIER_ID = 0;
+export const REGULAR_TIER_ID = 1;
+export const BASIC_TIER_ID = 2;
+
+export const METAPLEX_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+// Metadata minted NFTs of a tier carry - a base URI ending in '/' gets "<serial>.json" appended
+export const collectionMetadataFor = (tierId: number): { namePrefix: string; symbol: string; baseUri: string } => {
+  switch (tierId) {
+    case OG_TIER_ID:
+      return { namePrefix: "OG Reservation", symbol: "OG", baseUri: "https://example.com/og/" };
+    case REGULAR_TIER_ID:
+      return { namePrefix: "Regular Reservation", symbol: "REG", baseUri: "https://example.com/regular/" };
+    case BASIC_TIER_ID:
+      return { namePrefix: "Basic Reservation", symbol: "BASIC", baseUri: "https://example.com/basic/" };
+    default:
+      return { namePrefix: "Reservation NFT", symbol: "RSV", baseUri: "https://example.com/reservation.json" };
+  }
+};
+
 // Test users
 export interface TestUser {
   keypair: Keypair;
@@ -59,6 +95,7 @@ class TestContext {
   public adminStateBump: number;
   public vaultPda: PublicKey;
   public vaultBump: number;
+  public programDataPda: PublicKey;
 
   // Collections
   public ogCollectionMint?: PublicKey;
@@ -136,14 +173,13 @@ class TestContext {
     this.user4 = await this.createTestUser(5); // 5 SOL
 
     // Derive PDAs
-    [this.adminStatePda, this.adminStateBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("admin_state")],
-      this.program.programId
-    );
+    [this.adminStatePda, this.adminStateBump] = this.getAdminStatePda();
+    [this.vaultPda, this.vaultBump] = this.getVaultPda(this.usdcMint);
 
-    [this.vaultPda, this.vaultBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), this.usdcMint.toBuffer()],
-      this.program.programId
+    // ProgramData holds the upgrade authority (the provider wallet that deployed the program)
+    [this.programDataPda] = PublicKey.findProgramAddressSync(
+      [this.program.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_PROGRAM_ID
     );
 
     this.initialized = true;
@@ -213,9 +249,292 @@ class TestContext {
     );
   }
 
-  // Helper to fetch admin state
+  // Helper to get the PDA seed component for a campaign (empty for the default campaign)
+  public campaignSeed(campaignId: BN = DEFAULT_CAMPAIGN_ID): Buffer {
+    return campaignId.isZero() ? Buffer.alloc(0) : campaignId.toArrayLike(Buffer, "le", 8);
+  }
+
+  // Helper to get admin state PDA for a campaign
+  public getAdminStatePda(campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_state"), this.campaignSeed(campaignId)],
+      this.program.programId
+    );
+  }
+
+  // Helper to get vault PDA for a payment mint within a campaign
+  public getVaultPda(paymentMint: PublicKey, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), this.campaignSeed(campaignId), paymentMint.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  // Helper to get user state PDA for a wallet within a campaign
+  public getUserStatePda(user: PublicKey, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("user_state"), this.campaignSeed(campaignId), user.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  // Helper to get per-wallet sale phase counters PDA for a tier within a campaign
+  public getWalletMintStatePda(tierId: number, wallet: PublicKey, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    const tierSeed = Buffer.alloc(2);
+    tierSeed.writeUInt16LE(tierId);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("wallet_mint_state"), this.campaignSeed(campaignId), tierSeed, wallet.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  // Helper to get multisig PDA for a campaign
+  public getMultisigPda(campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("multisig"), this.campaignSeed(campaignId)],
+      this.program.programId
+    );
+  }
+
+  // Helper to get proposal PDA by id within a campaign
+  public getProposalPda(proposalId: BN, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), this.campaignSeed(campaignId), proposalId.toArrayLike(Buffer, "le", 8)],
+      this.program.programId
+    );
+  }
+
+  // Helper to get queued config change PDA by id within a campaign
+  public getPendingChangePda(changeId: BN, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_change"), this.campaignSeed(campaignId), changeId.toArrayLike(Buffer, "le", 8)],
+      this.program.programId
+    );
+  }
+
+  // Helper to get role assignment PDA (role index follows the on-chain Role enum order)
+  public getRoleAssignmentPda(role: number, holder: PublicKey, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("role_assignment"), this.campaignSeed(campaignId), Buffer.from([role]), holder.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  // Helper to get an airdrop operator's quota PDA for a tier
+  public getOperatorQuotaPda(tierId: number, operator: PublicKey, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    const tierSeed = Buffer.alloc(2);
+    tierSeed.writeUInt16LE(tierId);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("operator_quota"), this.campaignSeed(campaignId), tierSeed, operator.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  // Helper to get the reservation PDA of an NFT mint
+  public getReservationPda(mint: PublicKey, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("reservation"), this.campaignSeed(campaignId), mint.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  // Helper to get the serial record PDA for a collection serial
+  public getSerialRecordPda(tierId: number, serial: BN, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    const tierSeed = Buffer.alloc(2);
+    tierSeed.writeUInt16LE(tierId);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("serial"), this.campaignSeed(campaignId), tierSeed, serial.toArrayLike(Buffer, "le", 8)],
+      this.program.programId
+    );
+  }
+
+  // Helper to get the collection migration PDA of a tier
+  public getCollectionMigrationPda(tierId: number, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    const tierSeed = Buffer.alloc(2);
+    tierSeed.writeUInt16LE(tierId);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_migration"), this.campaignSeed(campaignId), tierSeed],
+      this.program.programId
+    );
+  }
+
+  // Serial record the next mint in a collection will create
+  public async getNextSerialRecordPda(tierId: number, campaignId: BN = DEFAULT_CAMPAIGN_ID): Promise<PublicKey> {
+    const collectionState = await this.program.account.collectionState.fetch(this.getCollectionStatePda(tierId, campaignId)[0]);
+    return this.getSerialRecordPda(tierId, collectionState.lastSerial.addn(1), campaignId)[0];
+  }
+
+  // Serial record a burn of `mint` has to mark (null when the NFT has no reservation or serial)
+  public async getBurnSerialRecordPda(mint: PublicKey, campaignId: BN = DEFAULT_CAMPAIGN_ID): Promise<PublicKey | null> {
+    const reservation = await this.program.account.reservation.fetchNullable(this.getReservationPda(mint, campaignId)[0]);
+    if (!reservation || reservation.serial.isZero()) {
+      return null;
+    }
+    return this.getSerialRecordPda(reservation.tierId, reservation.serial, campaignId)[0];
+  }
+
+  // Helper to get collection state PDA for a tier within a campaign
+  public getCollectionStatePda(tierId: number, campaignId: BN = DEFAULT_CAMPAIGN_ID): [PublicKey, number] {
+    const tierSeed = Buffer.alloc(2);
+    tierSeed.writeUInt16LE(tierId);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_state"), this.campaignSeed(campaignId), tierSeed],
+      this.program.programId
+    );
+  }
+
+  // Helper to fetch collection state for a tier
+  public async fetchCollectionState(tierId: number): Promise<any> {
+    const [collectionStatePda] = this.getCollectionStatePda(tierId);
+    return await this.program.account.collectionState.fetch(collectionStatePda);
+  }
+
+  // Helper to fetch admin state together with the OG/Regular/Basic collection states
   public async fetchAdminState(): Promise<any> {
-    return await this.program.account.adminState.fetch(this.adminStatePda);
+    const adminState = await this.program.account.adminState.fetch(this.adminStatePda);
+    const [ogCollection, regularCollection, basicCollection] = await Promise.all([
+      this.fetchCollectionState(OG_TIER_ID),
+      this.fetchCollectionState(REGULAR_TIER_ID),
+      this.fetchCollectionState(BASIC_TIER_ID),
+    ]);
+    return { ...adminState, ogCollection, regularCollection, basicCollection };
+  }
+
+  // Token metadata PDAs (metadata, master edition) of a mint
+  public getMetadataPdas(mint: PublicKey): { metadata: PublicKey; masterEdition: PublicKey } {
+    const [metadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      METAPLEX_PROGRAM_ID
+    );
+    const [masterEdition] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), METAPLEX_PROGRAM_ID.toBuffer(), mint.toBuffer(), Buffer.from("edition")],
+      METAPLEX_PROGRAM_ID
+    );
+    return { metadata, masterEdition };
+  }
+
+  // Collection accounts mint instructions take for a tier, read from its collection state
+  public async getCollectionAccounts(
+    tierId: number,
+    campaignId: BN = DEFAULT_CAMPAIGN_ID
+  ): Promise<{ collectionMint: PublicKey; collectionMetadata: PublicKey; collectionMasterEdition: PublicKey }> {
+    const collectionState = await this.program.account.collectionState.fetch(this.getCollectionStatePda(tierId, campaignId)[0]);
+    const { metadata, masterEdition } = this.getMetadataPdas(collectionState.collectionMint);
+    return {
+      collectionMint: collectionState.collectionMint,
+      collectionMetadata: metadata,
+      collectionMasterEdition: masterEdition,
+    };
+  }
+
+  // Helper to create a collection NFT owned by the admin state
+  public async createCollectionNft(name: string, symbol: string, uri: string): Promise<PublicKey> {
+    const collectionMint = Keypair.generate();
+    const { metadata, masterEdition } = this.getMetadataPdas(collectionMint.publicKey);
+    await this.program.methods
+      .createCollectionNft(name, symbol, uri)
+      .accounts({
+        adminState: this.adminStatePda,
+        signer: this.admin.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(collectionMint.publicKey, this.adminStatePda, true),
+        metadataAccount: metadata,
+        masterEditionAccount: masterEdition,
+      })
+      .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+      .signers([this.admin, collectionMint])
+      .rpc();
+    return collectionMint.publicKey;
+  }
+
+  // Helper to set the metadata minted NFTs of a tier carry
+  public async setCollectionMetadata(tierId: number, campaignId: BN = DEFAULT_CAMPAIGN_ID): Promise<void> {
+    const { namePrefix, symbol, baseUri } = collectionMetadataFor(tierId);
+    await this.program.methods
+      .updateCollectionMetadata(tierId, namePrefix, symbol, baseUri)
+      .accounts({
+        superAdmin: this.admin.publicKey,
+        adminState: this.getAdminStatePda(campaignId)[0],
+        collectionState: this.getCollectionStatePda(tierId, campaignId)[0],
+      })
+      .signers([this.admin])
+      .rpc();
+  }
+
+  // Helper to register a tier with its own collection NFT and metadata, ready for minting
+  public async addMintableCollection(tierId: number, mintFee: BN, maxSupply: BN, adminMintLimit: BN): Promise<PublicKey> {
+    const collectionMint = await this.createCollectionNft(`Tier ${tierId} Collection`, "TIER", "https://example.com/tier.json");
+    await this.program.methods
+      .addCollection(tierId, collectionMint, mintFee, maxSupply, adminMintLimit)
+      .accounts({
+        superAdmin: this.admin.publicKey,
+        adminState: this.adminStatePda,
+        collectionState: this.getCollectionStatePda(tierId)[0],
+      })
+      .signers([this.admin])
+      .rpc();
+    await this.setCollectionMetadata(tierId);
+    return collectionMint;
+  }
+
+  // Helper to initialize admin state and register the OG/Regular/Basic collections
+  public async initAdmin(
+    ogCollectionMint: PublicKey,
+    regularCollectionMint: PublicKey,
+    basicCollectionMint: PublicKey
+  ): Promise<void> {
+    await this.program.methods
+      .initAdmin(DEFAULT_CAMPAIGN_ID, this.withdrawWallet.publicKey, MINT_START_DATE)
+      .accounts({
+        upgradeAuthority: this.provider.wallet.publicKey,
+        programData: this.programDataPda,
+        adminState: this.adminStatePda,
+        vault: this.vaultPda,
+        superAdmin: this.admin.publicKey,
+        paymentMint: this.usdcMint,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([this.admin])
+      .rpc();
+
+    const collections: [number, PublicKey, BN, BN, BN][] = [
+      [OG_TIER_ID, ogCollectionMint, OG_MINT_FEE, OG_MAX_SUPPLY, OG_ADMIN_MINT_LIMIT],
+      [REGULAR_TIER_ID, regularCollectionMint, REGULAR_MINT_FEE, REGULAR_MAX_SUPPLY, REGULAR_ADMIN_MINT_LIMIT],
+      [BASIC_TIER_ID, basicCollectionMint, BASIC_MINT_FEE, BASIC_MAX_SUPPLY, BASIC_ADMIN_MINT_LIMIT],
+    ];
+    for (const [tierId, collectionMint, mintFee, maxSupply, adminMintLimit] of collections) {
+      await this.program.methods
+        .addCollection(tierId, collectionMint, mintFee, maxSupply, adminMintLimit)
+        .accounts({
+          superAdmin: this.admin.publicKey,
+          adminState: this.adminStatePda,
+          collectionState: this.getCollectionStatePda(tierId)[0],
+        })
+        .signers([this.admin])
+        .rpc();
+      await this.setCollectionMetadata(tierId);
+    }
+  }
+
+  // Helper to switch the withdraw wallet - the super admin proposes and the new wallet accepts
+  public async setWithdrawWallet(wallet: Keypair): Promise<void> {
+    await this.program.methods
+      .proposeWithdrawWallet(wallet.publicKey)
+      .accounts({
+        adminState: this.adminStatePda,
+        superAdmin: this.admin.publicKey,
+      })
+      .signers([this.admin])
+      .rpc();
+
+    await this.program.methods
+      .acceptWithdrawWallet()
+      .accounts({
+        adminState: this.adminStatePda,
+        newWithdrawWallet: wallet.publicKey,
+      })
+      .signers([wallet])
+      .rpc();
   }
 
   // Helper to mint USDC to a user
@@ -237,6 +556,53 @@ class TestContext {
   }
 }
 
+// Allowlist entry - price 0 means the collection mint fee applies
+export interface AllowlistEntry {
+  wallet: PublicKey;
+  price: BN;
+}
+
+// Leaf hash matching the program: keccak256(wallet || price as u64 LE)
+export const allowlistLeaf = (entry: AllowlistEntry): Buffer => {
+  return Buffer.from(
+    keccak_256(Buffer.concat([entry.wallet.toBuffer(), entry.price.toArrayLike(Buffer, "le", 8)]))
+  );
+};
+
+// Build a sorted-pair keccak Merkle tree and return its root plus a proof lookup per wallet
+export const buildAllowlist = (
+  entries: AllowlistEntry[]
+): { root: number[]; getProof: (wallet: PublicKey) => { price: BN; proof: number[][] } } => {
+  const hashPair = (a: Buffer, b: Buffer): Buffer =>
+    Buffer.from(keccak_256(Buffer.compare(a, b) <= 0 ? Buffer.concat([a, b]) : Buffer.concat([b, a])));
+
+  const layers: Buffer[][] = [entries.map(allowlistLeaf)];
+  while (layers[layers.length - 1].length > 1) {
+    const current = layers[layers.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < current.length; i += 2) {
+      // An odd node is promoted unchanged to the next layer
+      next.push(i + 1 < current.length ? hashPair(current[i], current[i + 1]) : current[i]);
+    }
+    layers.push(next);
+  }
+
+  const getProof = (wallet: PublicKey) => {
+    let index = entries.findIndex((entry) => entry.wallet.equals(wallet));
+    if (index < 0) throw new Error(`Wallet ${wallet.toBase58()} is not on the allowlist`);
+    const price = entries[index].price;
+    const proof: number[][] = [];
+    for (const layer of layers.slice(0, -1)) {
+      const sibling = index % 2 === 0 ? index + 1 : index - 1;
+      if (sibling < layer.length) proof.push(Array.from(layer[sibling]));
+      index = Math.floor(index / 2);
+    }
+    return { price, proof };
+  };
+
+  return { root: Array.from(layers[layers.length - 1][0]), getProof };
+};
+
 // Export singleton instance
 export const testContext = TestContext.getInstance();
 